@@ -0,0 +1,19 @@
+use binance_spot_connector_rust::{
+    convert,
+    http::Credentials,
+    hyper::{BinanceHttpClient, Error},
+};
+use env_logger::Builder;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    let client = BinanceHttpClient::default().credentials(credentials);
+    let request = convert::asset_info();
+    let data = client.send(request).await?.into_body_str().await?;
+    log::info!("{}", data);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use binance_spot_connector_rust::{
+    convert,
+    hyper::{BinanceHttpClient, Error},
+};
+use env_logger::Builder;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let client = BinanceHttpClient::default();
+    let request = convert::exchange_info().from_asset("BTC").to_asset("USDT");
+    let data = client.send(request).await?.into_body_str().await?;
+    log::info!("{}", data);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use binance_spot_connector_rust::{
+    convert::{self, order::ExpiredType},
+    http::Credentials,
+    hyper::{BinanceHttpClient, Error},
+    trade::order::Side,
+};
+use env_logger::Builder;
+use rust_decimal_macros::dec;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    let client = BinanceHttpClient::default().credentials(credentials);
+    let request =
+        convert::place_limit_order("BTC", "USDT", dec!(25000), Side::Buy, ExpiredType::Days7)
+            .base_amount(dec!(0.1));
+    let data = client.send(request).await?.into_body_str().await?;
+    log::info!("{}", data);
+    Ok(())
+}
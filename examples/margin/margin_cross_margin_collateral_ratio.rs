@@ -0,0 +1,17 @@
+use binance_spot_connector_rust::{
+    hyper::{BinanceHttpClient, Error},
+    margin,
+};
+use env_logger::Builder;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let client = BinanceHttpClient::default();
+    let request = margin::margin_cross_margin_collateral_ratio();
+    let data = client.send(request).await?.into_body_str().await?;
+    log::info!("{}", data);
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use binance_spot_connector_rust::{
+    http::Credentials,
+    hyper::{BinanceHttpClient, Error},
+    margin,
+};
+use env_logger::Builder;
+use futures_util::{pin_mut, StreamExt};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    let client = BinanceHttpClient::default().credentials(credentials);
+    let history = margin::margin_my_trade_history("BNBUSDT").start_time(1640995200000);
+    let trades = client.paginate(history);
+    pin_mut!(trades);
+    while let Some(trade) = trades.next().await {
+        log::info!("{:?}", trade?);
+    }
+    Ok(())
+}
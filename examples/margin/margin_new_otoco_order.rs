@@ -0,0 +1,33 @@
+use binance_spot_connector_rust::{
+    http::Credentials,
+    hyper::{BinanceHttpClient, Error},
+    margin,
+    trade::order::{Side, TimeInForce, WorkingMandatoryParams},
+};
+use env_logger::Builder;
+use rust_decimal_macros::dec;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    let client = BinanceHttpClient::default().credentials(credentials);
+    let request = margin::margin_new_otoco_order(
+        "BNBUSDT",
+        WorkingMandatoryParams::new("LIMIT", Side::Buy, dec!(596.0), dec!(1.0)),
+        Side::Sell,
+        dec!(1.0),
+        "LIMIT_MAKER",
+    )
+    .working_time_in_force(TimeInForce::Gtc)
+    .pending_above_price(dec!(610))
+    .pending_below_type("STOP_LOSS_LIMIT")
+    .pending_below_stop_price(dec!(580))
+    .pending_below_price(dec!(579))
+    .pending_below_time_in_force(TimeInForce::Gtc);
+    let data = client.send(request).await?.into_body_str().await?;
+    log::info!("{}", data);
+    Ok(())
+}
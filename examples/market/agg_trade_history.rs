@@ -0,0 +1,21 @@
+use binance_spot_connector_rust::{
+    hyper::{BinanceHttpClient, Error},
+    market,
+};
+use env_logger::Builder;
+use futures_util::{pin_mut, StreamExt};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let client = BinanceHttpClient::default();
+    let history = market::agg_trade_history("BNBUSDT").start_time(1640995200000);
+    let trades = client.paginate(history);
+    pin_mut!(trades);
+    while let Some(trade) = trades.next().await {
+        log::info!("{:?}", trade?);
+    }
+    Ok(())
+}
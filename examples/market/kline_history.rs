@@ -0,0 +1,26 @@
+use binance_spot_connector_rust::{
+    hyper::{BinanceHttpClient, Error},
+    market::{self, kline_history::KlineHistoryItem, klines::KlineInterval},
+};
+use env_logger::Builder;
+use futures_util::{pin_mut, StreamExt};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+
+    let client = BinanceHttpClient::default();
+    let history = market::kline_history("BNBUSDT", KlineInterval::Minutes1, 1654079109000)
+        .end_time(1654165509000);
+    let klines = client.paginate(history);
+    pin_mut!(klines);
+    while let Some(item) = klines.next().await {
+        match item? {
+            KlineHistoryItem::Kline(kline) => log::info!("{:?}", kline),
+            KlineHistoryItem::Gap(gap) => log::warn!("{:?}", gap),
+        }
+    }
+    Ok(())
+}
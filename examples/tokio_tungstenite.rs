@@ -21,7 +21,8 @@ async fn main() {
     conn.subscribe(vec![
         &KlineStream::new("BTCUSDT", KlineInterval::Minutes1).into()
     ])
-    .await;
+    .await
+    .expect("Failed to subscribe");
     // Start a timer for 10 seconds
     let timer = tokio::time::Instant::now();
     let duration = Duration::new(10, 0);
@@ -17,12 +17,18 @@ fn main() {
     conn.subscribe(vec![
         &KlineStream::new("BTCUSDT", KlineInterval::Minutes1).into(),
         &KlineStream::new("BNBBUSD", KlineInterval::Minutes3).into(),
-    ]);
+    ])
+    .expect("Failed to subscribe");
     // Read messages
-    while let Ok(message) = conn.as_mut().read() {
-        let data = message.into_data();
-        let string_data = String::from_utf8(data).expect("Found invalid UTF-8 chars");
-        log::info!("{}", &string_data);
+    while let Ok(message) = conn.read() {
+        match conn.handle_reply(&message) {
+            Some(reply) => log::info!("Reply to {}: {:?}", reply.id, reply.result),
+            None => {
+                let data = message.into_data();
+                let string_data = String::from_utf8(data).expect("Found invalid UTF-8 chars");
+                log::info!("{}", &string_data);
+            }
+        }
     }
     // Disconnect
     conn.close().expect("Failed to disconnect");
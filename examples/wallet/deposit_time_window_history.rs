@@ -0,0 +1,23 @@
+use binance_spot_connector_rust::{
+    http::{pagination::TimeWindowHistory, Credentials},
+    hyper::{BinanceHttpClient, Error},
+    wallet,
+};
+use env_logger::Builder;
+use futures_util::{pin_mut, StreamExt};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    Builder::from_default_env()
+        .filter(None, log::LevelFilter::Info)
+        .init();
+    let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    let client = BinanceHttpClient::default().credentials(credentials);
+    let history = TimeWindowHistory::new(wallet::deposit_history().coin("BNB"), 1577836800000);
+    let deposits = client.paginate(history);
+    pin_mut!(deposits);
+    while let Some(deposit) = deposits.next().await {
+        log::info!("{:?}", deposit?);
+    }
+    Ok(())
+}
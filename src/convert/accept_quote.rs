@@ -0,0 +1,93 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/convert/acceptQuote`
+///
+/// Accept the offered quote by quote ID.
+///
+/// * Prefer [`Quote::accept`](crate::convert::quote::Quote::accept), which refuses quotes past their `validTimestamp`.
+///
+/// Weight(UID): 500
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::accept_quote("12415572564");
+/// ```
+pub struct AcceptQuote {
+    quote_id: String,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl AcceptQuote {
+    pub fn new(quote_id: &str) -> Self {
+        Self {
+            quote_id: quote_id.to_owned(),
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<AcceptQuote> for Request {
+    fn from(request: AcceptQuote) -> Request {
+        let mut params = vec![("quoteId".to_owned(), request.quote_id)];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/acceptQuote".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AcceptQuote;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_accept_quote_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = AcceptQuote::new("12415572564")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/acceptQuote".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("quoteId".to_owned(), "12415572564".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/convert/limit/cancelOrder`
+///
+/// Cancel a convert limit order
+///
+/// Weight(UID): 200
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::cancel_limit_order(1603680255057330400);
+/// ```
+pub struct CancelLimitOrder {
+    order_id: u64,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl CancelLimitOrder {
+    pub fn new(order_id: u64) -> Self {
+        Self {
+            order_id,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<CancelLimitOrder> for Request {
+    fn from(request: CancelLimitOrder) -> Request {
+        let mut params = vec![("orderId".to_owned(), request.order_id.to_string())];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/limit/cancelOrder".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CancelLimitOrder;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_cancel_limit_order_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = CancelLimitOrder::new(1603680255057330400)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/limit/cancelOrder".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("orderId".to_owned(), "1603680255057330400".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use crate::http::{request::Request, Method};
+
+/// `GET /sapi/v1/convert/exchangeInfo`
+///
+/// Query for all convertible token pairs and the tokens' respective upper/lower limits
+///
+/// * User needs to supply either or both of the input parameters.
+/// * If not defined for both `fromAsset` and `toAsset`, only partial token pairs will be returned.
+///
+/// Weight(IP): 3000
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::exchange_info().from_asset("BTC").to_asset("USDT");
+/// ```
+pub struct ExchangeInfo {
+    from_asset: Option<String>,
+    to_asset: Option<String>,
+}
+
+impl ExchangeInfo {
+    pub fn new() -> Self {
+        Self {
+            from_asset: None,
+            to_asset: None,
+        }
+    }
+
+    pub fn from_asset(mut self, from_asset: &str) -> Self {
+        self.from_asset = Some(from_asset.to_owned());
+        self
+    }
+
+    pub fn to_asset(mut self, to_asset: &str) -> Self {
+        self.to_asset = Some(to_asset.to_owned());
+        self
+    }
+}
+
+impl From<ExchangeInfo> for Request {
+    fn from(request: ExchangeInfo) -> Request {
+        let mut params = vec![];
+
+        if let Some(from_asset) = request.from_asset {
+            params.push(("fromAsset".to_owned(), from_asset));
+        }
+
+        if let Some(to_asset) = request.to_asset {
+            params.push(("toAsset".to_owned(), to_asset));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/exchangeInfo".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: None,
+            sign: false,
+        }
+    }
+}
+
+impl Default for ExchangeInfo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExchangeInfo;
+    use crate::http::{request::Request, Method};
+
+    #[test]
+    fn convert_exchange_info_convert_to_request_test() {
+        let request: Request = ExchangeInfo::new()
+            .from_asset("BTC")
+            .to_asset("USDT")
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/exchangeInfo".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![
+                    ("fromAsset".to_owned(), "BTC".to_string()),
+                    ("toAsset".to_owned(), "USDT".to_string()),
+                ],
+                sign: false
+            }
+        );
+    }
+}
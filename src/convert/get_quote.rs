@@ -0,0 +1,157 @@
+use crate::convert::order::{ValidTime, WalletType};
+use crate::http::{request::Request, Credentials, Method};
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/convert/getQuote`
+///
+/// Request a quote for the requested token pairs
+///
+/// * Either `fromAmount` or `toAmount` should be sent.
+/// * `quoteId` will be returned only if you have enough funds to convert.
+/// * The returned quote can be accepted with [`Quote::accept`](crate::convert::quote::Quote::accept) until its `validTimestamp`.
+///
+/// Weight(UID): 200
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+/// use rust_decimal_macros::dec;
+///
+/// let request = convert::get_quote("BTC", "USDT").from_amount(dec!(0.1));
+/// ```
+pub struct GetQuote {
+    from_asset: String,
+    to_asset: String,
+    from_amount: Option<Decimal>,
+    to_amount: Option<Decimal>,
+    wallet_type: Option<WalletType>,
+    valid_time: Option<ValidTime>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl GetQuote {
+    pub fn new(from_asset: &str, to_asset: &str) -> Self {
+        Self {
+            from_asset: from_asset.to_owned(),
+            to_asset: to_asset.to_owned(),
+            from_amount: None,
+            to_amount: None,
+            wallet_type: None,
+            valid_time: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn from_amount(mut self, from_amount: Decimal) -> Self {
+        self.from_amount = Some(from_amount);
+        self
+    }
+
+    pub fn to_amount(mut self, to_amount: Decimal) -> Self {
+        self.to_amount = Some(to_amount);
+        self
+    }
+
+    pub fn wallet_type(mut self, wallet_type: WalletType) -> Self {
+        self.wallet_type = Some(wallet_type);
+        self
+    }
+
+    pub fn valid_time(mut self, valid_time: ValidTime) -> Self {
+        self.valid_time = Some(valid_time);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<GetQuote> for Request {
+    fn from(request: GetQuote) -> Request {
+        let mut params = vec![
+            ("fromAsset".to_owned(), request.from_asset),
+            ("toAsset".to_owned(), request.to_asset),
+        ];
+
+        if let Some(from_amount) = request.from_amount {
+            params.push(("fromAmount".to_owned(), from_amount.to_string()));
+        }
+
+        if let Some(to_amount) = request.to_amount {
+            params.push(("toAmount".to_owned(), to_amount.to_string()));
+        }
+
+        if let Some(wallet_type) = request.wallet_type {
+            params.push(("walletType".to_owned(), wallet_type.to_string()));
+        }
+
+        if let Some(valid_time) = request.valid_time {
+            params.push(("validTime".to_owned(), valid_time.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/getQuote".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GetQuote, ValidTime, WalletType};
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_get_quote_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = GetQuote::new("BTC", "USDT")
+            .from_amount(dec!(0.1))
+            .to_amount(dec!(1000))
+            .wallet_type(WalletType::Spot)
+            .valid_time(ValidTime::Seconds10)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/getQuote".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("fromAsset".to_owned(), "BTC".to_string()),
+                    ("toAsset".to_owned(), "USDT".to_string()),
+                    ("fromAmount".to_owned(), "0.1".to_string()),
+                    ("toAmount".to_owned(), "1000".to_string()),
+                    ("walletType".to_owned(), "SPOT".to_string()),
+                    ("validTime".to_owned(), "10s".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Convert
+
+pub mod accept_quote;
+pub mod asset_info;
+pub mod cancel_limit_order;
+pub mod exchange_info;
+pub mod get_quote;
+pub mod open_limit_orders;
+pub mod order;
+pub mod order_status;
+pub mod place_limit_order;
+pub mod quote;
+pub mod trade_flow;
+
+use crate::trade::order::Side;
+use rust_decimal::Decimal;
+
+use accept_quote::AcceptQuote;
+use asset_info::AssetInfo;
+use cancel_limit_order::CancelLimitOrder;
+use exchange_info::ExchangeInfo;
+use get_quote::GetQuote;
+use open_limit_orders::OpenLimitOrders;
+use order::ExpiredType;
+use order_status::OrderStatus;
+use place_limit_order::PlaceLimitOrder;
+use trade_flow::TradeFlow;
+
+pub fn exchange_info() -> ExchangeInfo {
+    ExchangeInfo::new()
+}
+
+pub fn asset_info() -> AssetInfo {
+    AssetInfo::new()
+}
+
+pub fn get_quote(from_asset: &str, to_asset: &str) -> GetQuote {
+    GetQuote::new(from_asset, to_asset)
+}
+
+pub fn accept_quote(quote_id: &str) -> AcceptQuote {
+    AcceptQuote::new(quote_id)
+}
+
+pub fn order_status() -> OrderStatus {
+    OrderStatus::new()
+}
+
+pub fn trade_flow(start_time: u64, end_time: u64) -> TradeFlow {
+    TradeFlow::new(start_time, end_time)
+}
+
+pub fn place_limit_order(
+    base_asset: &str,
+    quote_asset: &str,
+    limit_price: Decimal,
+    side: Side,
+    expired_type: ExpiredType,
+) -> PlaceLimitOrder {
+    PlaceLimitOrder::new(base_asset, quote_asset, limit_price, side, expired_type)
+}
+
+pub fn cancel_limit_order(order_id: u64) -> CancelLimitOrder {
+    CancelLimitOrder::new(order_id)
+}
+
+pub fn open_limit_orders() -> OpenLimitOrders {
+    OpenLimitOrders::new()
+}
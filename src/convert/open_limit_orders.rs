@@ -0,0 +1,92 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/convert/limit/queryOpenOrders`
+///
+/// Query the account's open convert limit orders
+///
+/// Weight(UID): 3000
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::open_limit_orders();
+/// ```
+pub struct OpenLimitOrders {
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl OpenLimitOrders {
+    pub fn new() -> Self {
+        Self {
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<OpenLimitOrders> for Request {
+    fn from(request: OpenLimitOrders) -> Request {
+        let mut params = vec![];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/limit/queryOpenOrders".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for OpenLimitOrders {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OpenLimitOrders;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_open_limit_orders_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = OpenLimitOrders::new()
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/limit/queryOpenOrders".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![("recvWindow".to_owned(), "5000".to_string()),],
+                sign: true
+            }
+        );
+    }
+}
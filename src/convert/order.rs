@@ -0,0 +1,36 @@
+use strum::Display;
+
+#[derive(Copy, Clone, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum WalletType {
+    Spot,
+    Funding,
+    #[strum(serialize = "SPOT_FUNDING")]
+    SpotFunding,
+}
+
+/// Time a quote stays valid for once issued.
+#[derive(Copy, Clone, Display)]
+pub enum ValidTime {
+    #[strum(serialize = "10s")]
+    Seconds10,
+    #[strum(serialize = "30s")]
+    Seconds30,
+    #[strum(serialize = "1m")]
+    Minutes1,
+    #[strum(serialize = "2m")]
+    Minutes2,
+}
+
+/// Time a convert limit order stays open for.
+#[derive(Copy, Clone, Display)]
+pub enum ExpiredType {
+    #[strum(serialize = "1_D")]
+    Days1,
+    #[strum(serialize = "3_D")]
+    Days3,
+    #[strum(serialize = "7_D")]
+    Days7,
+    #[strum(serialize = "30_D")]
+    Days30,
+}
@@ -0,0 +1,122 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/convert/orderStatus`
+///
+/// Query order status by order ID or quote ID
+///
+/// * Either `orderId` or `quoteId` is required.
+///
+/// Weight(UID): 100
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::order_status().order_id("933256278426274426");
+/// ```
+pub struct OrderStatus {
+    order_id: Option<String>,
+    quote_id: Option<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl OrderStatus {
+    pub fn new() -> Self {
+        Self {
+            order_id: None,
+            quote_id: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn order_id(mut self, order_id: &str) -> Self {
+        self.order_id = Some(order_id.to_owned());
+        self
+    }
+
+    pub fn quote_id(mut self, quote_id: &str) -> Self {
+        self.quote_id = Some(quote_id.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<OrderStatus> for Request {
+    fn from(request: OrderStatus) -> Request {
+        let mut params = vec![];
+
+        if let Some(order_id) = request.order_id {
+            params.push(("orderId".to_owned(), order_id));
+        }
+
+        if let Some(quote_id) = request.quote_id {
+            params.push(("quoteId".to_owned(), quote_id));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/orderStatus".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for OrderStatus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderStatus;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_order_status_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = OrderStatus::new()
+            .order_id("933256278426274426")
+            .quote_id("12415572564")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/orderStatus".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("orderId".to_owned(), "933256278426274426".to_string()),
+                    ("quoteId".to_owned(), "12415572564".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
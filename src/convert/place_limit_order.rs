@@ -0,0 +1,163 @@
+use crate::convert::order::{ExpiredType, WalletType};
+use crate::http::{request::Request, Credentials, Method};
+use crate::trade::order::Side;
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/convert/limit/placeOrder`
+///
+/// Place a limit order for a convert pair
+///
+/// * Either `baseAmount` or `quoteAmount` is required.
+///
+/// Weight(UID): 500
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert::{self, order::ExpiredType};
+/// use binance_spot_connector_rust::trade::order::Side;
+/// use rust_decimal_macros::dec;
+///
+/// let request = convert::place_limit_order("BTC", "USDT", dec!(25000), Side::Buy, ExpiredType::Days7).base_amount(dec!(0.1));
+/// ```
+pub struct PlaceLimitOrder {
+    base_asset: String,
+    quote_asset: String,
+    limit_price: Decimal,
+    side: Side,
+    expired_type: ExpiredType,
+    base_amount: Option<Decimal>,
+    quote_amount: Option<Decimal>,
+    wallet_type: Option<WalletType>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl PlaceLimitOrder {
+    pub fn new(
+        base_asset: &str,
+        quote_asset: &str,
+        limit_price: Decimal,
+        side: Side,
+        expired_type: ExpiredType,
+    ) -> Self {
+        Self {
+            base_asset: base_asset.to_owned(),
+            quote_asset: quote_asset.to_owned(),
+            limit_price,
+            side,
+            expired_type,
+            base_amount: None,
+            quote_amount: None,
+            wallet_type: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn base_amount(mut self, base_amount: Decimal) -> Self {
+        self.base_amount = Some(base_amount);
+        self
+    }
+
+    pub fn quote_amount(mut self, quote_amount: Decimal) -> Self {
+        self.quote_amount = Some(quote_amount);
+        self
+    }
+
+    pub fn wallet_type(mut self, wallet_type: WalletType) -> Self {
+        self.wallet_type = Some(wallet_type);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<PlaceLimitOrder> for Request {
+    fn from(request: PlaceLimitOrder) -> Request {
+        let mut params = vec![
+            ("baseAsset".to_owned(), request.base_asset),
+            ("quoteAsset".to_owned(), request.quote_asset),
+            ("limitPrice".to_owned(), request.limit_price.to_string()),
+            ("side".to_owned(), request.side.to_string()),
+            ("expiredType".to_owned(), request.expired_type.to_string()),
+        ];
+
+        if let Some(base_amount) = request.base_amount {
+            params.push(("baseAmount".to_owned(), base_amount.to_string()));
+        }
+
+        if let Some(quote_amount) = request.quote_amount {
+            params.push(("quoteAmount".to_owned(), quote_amount.to_string()));
+        }
+
+        if let Some(wallet_type) = request.wallet_type {
+            params.push(("walletType".to_owned(), wallet_type.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/limit/placeOrder".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpiredType, PlaceLimitOrder, Side, WalletType};
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_place_limit_order_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request =
+            PlaceLimitOrder::new("BTC", "USDT", dec!(25000), Side::Buy, ExpiredType::Days7)
+                .base_amount(dec!(0.1))
+                .quote_amount(dec!(2500))
+                .wallet_type(WalletType::Spot)
+                .recv_window(5000)
+                .credentials(&credentials)
+                .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/limit/placeOrder".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("baseAsset".to_owned(), "BTC".to_string()),
+                    ("quoteAsset".to_owned(), "USDT".to_string()),
+                    ("limitPrice".to_owned(), "25000".to_string()),
+                    ("side".to_owned(), "BUY".to_string()),
+                    ("expiredType".to_owned(), "7_D".to_string()),
+                    ("baseAmount".to_owned(), "0.1".to_string()),
+                    ("quoteAmount".to_owned(), "2500".to_string()),
+                    ("walletType".to_owned(), "SPOT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
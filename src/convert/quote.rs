@@ -0,0 +1,146 @@
+use crate::convert::accept_quote::AcceptQuote;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Quote issued by `POST /sapi/v1/convert/getQuote`.
+///
+/// A quote can only be accepted until its `valid_timestamp`. Use [`Quote::accept`]
+/// rather than [`crate::convert::accept_quote`] to have the expiry checked before
+/// the request is sent.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert::quote::Quote;
+/// use rust_decimal_macros::dec;
+///
+/// let quote = Quote {
+///     quote_id: "12415572564".to_owned(),
+///     ratio: dec!(38163.7),
+///     inverse_ratio: dec!(0.0000262),
+///     valid_timestamp: 1623319461670,
+///     to_amount: dec!(3816.37),
+///     from_amount: dec!(0.1),
+/// };
+///
+/// let request = quote.accept_at(1623319451670).expect("Quote expired");
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    pub quote_id: String,
+    pub ratio: Decimal,
+    pub inverse_ratio: Decimal,
+    pub valid_timestamp: u64,
+    pub to_amount: Decimal,
+    pub from_amount: Decimal,
+}
+
+impl Quote {
+    /// Whether the quote can still be accepted at `timestamp`, in milliseconds.
+    pub fn is_valid_at(&self, timestamp: u64) -> bool {
+        timestamp < self.valid_timestamp
+    }
+
+    /// Build the `acceptQuote` request if the quote has not expired according
+    /// to the system clock.
+    pub fn accept(&self) -> Result<AcceptQuote, QuoteExpired> {
+        // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis();
+
+        self.accept_at(timestamp as u64)
+    }
+
+    /// Build the `acceptQuote` request if the quote has not expired at `timestamp`.
+    ///
+    /// Pass a timestamp adjusted by the client's `timestamp_delta` when the local
+    /// clock is not in sync with the server.
+    pub fn accept_at(&self, timestamp: u64) -> Result<AcceptQuote, QuoteExpired> {
+        if self.is_valid_at(timestamp) {
+            Ok(AcceptQuote::new(&self.quote_id))
+        } else {
+            Err(QuoteExpired {
+                quote_id: self.quote_id.clone(),
+                valid_timestamp: self.valid_timestamp,
+                timestamp,
+            })
+        }
+    }
+}
+
+/// The quote was past its `validTimestamp` when it was about to be accepted.
+#[derive(Debug, PartialEq, Eq)]
+pub struct QuoteExpired {
+    pub quote_id: String,
+    pub valid_timestamp: u64,
+    pub timestamp: u64,
+}
+
+impl fmt::Display for QuoteExpired {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "quote {} expired at {}, {}ms before {}",
+            self.quote_id,
+            self.valid_timestamp,
+            self.timestamp - self.valid_timestamp,
+            self.timestamp
+        )
+    }
+}
+
+impl Error for QuoteExpired {}
+
+#[cfg(test)]
+mod tests {
+    use super::{Quote, QuoteExpired};
+    use crate::http::{request::Request, Method};
+    use rust_decimal_macros::dec;
+
+    fn quote() -> Quote {
+        Quote {
+            quote_id: "12415572564".to_owned(),
+            ratio: dec!(38163.7),
+            inverse_ratio: dec!(0.0000262),
+            valid_timestamp: 1623319461670,
+            to_amount: dec!(3816.37),
+            from_amount: dec!(0.1),
+        }
+    }
+
+    #[test]
+    fn convert_quote_accept_before_valid_timestamp_test() {
+        let request: Request = quote().accept_at(1623319461669).unwrap().into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/acceptQuote".to_owned(),
+                credentials: None,
+                method: Method::Post,
+                params: vec![("quoteId".to_owned(), "12415572564".to_string())],
+                sign: true
+            }
+        );
+    }
+
+    #[test]
+    fn convert_quote_accept_after_valid_timestamp_test() {
+        let err = quote().accept_at(1623319461670).err().unwrap();
+
+        assert_eq!(
+            err,
+            QuoteExpired {
+                quote_id: "12415572564".to_owned(),
+                valid_timestamp: 1623319461670,
+                timestamp: 1623319461670,
+            }
+        );
+    }
+}
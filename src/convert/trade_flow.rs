@@ -0,0 +1,112 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/convert/tradeFlow`
+///
+/// Get Convert Trade History
+///
+/// * The max interval between `startTime` and `endTime` is 30 days.
+///
+/// Weight(UID): 3000
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::convert;
+///
+/// let request = convert::trade_flow(1623319461670, 1641782889000).limit(100);
+/// ```
+pub struct TradeFlow {
+    start_time: u64,
+    end_time: u64,
+    limit: Option<u32>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl TradeFlow {
+    pub fn new(start_time: u64, end_time: u64) -> Self {
+        Self {
+            start_time,
+            end_time,
+            limit: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<TradeFlow> for Request {
+    fn from(request: TradeFlow) -> Request {
+        let mut params = vec![
+            ("startTime".to_owned(), request.start_time.to_string()),
+            ("endTime".to_owned(), request.end_time.to_string()),
+        ];
+
+        if let Some(limit) = request.limit {
+            params.push(("limit".to_owned(), limit.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/convert/tradeFlow".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TradeFlow;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn convert_trade_flow_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = TradeFlow::new(1623319461670, 1641782889000)
+            .limit(100)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/convert/tradeFlow".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("startTime".to_owned(), "1623319461670".to_string()),
+                    ("endTime".to_owned(), "1641782889000".to_string()),
+                    ("limit".to_owned(), "100".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Base urls of the Binance environments.
+//!
+//! An [`Environment`] configures the REST, websocket stream and WebSocket API
+//! urls of the clients consistently.
+//!
+//! ```
+//! use binance_spot_connector_rust::{environment::Environment, http::Credentials};
+//!
+//! let environment = Environment::Testnet;
+//! let credentials = Credentials::from_hmac("api-key", "api-secret").testnet();
+//!
+//! assert_eq!(environment.rest_url(), "https://testnet.binance.vision");
+//! assert!(environment.accepts(&credentials));
+//! assert!(!Environment::Production.accepts(&credentials));
+//! ```
+use crate::http::Credentials;
+
+/// Binance environment.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/rest-api/general-api-information)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Environment {
+    /// `api.binance.com`
+    Production,
+    /// `api1.binance.com`, same as [`Environment::Production`] on another cluster.
+    Api1,
+    /// `api2.binance.com`, same as [`Environment::Production`] on another cluster.
+    Api2,
+    /// `api3.binance.com`, same as [`Environment::Production`] on another cluster.
+    Api3,
+    /// `api4.binance.com`, same as [`Environment::Production`] on another cluster.
+    Api4,
+    /// `data-api.binance.vision`, serving public market data only.
+    MarketData,
+    /// `testnet.binance.vision`
+    Testnet,
+    /// Any other server, e.g. a proxy or a mock server.
+    Custom {
+        rest_url: String,
+        stream_url: String,
+        ws_api_url: String,
+    },
+}
+
+impl Environment {
+    /// Base url of the REST API, without trailing slash.
+    pub fn rest_url(&self) -> &str {
+        match self {
+            Environment::Production => "https://api.binance.com",
+            Environment::Api1 => "https://api1.binance.com",
+            Environment::Api2 => "https://api2.binance.com",
+            Environment::Api3 => "https://api3.binance.com",
+            Environment::Api4 => "https://api4.binance.com",
+            Environment::MarketData => "https://data-api.binance.vision",
+            Environment::Testnet => "https://testnet.binance.vision",
+            Environment::Custom { rest_url, .. } => rest_url,
+        }
+    }
+
+    /// Base url of the websocket streams, without path, e.g. passed to
+    /// [`crate::websocket::StreamUrl::base_url`].
+    pub fn stream_url(&self) -> &str {
+        match self {
+            Environment::Production
+            | Environment::Api1
+            | Environment::Api2
+            | Environment::Api3
+            | Environment::Api4 => "wss://stream.binance.com:9443",
+            Environment::MarketData => "wss://data-stream.binance.vision",
+            Environment::Testnet => "wss://stream.testnet.binance.vision",
+            Environment::Custom { stream_url, .. } => stream_url,
+        }
+    }
+
+    /// Url of the WebSocket API.
+    pub fn ws_api_url(&self) -> &str {
+        match self {
+            Environment::Production
+            | Environment::Api1
+            | Environment::Api2
+            | Environment::Api3
+            | Environment::Api4
+            | Environment::MarketData => "wss://ws-api.binance.com:443/ws-api/v3",
+            Environment::Testnet => "wss://ws-api.testnet.binance.vision/ws-api/v3",
+            Environment::Custom { ws_api_url, .. } => ws_api_url,
+        }
+    }
+
+    /// Whether the environment trades real funds.
+    pub fn is_production(&self) -> bool {
+        !matches!(self, Environment::Testnet | Environment::Custom { .. })
+    }
+
+    /// Whether `credentials` may be used against this environment.
+    ///
+    /// Credentials marked with [`Credentials::testnet`] are refused by
+    /// production environments. Unmarked credentials are always accepted.
+    pub fn accepts(&self, credentials: &Credentials) -> bool {
+        !(credentials.is_testnet() && self.is_production())
+    }
+
+    /// Known environment serving `url`, `None` for other servers.
+    ///
+    /// ```
+    /// use binance_spot_connector_rust::environment::Environment;
+    ///
+    /// assert_eq!(
+    ///     Environment::from_url("wss://stream.testnet.binance.vision/ws"),
+    ///     Some(Environment::Testnet)
+    /// );
+    /// assert_eq!(Environment::from_url("http://localhost:8080"), None);
+    /// ```
+    pub fn from_url(url: &str) -> Option<Self> {
+        [
+            Environment::Production,
+            Environment::Api1,
+            Environment::Api2,
+            Environment::Api3,
+            Environment::Api4,
+            Environment::MarketData,
+            Environment::Testnet,
+        ]
+        .into_iter()
+        .find(|environment| {
+            [
+                environment.rest_url(),
+                environment.stream_url(),
+                environment.ws_api_url(),
+            ]
+            .iter()
+            .any(|base_url| {
+                url.strip_prefix(base_url).map_or(false, |path| {
+                    path.is_empty() || path.starts_with(['/', '?'])
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Environment;
+    use crate::http::Credentials;
+
+    #[test]
+    fn environment_from_url_test() {
+        assert_eq!(
+            Environment::from_url("https://api.binance.com"),
+            Some(Environment::Production)
+        );
+        assert_eq!(
+            Environment::from_url("https://api3.binance.com/api/v3/ping"),
+            Some(Environment::Api3)
+        );
+        assert_eq!(
+            Environment::from_url("wss://ws-api.binance.com:443/ws-api/v3"),
+            Some(Environment::Production)
+        );
+        assert_eq!(
+            Environment::from_url("wss://data-stream.binance.vision/stream?streams=a@trade"),
+            Some(Environment::MarketData)
+        );
+        assert_eq!(
+            Environment::from_url("https://testnet.binance.vision"),
+            Some(Environment::Testnet)
+        );
+        assert_eq!(Environment::from_url("https://api.binance.com.evil"), None);
+    }
+
+    #[test]
+    fn environment_accepts_test() {
+        let credentials = Credentials::from_hmac("api-key", "api-secret");
+        let testnet_credentials = credentials.clone().testnet();
+        let custom = Environment::Custom {
+            rest_url: "http://localhost:8080".to_owned(),
+            stream_url: "ws://localhost:8080".to_owned(),
+            ws_api_url: "ws://localhost:8080/ws-api/v3".to_owned(),
+        };
+
+        assert!(Environment::Production.accepts(&credentials));
+        assert!(Environment::Testnet.accepts(&credentials));
+        assert!(!Environment::Production.accepts(&testnet_credentials));
+        assert!(!Environment::Api1.accepts(&testnet_credentials));
+        assert!(!Environment::MarketData.accepts(&testnet_credentials));
+        assert!(Environment::Testnet.accepts(&testnet_credentials));
+        assert!(custom.accepts(&testnet_credentials));
+    }
+}
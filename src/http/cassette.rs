@@ -0,0 +1,471 @@
+//! Recording of HTTP interactions, replayed without network access.
+//!
+//! A [`Cassette`] set on the `ureq` or `hyper` client in record mode saves
+//! each request with its response to a JSON file. In replay mode, the client
+//! answers the requests from the file instead of sending them.
+//!
+//! Recorded requests hold the method, path and parameters without
+//! `timestamp` and `signature`, and are matched regardless of the parameters
+//! order and of the client order ids, which a
+//! [`ClientOrderIdGenerator`](crate::trade::client_order_id::ClientOrderIdGenerator)
+//! sets anew on each run. The `X-MBX-APIKEY` header is never recorded and account
+//! identifiers are redacted from the parameters and the JSON bodies, see
+//! [`Cassette::redact`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use binance_spot_connector_rust::{
+//!     http::{cassette::Cassette, Credentials},
+//!     trade,
+//!     ureq::BinanceHttpClient,
+//! };
+//!
+//! // Record once against the testnet...
+//! let credentials = Credentials::from_hmac("api-key", "api-secret");
+//! let client = BinanceHttpClient::with_url("https://testnet.binance.vision")
+//!     .credentials(credentials)
+//!     .cassette(Cassette::record("tests/cassettes/account.json"));
+//! client.send(trade::account()).expect("Request failed");
+//!
+//! // ...then replay offline in CI.
+//! let cassette = Cassette::replay("tests/cassettes/account.json").expect("Missing cassette");
+//! let client = BinanceHttpClient::with_url("https://testnet.binance.vision")
+//!     .credentials(Credentials::from_hmac("api-key", "api-secret"))
+//!     .cassette(cassette);
+//! let data = client
+//!     .send(trade::account())
+//!     .expect("Request failed")
+//!     .into_body_str()
+//!     .expect("Failed to parse body");
+//! ```
+
+use crate::http::Method;
+use crate::trade::client_order_id::client_order_id_params;
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Replacement of the redacted values.
+pub const REDACTED: &str = "REDACTED";
+
+/// Parameters left out of the recorded requests, they change on every request.
+const VOLATILE_PARAMS: &[&str] = &["timestamp", "signature"];
+
+/// Headers describing the transfer of the body, not recorded since the body
+/// is stored decoded.
+const TRANSFER_HEADERS: &[&str] = &[
+    "content-length",
+    "content-encoding",
+    "transfer-encoding",
+    "connection",
+];
+
+/// Account identifiers redacted by default.
+const DEFAULT_REDACTED: &[&str] = &["uid", "email", "subAccountId", "listenKey"];
+
+/// Whether the cassette saves or serves the interactions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Send the requests and save them with their response.
+    Record,
+    /// Answer the requests from the cassette, without network access.
+    Replay,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub params: Vec<(String, String)>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl RecordedResponse {
+    /// Response with a decoded body, without the headers describing its
+    /// transfer.
+    pub(crate) fn new(status: u16, headers: Vec<(String, String)>, body: String) -> Self {
+        let headers = headers
+            .into_iter()
+            .filter(|(key, _)| {
+                !TRANSFER_HEADERS
+                    .iter()
+                    .any(|header| header.eq_ignore_ascii_case(key))
+            })
+            .collect();
+
+        Self {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+/// Request and response pair.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Interaction {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+/// Cassette error.
+#[derive(Debug)]
+pub enum CassetteError {
+    /// The cassette file could not be read or written.
+    Io(io::Error),
+    /// The cassette file is not valid.
+    Deserialize(serde_json::Error),
+    /// No recorded interaction matches the request in replay mode.
+    NotRecorded(RecordedRequest),
+}
+
+#[derive(Debug)]
+struct Inner {
+    path: Option<PathBuf>,
+    mode: Mode,
+    interactions: Vec<Interaction>,
+    replayed: Vec<bool>,
+    redacted: Vec<String>,
+}
+
+/// Recorded HTTP interactions, shared by the clones of a client.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Cassette {
+    /// Record the interactions to the file at `path`, replaced after each
+    /// interaction.
+    pub fn record(path: impl AsRef<Path>) -> Self {
+        Self::new(Some(path.as_ref().to_owned()), Mode::Record, vec![])
+    }
+
+    /// Replay the interactions recorded in the file at `path`.
+    pub fn replay(path: impl AsRef<Path>) -> Result<Self, CassetteError> {
+        let data = fs::read_to_string(path).map_err(CassetteError::Io)?;
+        let interactions = serde_json::from_str(&data).map_err(CassetteError::Deserialize)?;
+
+        Ok(Self::from_interactions(interactions))
+    }
+
+    /// Replay `interactions`, e.g. written by hand.
+    pub fn from_interactions(interactions: Vec<Interaction>) -> Self {
+        Self::new(None, Mode::Replay, interactions)
+    }
+
+    fn new(path: Option<PathBuf>, mode: Mode, interactions: Vec<Interaction>) -> Self {
+        let inner = Inner {
+            path,
+            mode,
+            replayed: vec![false; interactions.len()],
+            interactions,
+            redacted: DEFAULT_REDACTED
+                .iter()
+                .map(|name| name.to_string())
+                .collect(),
+        };
+
+        Self {
+            inner: Arc::new(Mutex::new(inner)),
+        }
+    }
+
+    /// Redact the parameter, response header and JSON field `name` in
+    /// addition to `uid`, `email`, `subAccountId` and `listenKey`.
+    pub fn redact(self, name: &str) -> Self {
+        self.inner.lock().unwrap().redacted.push(name.to_owned());
+        self
+    }
+
+    pub fn mode(&self) -> Mode {
+        self.inner.lock().unwrap().mode
+    }
+
+    /// Interactions recorded so far, or loaded for replay.
+    pub fn interactions(&self) -> Vec<Interaction> {
+        self.inner.lock().unwrap().interactions.clone()
+    }
+
+    /// Response recorded for the request.
+    ///
+    /// Interactions are replayed in order, a request matching only replayed
+    /// interactions gets the last matching response again.
+    pub(crate) fn replay_response(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &[(String, String)],
+    ) -> Result<RecordedResponse, CassetteError> {
+        let mut inner = self.inner.lock().unwrap();
+        let request = inner.recorded_request(method, path, params);
+
+        let matching: Vec<usize> = inner
+            .interactions
+            .iter()
+            .enumerate()
+            .filter(|(_, interaction)| {
+                same_request(
+                    &interaction.request,
+                    &request,
+                    client_order_id_params(method, path),
+                )
+            })
+            .map(|(index, _)| index)
+            .collect();
+        let index = matching
+            .iter()
+            .find(|index| !inner.replayed[**index])
+            .or(matching.last())
+            .copied()
+            .ok_or(CassetteError::NotRecorded(request))?;
+        inner.replayed[index] = true;
+
+        Ok(inner.interactions[index].response.clone())
+    }
+
+    /// Save the request with its response, redacted.
+    ///
+    /// The response is served whether the file could be written or not,
+    /// failures are logged.
+    pub(crate) fn record_response(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &[(String, String)],
+        response: RecordedResponse,
+    ) {
+        let mut inner = self.inner.lock().unwrap();
+        let request = inner.recorded_request(method, path, params);
+        let response = inner.redact_response(response);
+        inner.interactions.push(Interaction { request, response });
+        inner.replayed.push(true);
+
+        if let Some(path) = &inner.path {
+            let written = serde_json::to_string_pretty(&inner.interactions)
+                .map_err(CassetteError::Deserialize)
+                .and_then(|data| fs::write(path, data).map_err(CassetteError::Io));
+            if let Err(err) = written {
+                log::warn!("Failed to write cassette {}: {:?}", path.display(), err);
+            }
+        }
+    }
+}
+
+impl Inner {
+    fn is_redacted(&self, name: &str) -> bool {
+        self.redacted
+            .iter()
+            .any(|redacted| redacted.eq_ignore_ascii_case(name))
+    }
+
+    fn recorded_request(
+        &self,
+        method: &Method,
+        path: &str,
+        params: &[(String, String)],
+    ) -> RecordedRequest {
+        let params = params
+            .iter()
+            .filter(|(key, _)| !VOLATILE_PARAMS.contains(&key.as_str()))
+            .map(|(key, value)| match self.is_redacted(key) {
+                true => (key.to_owned(), REDACTED.to_owned()),
+                false => (key.to_owned(), value.to_owned()),
+            })
+            .collect();
+
+        RecordedRequest {
+            method: method.as_ref().to_owned(),
+            path: path.to_owned(),
+            params,
+        }
+    }
+
+    fn redact_response(&self, mut response: RecordedResponse) -> RecordedResponse {
+        for (key, value) in response.headers.iter_mut() {
+            if self.is_redacted(key) {
+                *value = REDACTED.to_owned();
+            }
+        }
+        // The body is kept as sent by the server, only the redacted values
+        // are replaced.
+        if serde_json::from_str::<IgnoredAny>(&response.body).is_ok() {
+            response.body = self.redact_body(&response.body);
+        }
+
+        response
+    }
+
+    /// Replace the string and number values of the redacted fields of a
+    /// valid JSON body.
+    fn redact_body(&self, body: &str) -> String {
+        let bytes = body.as_bytes();
+        let mut redacted = String::with_capacity(body.len());
+        let mut copied = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != b'"' {
+                i += 1;
+                continue;
+            }
+            let key_end = string_end(bytes, i);
+            let key = &body[i + 1..key_end - 1];
+            let colon = skip_whitespace(bytes, key_end);
+            i = key_end;
+            if bytes.get(colon) != Some(&b':') || !self.is_redacted(key) {
+                continue;
+            }
+
+            let value = skip_whitespace(bytes, colon + 1);
+            let (value_end, replacement) = match bytes.get(value) {
+                Some(b'"') => (string_end(bytes, value), format!("\"{}\"", REDACTED)),
+                Some(b'-' | b'0'..=b'9') => (number_end(bytes, value), "0".to_owned()),
+                _ => continue,
+            };
+            redacted.push_str(&body[copied..value]);
+            redacted.push_str(&replacement);
+            copied = value_end;
+            i = value_end;
+        }
+        redacted.push_str(&body[copied..]);
+
+        redacted
+    }
+}
+
+/// Index after the JSON string starting at `start`.
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Index after the JSON number starting at `start`.
+fn number_end(bytes: &[u8], start: usize) -> usize {
+    start
+        + bytes[start..]
+            .iter()
+            .take_while(|byte| matches!(byte, b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9'))
+            .count()
+}
+
+fn skip_whitespace(bytes: &[u8], start: usize) -> usize {
+    start
+        + bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_whitespace())
+            .count()
+}
+
+/// Whether the requests match, regardless of the parameters order and of
+/// the `ignored` parameters.
+fn same_request(recorded: &RecordedRequest, request: &RecordedRequest, ignored: &[&str]) -> bool {
+    let sorted = |params: &[(String, String)]| {
+        let mut params: Vec<(String, String)> = params
+            .iter()
+            .filter(|(key, _)| !ignored.contains(&key.as_str()))
+            .cloned()
+            .collect();
+        params.sort();
+        params
+    };
+
+    recorded.method == request.method
+        && recorded.path == request.path
+        && sorted(&recorded.params) == sorted(&request.params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cassette, CassetteError, Mode, RecordedResponse, REDACTED};
+    use crate::http::Method;
+
+    fn params(params: &[(&str, &str)]) -> Vec<(String, String)> {
+        params
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn cassette_record_replay_test() {
+        let path = std::env::temp_dir().join(format!("cassette_{}.json", std::process::id()));
+        let cassette = Cassette::record(&path).redact("x-mbx-uuid");
+        assert_eq!(cassette.mode(), Mode::Record);
+
+        cassette
+            .record_response(
+                &Method::Get,
+                "/api/v3/account",
+                &params(&[
+                    ("omitZeroBalances", "true"),
+                    ("timestamp", "1"),
+                    ("signature", "abc"),
+                ]),
+                RecordedResponse {
+                    status: 200,
+                    headers: vec![("x-mbx-uuid".to_owned(), "1234".to_owned())],
+                    body: r#"{"uid":354937868,"balances":[{"asset":"BTC","free":"1.50000000","email":"a@b.c"}], "updateTime" : 1.0e3, "email" : "d@e.f"}"#
+                        .to_owned(),
+                },
+            );
+
+        let interaction = &cassette.interactions()[0];
+        assert_eq!(
+            interaction.request.params,
+            params(&[("omitZeroBalances", "true")])
+        );
+        assert_eq!(interaction.response.headers[0].1, REDACTED);
+        assert_eq!(
+            interaction.response.body,
+            r#"{"uid":0,"balances":[{"asset":"BTC","free":"1.50000000","email":"REDACTED"}], "updateTime" : 1.0e3, "email" : "REDACTED"}"#
+        );
+
+        let cassette = Cassette::record(std::env::temp_dir().join("missing").join("cassette.json"));
+        cassette.record_response(
+            &Method::Get,
+            "/api/v3/ping",
+            &[],
+            RecordedResponse {
+                status: 200,
+                headers: vec![],
+                body: "not json, uid: 1".to_owned(),
+            },
+        );
+        assert_eq!(cassette.interactions()[0].response.body, "not json, uid: 1");
+
+        let cassette = Cassette::replay(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cassette.mode(), Mode::Replay);
+        let response = cassette
+            .replay_response(
+                &Method::Get,
+                "/api/v3/account",
+                &params(&[("timestamp", "2"), ("omitZeroBalances", "true")]),
+            )
+            .unwrap();
+        assert_eq!(response.status, 200);
+
+        match cassette.replay_response(&Method::Get, "/api/v3/account", &[]) {
+            Err(CassetteError::NotRecorded(request)) => assert_eq!(request.path, "/api/v3/account"),
+            _ => panic!("Invalid response"),
+        }
+    }
+}
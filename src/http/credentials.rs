@@ -4,7 +4,9 @@
 /// valid API credentials.
 ///
 /// Note: Production and TESTNET API Credentials are not
-/// interchangeable.
+/// interchangeable. Testnet credentials marked with [`Credentials::testnet`]
+/// are refused by the clients configured for a production
+/// [`crate::environment::Environment`].
 ///
 /// [API Documentation](https://developers.binance.com/docs/rebate/quick-start#api-key-restrictions)
 ///
@@ -12,6 +14,7 @@
 pub struct Credentials {
     pub api_key: String,
     pub signature: Signature,
+    testnet: bool,
 }
 
 #[derive(PartialEq, Eq, Clone)]
@@ -43,6 +46,7 @@ impl Credentials {
             signature: Signature::Hmac(HmacSignature {
                 api_secret: api_secret.into(),
             }),
+            testnet: false,
         }
     }
 
@@ -50,14 +54,27 @@ impl Credentials {
         Credentials {
             api_key: api_key.into(),
             signature: Signature::Ed25519(Ed25519Signature { key: key.into() }),
+            testnet: false,
         }
     }
+
+    /// Mark the credentials as issued by the testnet.
+    pub fn testnet(mut self) -> Self {
+        self.testnet = true;
+        self
+    }
+
+    /// Whether the credentials were issued by the testnet.
+    pub fn is_testnet(&self) -> bool {
+        self.testnet
+    }
 }
 
 impl std::fmt::Debug for Credentials {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Credentials")
             .field("api_key", &"[redacted]")
+            .field("testnet", &self.testnet)
             .finish()
     }
 }
@@ -1,7 +1,10 @@
 mod credentials;
 mod method;
 
+#[cfg(any(feature = "enable-ureq", feature = "enable-hyper"))]
+pub mod cassette;
 pub mod error;
+pub mod pagination;
 pub mod request;
 
 pub use credentials::Credentials;
@@ -0,0 +1,349 @@
+use crate::http::request::Request;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Request weight limit per minute applied to an IP address by default.
+pub const DEFAULT_WEIGHT_LIMIT: u32 = 6000;
+
+/// Request weight limit per minute applied to an IP address on `/sapi` endpoints.
+pub const SAPI_WEIGHT_LIMIT: u32 = 12000;
+
+/// Sequence of requests walking a result set page per page.
+///
+/// Implemented by the history downloaders such as [`crate::market::kline_history`]
+/// and consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// After each page, the client reads the weight header returned by the server and
+/// waits for the next weight interval if the next request would exceed the weight limit.
+pub trait Paginate {
+    /// Response body of a page.
+    type Page: DeserializeOwned;
+    /// Item yielded to the caller.
+    type Item;
+
+    /// Request for the next page, `None` once all pages have been fetched.
+    fn next_request(&self) -> Option<Request>;
+
+    /// Consume a page, returning its items in order.
+    fn handle_page(&mut self, page: Self::Page) -> Vec<Self::Item>;
+
+    /// Weight of a page request.
+    fn page_weight(&self) -> u32;
+
+    /// Request weight per minute not to exceed, `None` to never wait between pages.
+    fn max_weight(&self) -> Option<u32>;
+
+    /// Response header holding the weight used in the current interval.
+    fn weight_header(&self) -> &'static str {
+        "x-mbx-used-weight-1m"
+    }
+
+    /// Interval over which the weight is counted, in milliseconds.
+    fn weight_interval(&self) -> u64 {
+        60_000
+    }
+
+    /// Time to wait before the next page given the weight used in the current interval.
+    fn throttle(&self, used_weight: Option<u32>, timestamp: u64) -> Option<Duration> {
+        let max_weight = self.max_weight()?;
+        let used_weight = used_weight?;
+
+        if used_weight + self.page_weight() <= max_weight || self.next_request().is_none() {
+            return None;
+        }
+
+        // Weight is counted per wall clock interval.
+        let interval = self.weight_interval();
+        Some(Duration::from_millis(interval - timestamp % interval))
+    }
+}
+
+/// How an endpoint returns the records of a time window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowPaging {
+    /// Pages of `n` records, requested with `offset`/`limit` or `current`/`size`.
+    Pages(u32),
+    /// A single page of at most `n` records. A full page may be truncated,
+    /// so the window is split in half and requested again.
+    Capped(u32),
+}
+
+/// Endpoint returning the records between `startTime` and `endTime`.
+///
+/// Implemented by the wallet and margin history requests, and consumed with
+/// [`TimeWindowHistory`].
+pub trait TimeWindowed: Clone {
+    /// Response body of a page.
+    type Page: DeserializeOwned;
+    /// Record of a page.
+    type Row;
+
+    /// Longest time range accepted between `startTime` and `endTime`, in
+    /// milliseconds, `None` if unlimited.
+    fn max_window(&self) -> Option<u64>;
+
+    fn paging(&self) -> WindowPaging;
+
+    /// Request for the records between `start_time` and `end_time` included,
+    /// `page` starting at 0.
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request;
+
+    /// Records of a page.
+    fn rows(page: Self::Page) -> Vec<Self::Row>;
+
+    /// Weight of a page request.
+    fn page_weight(&self) -> u32;
+
+    /// Request weight not to exceed per interval by default.
+    fn default_weight_limit(&self) -> u32 {
+        SAPI_WEIGHT_LIMIT
+    }
+
+    /// Response header holding the weight used in the current interval.
+    fn weight_header(&self) -> &'static str {
+        "x-sapi-used-ip-weight-1m"
+    }
+
+    /// Interval over which the weight is counted, in milliseconds.
+    fn weight_interval(&self) -> u64 {
+        60_000
+    }
+}
+
+/// Paginated history of a [`TimeWindowed`] endpoint.
+///
+/// Slices `[start_time, end_time]` into windows accepted by the endpoint and
+/// follows the pages of each window. Records are returned window after window,
+/// in the order of the server within a window.
+///
+/// Paging parameters and times set on the request are overridden.
+///
+/// The history is consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::{http::pagination::TimeWindowHistory, wallet};
+///
+/// let history = TimeWindowHistory::new(wallet::deposit_history().coin("BNB"), 1577836800000)
+///     .end_time(1672531200000);
+/// ```
+pub struct TimeWindowHistory<E> {
+    endpoint: E,
+    start_time: u64,
+    end_time: u64,
+    window: Option<u64>,
+    page: u32,
+    weight_limit: Option<u32>,
+}
+
+impl<E: TimeWindowed> TimeWindowHistory<E> {
+    /// Records of `endpoint` from `start_time` until now.
+    pub fn new(endpoint: E, start_time: u64) -> Self {
+        // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+        let end_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis();
+
+        Self {
+            window: endpoint.max_window(),
+            weight_limit: Some(endpoint.default_weight_limit()),
+            endpoint,
+            start_time,
+            end_time: end_time as u64,
+            page: 0,
+        }
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = end_time;
+        self
+    }
+
+    /// Request weight per interval not to exceed, `None` to never wait between pages.
+    pub fn weight_limit(mut self, weight_limit: Option<u32>) -> Self {
+        self.weight_limit = weight_limit;
+        self
+    }
+
+    /// Start of the window not downloaded yet.
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    fn window_end_time(&self) -> u64 {
+        match self.window {
+            Some(window) => self
+                .end_time
+                .min(self.start_time.saturating_add(window - 1)),
+            None => self.end_time,
+        }
+    }
+
+    fn next_window(&mut self) {
+        self.start_time = self.window_end_time() + 1;
+        self.window = self.endpoint.max_window();
+        self.page = 0;
+    }
+}
+
+impl<E: TimeWindowed> Paginate for TimeWindowHistory<E> {
+    type Page = E::Page;
+    type Item = E::Row;
+
+    fn next_request(&self) -> Option<Request> {
+        if self.start_time > self.end_time {
+            return None;
+        }
+
+        Some(
+            self.endpoint
+                .window_request(self.start_time, self.window_end_time(), self.page),
+        )
+    }
+
+    fn handle_page(&mut self, page: E::Page) -> Vec<E::Row> {
+        let rows = E::rows(page);
+
+        match self.endpoint.paging() {
+            WindowPaging::Pages(size) if rows.len() >= size as usize => self.page += 1,
+            WindowPaging::Capped(size) if rows.len() >= size as usize => {
+                let window = self.window_end_time() - self.start_time + 1;
+                if window > 1 {
+                    self.window = Some(window / 2);
+                    return vec![];
+                }
+                self.next_window();
+            }
+            _ => self.next_window(),
+        }
+
+        rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        self.endpoint.page_weight()
+    }
+
+    fn max_weight(&self) -> Option<u32> {
+        self.weight_limit
+    }
+
+    fn weight_header(&self) -> &'static str {
+        self.endpoint.weight_header()
+    }
+
+    fn weight_interval(&self) -> u64 {
+        self.endpoint.weight_interval()
+    }
+}
+
+/// Page of records with their total count.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Rows<T> {
+    #[serde(default = "Vec::new")]
+    pub rows: Vec<T>,
+    #[serde(default)]
+    pub total: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Paginate, TimeWindowHistory, TimeWindowed, WindowPaging};
+    use crate::http::{request::Request, Method};
+    use crate::wallet;
+
+    #[derive(Clone)]
+    struct Endpoint(WindowPaging);
+
+    impl TimeWindowed for Endpoint {
+        type Page = Vec<u64>;
+        type Row = u64;
+
+        fn max_window(&self) -> Option<u64> {
+            Some(100)
+        }
+
+        fn paging(&self) -> WindowPaging {
+            self.0
+        }
+
+        fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+            Request {
+                path: "/path".to_owned(),
+                method: Method::Get,
+                params: vec![
+                    ("startTime".to_owned(), start_time.to_string()),
+                    ("endTime".to_owned(), end_time.to_string()),
+                    ("page".to_owned(), page.to_string()),
+                ],
+                credentials: None,
+                sign: false,
+            }
+        }
+
+        fn rows(page: Vec<u64>) -> Vec<u64> {
+            page
+        }
+
+        fn page_weight(&self) -> u32 {
+            1
+        }
+    }
+
+    fn params(request: Option<Request>) -> Vec<String> {
+        request
+            .unwrap()
+            .params
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect()
+    }
+
+    #[test]
+    fn time_window_history_pages_test() {
+        let mut history = TimeWindowHistory::new(Endpoint(WindowPaging::Pages(2)), 0).end_time(249);
+
+        assert_eq!(params(history.next_request()), vec!["0", "99", "0"]);
+        assert_eq!(history.handle_page(vec![1, 2]), vec![1, 2]);
+        assert_eq!(params(history.next_request()), vec!["0", "99", "1"]);
+        assert_eq!(history.handle_page(vec![3]), vec![3]);
+        assert_eq!(params(history.next_request()), vec!["100", "199", "0"]);
+        assert_eq!(history.handle_page(vec![]), Vec::<u64>::new());
+        assert_eq!(params(history.next_request()), vec!["200", "249", "0"]);
+        history.handle_page(vec![]);
+        assert_eq!(history.next_request(), None);
+    }
+
+    #[test]
+    fn time_window_history_split_capped_window_test() {
+        let mut history =
+            TimeWindowHistory::new(Endpoint(WindowPaging::Capped(2)), 0).end_time(199);
+
+        assert_eq!(params(history.next_request()), vec!["0", "99", "0"]);
+        assert_eq!(history.handle_page(vec![1, 2]), Vec::<u64>::new());
+        assert_eq!(params(history.next_request()), vec!["0", "49", "0"]);
+        assert_eq!(history.handle_page(vec![1]), vec![1]);
+        assert_eq!(params(history.next_request()), vec!["50", "149", "0"]);
+    }
+
+    #[test]
+    fn time_window_history_deposit_history_test() {
+        let history = TimeWindowHistory::new(wallet::deposit_history().coin("BNB"), 0);
+
+        assert_eq!(
+            history.next_request().unwrap().params,
+            vec![
+                ("coin".to_owned(), "BNB".to_string()),
+                ("startTime".to_owned(), "0".to_string()),
+                ("endTime".to_owned(), "7775999999".to_string()),
+                ("offset".to_owned(), "0".to_string()),
+                ("limit".to_owned(), "1000".to_string()),
+            ]
+        );
+    }
+}
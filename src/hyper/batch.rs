@@ -0,0 +1,239 @@
+use crate::http::request::Request;
+use crate::hyper::{BinanceHttpClient, Error, Response};
+use crate::trade::{
+    self,
+    batch::{order_count, OrderBatch, OrderRateLimiter, RateLimitUsage},
+};
+use futures_util::future::join_all;
+use hyper::client::connect::Connect;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl<T> BinanceHttpClient<T>
+where
+    T: Connect + Clone + Send + Sync + 'static,
+{
+    /// Send the requests of an [`OrderBatch`], up to `max_concurrency` at a
+    /// time, waiting whenever an order count limit is reached.
+    ///
+    /// Returns one result per request, in the order of the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     http::Credentials,
+    ///     hyper::BinanceHttpClient,
+    ///     trade::{self, batch::{BulkCancel, OrderBatch}, order::Side},
+    /// };
+    /// use rust_decimal_macros::dec;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    ///     let client = BinanceHttpClient::default().credentials(credentials);
+    ///
+    ///     let orders = ["BNBUSDT", "ETHUSDT", "BTCUSDT"]
+    ///         .map(|symbol| trade::new_order(symbol, Side::Buy, "MARKET").quote_order_qty(dec!(20)));
+    ///     for result in client.send_batch(OrderBatch::new(orders)).await {
+    ///         match result {
+    ///             Ok(response) => println!("{:?}", response.into_body_str().await),
+    ///             Err(err) => println!("{:?}", err),
+    ///         }
+    ///     }
+    ///
+    ///     client.send_batch(BulkCancel::new().symbol("BNBUSDT").order_id("ETHUSDT", 12)).await;
+    /// }
+    /// ```
+    pub async fn send_batch(&self, batch: impl Into<OrderBatch>) -> Vec<Result<Response, Error>> {
+        let (requests, max_concurrency, check_usage, mut limiter) = batch.into().into_parts();
+
+        if check_usage && !requests.is_empty() {
+            match self.order_rate_limiter().await {
+                Ok(usage) => limiter = usage,
+                Err(err) => log::warn!("Failed to get the order count usage: {:?}", err),
+            }
+        }
+
+        let mut results = Vec::with_capacity(requests.len());
+        let mut requests = requests.into_iter().peekable();
+        while let Some(next) = requests.peek() {
+            let timestamp = now();
+            if let Some(delay) = limiter.delay(order_count(next), timestamp) {
+                log::debug!("Order count limit reached, waiting {:?}", delay);
+                tokio::time::sleep(delay).await;
+                continue;
+            }
+
+            let available = limiter.available(timestamp);
+            let mut wave: Vec<Request> = vec![];
+            let mut count = 0;
+            while let Some(next) = requests.peek() {
+                if wave.len() == max_concurrency
+                    || !wave.is_empty() && count + order_count(next) > available
+                {
+                    break;
+                }
+                count += order_count(next);
+                wave.extend(requests.next());
+            }
+            limiter.record(count, timestamp);
+
+            let responses = join_all(wave.into_iter().map(|request| self.send(request))).await;
+
+            let timestamp = now();
+            for response in responses.iter().flatten() {
+                limiter.update(|name| response.header(name), timestamp);
+            }
+            results.extend(responses);
+        }
+
+        results
+    }
+
+    async fn order_rate_limiter(&self) -> Result<OrderRateLimiter, Error> {
+        let data = self
+            .send(trade::order_limit_usage())
+            .await?
+            .into_body_str()
+            .await?;
+        let usage: Vec<RateLimitUsage> = serde_json::from_str(&data).map_err(Error::Deserialize)?;
+
+        Ok(OrderRateLimiter::from_usage(&usage, now()))
+    }
+}
+
+fn now() -> u64 {
+    // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::http::cassette::{Cassette, Interaction, RecordedRequest, RecordedResponse};
+    use crate::hyper::BinanceHttpClient;
+    use crate::trade::{
+        self,
+        batch::{BulkCancel, OrderBatch},
+        order::Side,
+    };
+    use hyper::client::HttpConnector;
+    use hyper::Client;
+    use rust_decimal_macros::dec;
+
+    fn interaction(
+        method: &str,
+        path: &str,
+        symbol: Option<&str>,
+        status: u16,
+        headers: &[(&str, &str)],
+        body: &str,
+    ) -> Interaction {
+        let mut params = vec![];
+        if let Some(symbol) = symbol {
+            params.push(("symbol".to_owned(), symbol.to_owned()));
+        }
+        if method == "POST" {
+            params.extend([
+                ("side".to_owned(), "BUY".to_owned()),
+                ("type".to_owned(), "MARKET".to_owned()),
+                ("quantity".to_owned(), "1".to_owned()),
+            ]);
+        }
+
+        Interaction {
+            request: RecordedRequest {
+                method: method.to_owned(),
+                path: path.to_owned(),
+                params,
+            },
+            response: RecordedResponse {
+                status,
+                headers: headers
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.to_string()))
+                    .collect(),
+                body: body.to_owned(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn client_send_batch_test() {
+        let count = [("x-mbx-order-count-10s", "2")];
+        let cassette = Cassette::from_interactions(vec![
+            interaction(
+                "GET",
+                "/api/v3/rateLimit/order",
+                None,
+                200,
+                &[],
+                r#"[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50,"count":0}]"#,
+            ),
+            interaction(
+                "POST",
+                "/api/v3/order",
+                Some("BNBUSDT"),
+                200,
+                &count,
+                "BNBUSDT",
+            ),
+            interaction(
+                "POST",
+                "/api/v3/order",
+                Some("XYZUSDT"),
+                400,
+                &[],
+                r#"{"code":-1121,"msg":"Invalid symbol."}"#,
+            ),
+            interaction(
+                "POST",
+                "/api/v3/order",
+                Some("ETHUSDT"),
+                200,
+                &count,
+                "ETHUSDT",
+            ),
+            interaction(
+                "DELETE",
+                "/api/v3/openOrders",
+                Some("BNBUSDT"),
+                200,
+                &[],
+                "[]",
+            ),
+        ]);
+        let client = Client::builder().build(HttpConnector::new());
+        let client = BinanceHttpClient::new(client, "https://base-url.com").cassette(cassette);
+
+        let batch = OrderBatch::new(
+            ["BNBUSDT", "XYZUSDT", "ETHUSDT"]
+                .map(|symbol| trade::new_order(symbol, Side::Buy, "MARKET").quantity(dec!(1))),
+        )
+        .max_concurrency(2);
+        let mut bodies = vec![];
+        for result in client.send_batch(batch).await {
+            bodies.push(result.unwrap().into_body_str().await.ok());
+        }
+        assert_eq!(
+            bodies,
+            vec![Some("BNBUSDT".to_owned()), None, Some("ETHUSDT".to_owned())]
+        );
+
+        let results = client.send_batch(BulkCancel::new().symbol("BNBUSDT")).await;
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results
+                .into_iter()
+                .next()
+                .unwrap()
+                .unwrap()
+                .into_body_str()
+                .await
+                .unwrap(),
+            "[]"
+        );
+    }
+}
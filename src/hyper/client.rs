@@ -1,8 +1,12 @@
+use crate::environment::Environment;
+use crate::http::cassette::{Cassette, CassetteError, Mode, RecordedResponse};
 use crate::http::{request::Request, Credentials, Method};
 use crate::hyper::{Error, Response};
+use crate::trade::client_order_id::{assign_client_order_ids, ClientOrderIdGenerator};
 use crate::version::VERSION;
 use hyper::{client::connect::Connect, client::HttpConnector, Body, Client, Uri};
 use hyper_tls::HttpsConnector;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 #[derive(Clone)]
@@ -12,8 +16,11 @@ where
 {
     client: Client<T, Body>,
     base_url: String,
+    environment: Option<Environment>,
     timestamp_delta: i64,
     credentials: Option<Credentials>,
+    cassette: Option<Cassette>,
+    client_order_ids: Option<Arc<dyn ClientOrderIdGenerator>>,
 }
 
 impl<T> BinanceHttpClient<T>
@@ -24,11 +31,24 @@ where
         Self {
             client,
             base_url: base_url.to_owned(),
+            environment: Environment::from_url(base_url),
             timestamp_delta: 0,
             credentials: None,
+            cassette: None,
+            client_order_ids: None,
         }
     }
 
+    /// Send the requests to the REST API of `environment`.
+    ///
+    /// The environment is otherwise deduced from the base url. Requests
+    /// with testnet credentials are refused by production environments.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.base_url = environment.rest_url().to_owned();
+        self.environment = Some(environment);
+        self
+    }
+
     pub fn credentials(mut self, credentials: Credentials) -> Self {
         self.credentials = Some(credentials);
         self
@@ -38,6 +58,23 @@ where
         self.timestamp_delta = timestamp_delta;
         self
     }
+
+    /// Record the requests and their response to `cassette`, or answer
+    /// them from it without network access, see [`crate::http::cassette`].
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Set the client order ids missing from the order creating requests
+    /// with `generator`, see [`crate::trade::client_order_id`].
+    pub fn client_order_id_generator(
+        mut self,
+        generator: impl ClientOrderIdGenerator + 'static,
+    ) -> Self {
+        self.client_order_ids = Some(Arc::new(generator));
+        self
+    }
 }
 
 impl BinanceHttpClient<HttpsConnector<HttpConnector>> {
@@ -45,8 +82,11 @@ impl BinanceHttpClient<HttpsConnector<HttpConnector>> {
         BinanceHttpClient {
             client: Client::builder().build::<_, hyper::Body>(HttpsConnector::new()),
             base_url: base_url.to_owned(),
+            environment: Environment::from_url(base_url),
             timestamp_delta: 0,
             credentials: None,
+            cassette: None,
+            client_order_ids: None,
         }
     }
 }
@@ -56,31 +96,52 @@ where
     T: Connect + Clone + Send + Sync + 'static,
 {
     pub async fn send<R: Into<Request>>(&self, request: R) -> Result<Response, Error> {
+        let mut request = request.into();
+        assign_client_order_ids(&mut request, self.client_order_ids.as_deref())
+            .map_err(Error::InvalidClientOrderId)?;
         let Request {
             method,
             path,
             params,
             credentials,
             sign,
-        } = request.into();
+        } = request;
         let mut url_parts = vec![self.base_url.to_owned(), path];
         let has_params = !params.is_empty();
-        let mut query_string = String::new();
-        {
+        let mut query_string = {
             let mut serializer = url::form_urlencoded::Serializer::new(String::new());
             if has_params {
                 for (k, v) in params.iter() {
                     serializer.append_pair(k, v);
                 }
             }
-            query_string = serializer.finish();
-        }
-        let mut hyper_request = hyper::Request::builder().method(method);
+            serializer.finish()
+        };
+        let mut hyper_request = hyper::Request::builder().method(method.clone());
         let user_agent = &format!("binance-spot-connector-rust/{}", VERSION);
         hyper_request = hyper_request.header("User-Agent", user_agent);
         let client_credentials = self.credentials.as_ref();
         let request_credentials = credentials.as_ref();
-        if let Some(Credentials { api_key, signature }) = request_credentials.or(client_credentials)
+        if let (Some(environment), Some(credentials)) = (
+            &self.environment,
+            request_credentials.or(client_credentials),
+        ) {
+            if !environment.accepts(credentials) {
+                return Err(Error::TestnetCredentials);
+            }
+        }
+        let cassette = self.cassette.as_ref();
+        if let Some(cassette) = cassette.filter(|cassette| cassette.mode() == Mode::Replay) {
+            let recorded = cassette
+                .replay_response(&method, &url_parts[1], &params)
+                .map_err(Error::Cassette)?;
+            log::debug!("{} (replayed)", recorded.status);
+
+            return Ok(Response::from(into_hyper_response(recorded)?));
+        }
+        if let Some(Credentials {
+            api_key, signature, ..
+        }) = request_credentials.or(client_credentials)
         {
             hyper_request = hyper_request.header("X-MBX-APIKEY", api_key);
             if sign {
@@ -111,20 +172,52 @@ where
         let uri: Uri = url_parts.join("").parse()?;
         log::debug!("{}", uri);
         let hyper_request = hyper_request.uri(uri);
-        let request = hyper_request
-            .body(Body::empty())
-            .map_err(|err| Error::Parse(err))?;
-        let response = self
-            .client
-            .request(request)
-            .await
-            .map_err(|err| Error::Send(err))?;
+        let request = hyper_request.body(Body::empty()).map_err(Error::Parse)?;
+        let response = self.client.request(request).await.map_err(Error::Send)?;
         log::debug!("{}", response.status());
 
+        if let Some(cassette) = cassette {
+            let (parts, body) = response.into_parts();
+            let headers = parts
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    value
+                        .to_str()
+                        .ok()
+                        .map(|value| (name.as_str().to_owned(), value.to_owned()))
+                })
+                .collect();
+            let body = hyper::body::to_bytes(body).await.map_err(Error::Send)?;
+            let body = String::from_utf8(body.to_vec()).map_err(|err| {
+                Error::Cassette(CassetteError::Io(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    err,
+                )))
+            })?;
+            let recorded = RecordedResponse::new(parts.status.as_u16(), headers, body);
+            let response = into_hyper_response(recorded.clone())?;
+            cassette.record_response(&method, &url_parts[1], &params, recorded);
+
+            return Ok(Response::from(response));
+        }
+
         Ok(Response::from(response))
     }
 }
 
+/// Rebuild a `hyper` response from a recorded one.
+fn into_hyper_response(recorded: RecordedResponse) -> Result<hyper::Response<Body>, Error> {
+    let mut response = hyper::Response::builder().status(recorded.status);
+    for (name, value) in recorded.headers.iter() {
+        response = response.header(name, value);
+    }
+
+    response
+        .body(Body::from(recorded.body))
+        .map_err(Error::Parse)
+}
+
 impl Default for BinanceHttpClient<HttpsConnector<HttpConnector>> {
     fn default() -> Self {
         Self::new(
@@ -148,8 +241,12 @@ impl From<Method> for hyper::Method {
 #[cfg(test)]
 mod tests {
     use super::BinanceHttpClient;
-    use crate::http::{error::ClientError, request::Request, Credentials, Method};
+    use crate::environment::Environment;
+    use crate::http::{
+        cassette::Cassette, error::ClientError, request::Request, Credentials, Method,
+    };
     use crate::hyper::Error;
+    use crate::trade::client_order_id::StrategyIds;
     use hyper::client::connect::Connected;
     use hyper::{Client, Uri};
     use std::collections::HashMap;
@@ -255,6 +352,114 @@ mod tests {
         assert_eq!(data, "Test Response".to_owned());
     }
 
+    #[tokio::test]
+    async fn client_refuses_testnet_credentials_test() {
+        let client = Client::builder().build(MockConnector::new().response(200, "Test Response"));
+        let client = BinanceHttpClient::new(client, "https://base-url.com")
+            .environment(Environment::Production)
+            .credentials(Credentials::from_hmac("api-key", "api-secret").testnet());
+
+        let request = || Request {
+            method: Method::Get,
+            path: "/path".to_owned(),
+            params: vec![],
+            credentials: None,
+            sign: true,
+        };
+
+        match client.send(request()).await {
+            Err(Error::TestnetCredentials) => {}
+            _ => panic!("Invalid error"),
+        }
+
+        let client = client.environment(Environment::Testnet);
+        assert!(client.send(request()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn client_assigns_client_order_ids_test() {
+        let client = Client::builder().build(
+            MockConnector::new()
+                .param("newClientOrderId", None)
+                .response(200, "Test Response"),
+        );
+        let client = BinanceHttpClient::new(client, "https://base-url.com")
+            .client_order_id_generator(StrategyIds::new("grid").unwrap());
+
+        let request = |client_order_id: Option<&str>| Request {
+            method: Method::Post,
+            path: "/api/v3/order".to_owned(),
+            params: client_order_id
+                .map(|id| vec![("newClientOrderId".to_owned(), id.to_owned())])
+                .unwrap_or_default(),
+            credentials: None,
+            sign: false,
+        };
+
+        let data = client
+            .send(request(None))
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        assert_eq!(data, "Test Response".to_owned());
+
+        match client.send(request(Some("grid 1"))).await {
+            Err(Error::InvalidClientOrderId(_)) => {}
+            _ => panic!("Invalid error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn client_records_and_replays_cassette_test() {
+        let path = std::env::temp_dir().join(format!("hyper_cassette_{}.json", std::process::id()));
+        let request = || Request {
+            method: Method::Get,
+            path: "/api/v3/account".to_owned(),
+            params: vec![("omitZeroBalances".to_owned(), "true".to_owned())],
+            credentials: None,
+            sign: true,
+        };
+
+        let client =
+            Client::builder().build(MockConnector::new().response(200, "{\"uid\":354937868}"));
+        let recorder = BinanceHttpClient::new(client, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .cassette(Cassette::record(&path));
+        let data = recorder
+            .send(request())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        assert_eq!(data, "{\"uid\":354937868}");
+        let interactions = recorder.cassette.as_ref().unwrap().interactions();
+        assert_eq!(interactions[0].request.params.len(), 1);
+        assert_eq!(interactions[0].response.body, "{\"uid\":0}");
+        std::fs::remove_file(&path).unwrap();
+
+        let client = Client::builder().build(MockConnector::new().response(500, "Error"));
+        let client = BinanceHttpClient::new(client, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .cassette(Cassette::from_interactions(interactions));
+        let data = client
+            .send(request())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        assert_eq!(data, "{\"uid\":0}");
+
+        let client = client.cassette(Cassette::from_interactions(vec![]));
+        match client.send(request()).await {
+            Err(err) => assert!(matches!(err, Error::Cassette(_))),
+            Ok(_) => panic!("Request sent"),
+        }
+    }
+
     #[tokio::test]
     async fn client_handles_not_found_error_test() {
         let client = Client::builder().build(MockConnector::new().response(404, ""));
@@ -545,7 +750,9 @@ mod tests {
 
             let Self { ready, waker, .. } = self.get_mut();
             *ready = true;
-            waker.take().map(|w| w.wake());
+            if let Some(w) = waker.take() {
+                w.wake();
+            }
             Poll::Ready(Ok(buf.len()))
         }
 
@@ -29,8 +29,10 @@
 //! }
 //! ```
 
+mod batch;
 mod client;
 mod error;
+mod pagination;
 mod response;
 
 pub use client::*;
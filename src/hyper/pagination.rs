@@ -0,0 +1,95 @@
+use crate::http::pagination::Paginate;
+use crate::hyper::{BinanceHttpClient, Error};
+use futures_util::stream::{self, Stream};
+use hyper::client::connect::Connect;
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl<T> BinanceHttpClient<T>
+where
+    T: Connect + Clone + Send + Sync + 'static,
+{
+    /// Stream the items of a [`Paginate`] implementation, sending one request per page.
+    ///
+    /// After an error, polling the stream again retries the same page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     hyper::{BinanceHttpClient, Error},
+    ///     market::{self, kline_history::KlineHistoryItem, klines::KlineInterval},
+    /// };
+    /// use futures_util::{pin_mut, StreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Error> {
+    ///     let client = BinanceHttpClient::default();
+    ///     let history = market::kline_history("BTCUSDT", KlineInterval::Minutes1, 1654079109000);
+    ///
+    ///     let klines = client.paginate(history);
+    ///     pin_mut!(klines);
+    ///     while let Some(item) = klines.next().await {
+    ///         match item? {
+    ///             KlineHistoryItem::Kline(kline) => println!("{} {}", kline.open_time, kline.close),
+    ///             KlineHistoryItem::Gap(gap) => println!("Missing {}..{}", gap.start_time, gap.end_time),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn paginate<'a, P: Paginate + 'a>(
+        &'a self,
+        pager: P,
+    ) -> impl Stream<Item = Result<P::Item, Error>> + 'a {
+        stream::unfold(
+            (pager, VecDeque::new()),
+            move |(mut pager, mut items)| async move {
+                loop {
+                    if let Some(item) = items.pop_front() {
+                        return Some((Ok(item), (pager, items)));
+                    }
+
+                    pager.next_request()?;
+
+                    if let Err(err) = self.fetch_page(&mut pager, &mut items).await {
+                        return Some((Err(err), (pager, items)));
+                    }
+                }
+            },
+        )
+    }
+
+    async fn fetch_page<P: Paginate>(
+        &self,
+        pager: &mut P,
+        items: &mut VecDeque<P::Item>,
+    ) -> Result<(), Error> {
+        let request = match pager.next_request() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+
+        let response = self.send(request).await?;
+        let used_weight = response
+            .header(pager.weight_header())
+            .and_then(|weight| weight.parse().ok());
+
+        let data = response.into_body_str().await?;
+        let page: P::Page = serde_json::from_str(&data).map_err(Error::Deserialize)?;
+        items.extend(pager.handle_page(page));
+
+        // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis();
+
+        if let Some(delay) = pager.throttle(used_weight, timestamp as u64) {
+            log::debug!("Request weight limit reached, waiting {:?}", delay);
+            tokio::time::sleep(delay).await;
+        }
+
+        Ok(())
+    }
+}
@@ -10,6 +10,13 @@ pub struct Response {
 }
 
 impl Response {
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.inner_response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+    }
+
     pub async fn into_body_str(self) -> Result<String, Error> {
         let status = self.inner_response.status().as_u16();
         if 400 <= status {
@@ -19,21 +19,29 @@
 //! * `enable-hyper`: For a non-blocking http client powered by [`hyper`](https://docs.rs/hyper/0.14.16/hyper/).
 //! * `enable-tungstenite`: For a blocking web-socket client powered by [`tungstenite`](https://docs.rs/tungstenite/0.16.0/tungstenite/).
 //! * `enable-tokio-tungstenite`: For a non-blocking web-socket client powered by [`tokio-tungstenite`](https://docs.rs/tokio-tungstenite/0.17.1/tokio_tungstenite/).
+//! * `enable-mock-server`: For an in-process mock of the Binance servers to test offline, see [`mock_server`].
 //!
 //! # Testnet
 //!
-//! Http clients and web-socket clients can be configured to communicate with the testnet environment by specifying the base url on initialization.
+//! Http clients and web-socket clients can be configured to communicate with the testnet environment by specifying the [`environment::Environment`] on initialization.
+//! Credentials marked as testnet credentials are refused by clients configured for a production environment.
 //!
 //! ```
-//! use binance_spot_connector_rust::ureq::BinanceHttpClient;
+//! use binance_spot_connector_rust::{
+//!     environment::Environment, http::Credentials, ureq::BinanceHttpClient,
+//! };
 //!
-//! let testnet_http_client = BinanceHttpClient::with_url("https://testnet.binance.vision");
+//! let testnet_http_client = BinanceHttpClient::default()
+//!     .environment(Environment::Testnet)
+//!     .credentials(Credentials::from_hmac("api-key", "api-secret").testnet());
 //! ```
 //!
 //! ```no_run
-//! use binance_spot_connector_rust::tungstenite::BinanceWebSocketClient;
+//! use binance_spot_connector_rust::{
+//!     environment::Environment, tungstenite::BinanceWebSocketClient,
+//! };
 //!
-//! let testnet_websocket_client = BinanceWebSocketClient::connect_with_url("wss://stream.testnet.binance.vision/ws");
+//! let testnet_websocket_client = BinanceWebSocketClient::connect_with_environment(&Environment::Testnet);
 //! ```
 //!
 //! # Logging
@@ -79,7 +87,6 @@
 
 mod utils;
 mod version;
-mod websocket;
 
 #[cfg(feature = "enable-tokio-tungstenite")]
 pub mod tokio_tungstenite;
@@ -93,15 +100,26 @@ pub mod hyper;
 #[cfg(feature = "enable-ureq")]
 pub mod ureq;
 
+#[cfg(feature = "enable-mock-server")]
+pub mod mock_server;
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+pub mod recording;
+
+pub mod environment;
 pub mod http;
+pub mod portfolio;
 
 pub mod isolated_margin_stream;
 pub mod margin_stream;
 pub mod market_stream;
 pub mod stream;
 pub mod user_data_stream;
+pub mod websocket;
 
+pub mod convert;
 pub mod margin;
 pub mod market;
+pub mod simple_earn;
 pub mod trade;
 pub mod wallet;
@@ -0,0 +1,94 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/margin/max-leverage`
+///
+/// Adjust cross margin max leverage
+///
+/// * `maxLeverage` can only be `3`, `5` or `10`. `10` switches the account to Cross Margin Pro.
+/// * The margin level must be higher than the initial risk ratio of the new leverage.
+///
+/// Weight(UID): 3000
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_adjust_max_leverage(3);
+/// ```
+pub struct MarginAdjustMaxLeverage {
+    max_leverage: u32,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginAdjustMaxLeverage {
+    pub fn new(max_leverage: u32) -> Self {
+        Self {
+            max_leverage,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginAdjustMaxLeverage> for Request {
+    fn from(request: MarginAdjustMaxLeverage) -> Request {
+        let mut params = vec![("maxLeverage".to_owned(), request.max_leverage.to_string())];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/max-leverage".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginAdjustMaxLeverage;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_adjust_max_leverage_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginAdjustMaxLeverage::new(3)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/max-leverage".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("maxLeverage".to_owned(), "3".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/margin/available-inventory`
+///
+/// Query margin available inventory
+///
+/// * `type` is either `MARGIN` or `ISOLATED`.
+///
+/// Weight(UID): 50
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_available_inventory("MARGIN");
+/// ```
+pub struct MarginAvailableInventory {
+    r#type: String,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginAvailableInventory {
+    pub fn new(r#type: &str) -> Self {
+        Self {
+            r#type: r#type.to_owned(),
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginAvailableInventory> for Request {
+    fn from(request: MarginAvailableInventory) -> Request {
+        let mut params = vec![("type".to_owned(), request.r#type)];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/available-inventory".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginAvailableInventory;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_available_inventory_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginAvailableInventory::new("MARGIN")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/available-inventory".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("type".to_owned(), "MARGIN".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/margin/borrow-repay`
 ///
@@ -18,6 +24,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = margin::margin_borrow_repay_records("BORROW");
 /// ```
+#[derive(Clone)]
 pub struct MarginBorrowRepayRecords {
     type_: String,
     asset: Option<String>,
@@ -139,6 +146,55 @@ impl From<MarginBorrowRepayRecords> for Request {
     }
 }
 
+impl TimeWindowed for MarginBorrowRepayRecords {
+    type Page = Rows<BorrowRepay>;
+    type Row = BorrowRepay;
+
+    /// 30 days when `asset` is set, 7 days otherwise.
+    fn max_window(&self) -> Option<u64> {
+        match self.asset {
+            Some(_) => Some(30 * 86_400_000),
+            None => Some(7 * 86_400_000),
+        }
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.current = Some(page as u64 + 1);
+        request.size = Some(100);
+        request.into()
+    }
+
+    fn rows(page: Rows<BorrowRepay>) -> Vec<BorrowRepay> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        10
+    }
+}
+
+/// Borrow or repay returned by `GET /sapi/v1/margin/borrow-repay`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepay {
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+    pub amount: Decimal,
+    pub asset: String,
+    pub interest: Decimal,
+    pub principal: Decimal,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::MarginBorrowRepayRecords;
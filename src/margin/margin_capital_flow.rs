@@ -0,0 +1,189 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/margin/capital-flow`
+///
+/// Query cross or isolated margin capital flow
+///
+/// * Only the data of the last 90 days can be queried.
+/// * If `startTime` and `endTime` are not sent, the recent 7 days' data will be returned.
+/// * If `fromId` is sent, data with `id` greater than `fromId` will be returned, otherwise the latest data.
+///
+/// Weight(IP): 100
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_capital_flow().asset("BNB").limit(500);
+/// ```
+pub struct MarginCapitalFlow {
+    asset: Option<String>,
+    symbol: Option<String>,
+    r#type: Option<String>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    from_id: Option<u64>,
+    limit: Option<u32>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginCapitalFlow {
+    pub fn new() -> Self {
+        Self {
+            asset: None,
+            symbol: None,
+            r#type: None,
+            start_time: None,
+            end_time: None,
+            from_id: None,
+            limit: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn asset(mut self, asset: &str) -> Self {
+        self.asset = Some(asset.to_owned());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn r#type(mut self, r#type: &str) -> Self {
+        self.r#type = Some(r#type.to_owned());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.from_id = Some(from_id);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginCapitalFlow> for Request {
+    fn from(request: MarginCapitalFlow) -> Request {
+        let mut params = vec![];
+
+        if let Some(asset) = request.asset {
+            params.push(("asset".to_owned(), asset));
+        }
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(r#type) = request.r#type {
+            params.push(("type".to_owned(), r#type));
+        }
+
+        if let Some(start_time) = request.start_time {
+            params.push(("startTime".to_owned(), start_time.to_string()));
+        }
+
+        if let Some(end_time) = request.end_time {
+            params.push(("endTime".to_owned(), end_time.to_string()));
+        }
+
+        if let Some(from_id) = request.from_id {
+            params.push(("fromId".to_owned(), from_id.to_string()));
+        }
+
+        if let Some(limit) = request.limit {
+            params.push(("limit".to_owned(), limit.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/capital-flow".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for MarginCapitalFlow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginCapitalFlow;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_capital_flow_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginCapitalFlow::new()
+            .asset("BNB")
+            .symbol("BNBUSDT")
+            .r#type("TRANSFER")
+            .start_time(1640995200000)
+            .end_time(1640995200000)
+            .from_id(1)
+            .limit(500)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/capital-flow".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("asset".to_owned(), "BNB".to_string()),
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("type".to_owned(), "TRANSFER".to_string()),
+                    ("startTime".to_owned(), "1640995200000".to_string()),
+                    ("endTime".to_owned(), "1640995200000".to_string()),
+                    ("fromId".to_owned(), "1".to_string()),
+                    ("limit".to_owned(), "500".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
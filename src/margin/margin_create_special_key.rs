@@ -0,0 +1,148 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/margin/apiKey`
+///
+/// Create a special key for low-latency trading with margin accounts
+///
+/// * Only available for VIP 4 and above.
+/// * Up to 30 comma separated IPv4 addresses may be bound to the key with `ip`.
+/// * When `publicKey` is not sent, an HMAC key is generated and the secret returned.
+/// * `permissionMode` is either `TRADE` or `READ`; defaults to `TRADE`.
+///
+/// Weight(UID): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_create_special_key("bot").ip("1.2.3.4");
+/// ```
+pub struct MarginCreateSpecialKey {
+    api_name: String,
+    symbol: Option<String>,
+    ip: Option<String>,
+    public_key: Option<String>,
+    permission_mode: Option<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginCreateSpecialKey {
+    pub fn new(api_name: &str) -> Self {
+        Self {
+            api_name: api_name.to_owned(),
+            symbol: None,
+            ip: None,
+            public_key: None,
+            permission_mode: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn ip(mut self, ip: &str) -> Self {
+        self.ip = Some(ip.to_owned());
+        self
+    }
+
+    pub fn public_key(mut self, public_key: &str) -> Self {
+        self.public_key = Some(public_key.to_owned());
+        self
+    }
+
+    pub fn permission_mode(mut self, permission_mode: &str) -> Self {
+        self.permission_mode = Some(permission_mode.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginCreateSpecialKey> for Request {
+    fn from(request: MarginCreateSpecialKey) -> Request {
+        let mut params = vec![("apiName".to_owned(), request.api_name)];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(ip) = request.ip {
+            params.push(("ip".to_owned(), ip));
+        }
+
+        if let Some(public_key) = request.public_key {
+            params.push(("publicKey".to_owned(), public_key));
+        }
+
+        if let Some(permission_mode) = request.permission_mode {
+            params.push(("permissionMode".to_owned(), permission_mode));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/apiKey".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginCreateSpecialKey;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_create_special_key_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginCreateSpecialKey::new("bot")
+            .symbol("BNBUSDT")
+            .ip("1.2.3.4")
+            .public_key("public-key")
+            .permission_mode("TRADE")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/apiKey".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("apiName".to_owned(), "bot".to_string()),
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("ip".to_owned(), "1.2.3.4".to_string()),
+                    ("publicKey".to_owned(), "public-key".to_string()),
+                    ("permissionMode".to_owned(), "TRADE".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use crate::http::{request::Request, Method};
+
+/// `GET /sapi/v1/margin/crossMarginCollateralRatio`
+///
+/// Query cross margin collateral ratio
+///
+/// Weight(IP): 100
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_cross_margin_collateral_ratio();
+/// ```
+pub struct MarginCrossMarginCollateralRatio {}
+
+impl MarginCrossMarginCollateralRatio {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<MarginCrossMarginCollateralRatio> for Request {
+    fn from(_request: MarginCrossMarginCollateralRatio) -> Request {
+        let params = vec![];
+
+        Request {
+            path: "/sapi/v1/margin/crossMarginCollateralRatio".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: None,
+            sign: false,
+        }
+    }
+}
+
+impl Default for MarginCrossMarginCollateralRatio {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginCrossMarginCollateralRatio;
+    use crate::http::{request::Request, Method};
+
+    #[test]
+    fn margin_margin_cross_margin_collateral_ratio_convert_to_request_test() {
+        let request: Request = MarginCrossMarginCollateralRatio::new().into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/crossMarginCollateralRatio".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![],
+                sign: false
+            }
+        );
+    }
+}
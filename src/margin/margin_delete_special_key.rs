@@ -0,0 +1,122 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `DELETE /sapi/v1/margin/apiKey`
+///
+/// Delete the special key used for low-latency trading
+///
+/// * Send `symbol` to delete the key of an isolated margin account.
+///
+/// Weight(UID): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_delete_special_key().api_name("bot");
+/// ```
+pub struct MarginDeleteSpecialKey {
+    api_name: Option<String>,
+    symbol: Option<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginDeleteSpecialKey {
+    pub fn new() -> Self {
+        Self {
+            api_name: None,
+            symbol: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn api_name(mut self, api_name: &str) -> Self {
+        self.api_name = Some(api_name.to_owned());
+        self
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginDeleteSpecialKey> for Request {
+    fn from(request: MarginDeleteSpecialKey) -> Request {
+        let mut params = vec![];
+
+        if let Some(api_name) = request.api_name {
+            params.push(("apiName".to_owned(), api_name));
+        }
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/apiKey".to_owned(),
+            method: Method::Delete,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for MarginDeleteSpecialKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginDeleteSpecialKey;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_delete_special_key_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginDeleteSpecialKey::new()
+            .api_name("bot")
+            .symbol("BNBUSDT")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/apiKey".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Delete,
+                params: vec![
+                    ("apiName".to_owned(), "bot".to_string()),
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `PUT /sapi/v1/margin/apiKey/ip`
+///
+/// Edit the IP addresses bound to the special key
+///
+/// * `ip` is a comma separated list of up to 30 IPv4 addresses and replaces the existing list.
+///
+/// Weight(UID): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_edit_special_key_ip("1.2.3.4,5.6.7.8");
+/// ```
+pub struct MarginEditSpecialKeyIp {
+    ip: String,
+    symbol: Option<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginEditSpecialKeyIp {
+    pub fn new(ip: &str) -> Self {
+        Self {
+            ip: ip.to_owned(),
+            symbol: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginEditSpecialKeyIp> for Request {
+    fn from(request: MarginEditSpecialKeyIp) -> Request {
+        let mut params = vec![("ip".to_owned(), request.ip)];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/apiKey/ip".to_owned(),
+            method: Method::Put,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginEditSpecialKeyIp;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_edit_special_key_ip_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginEditSpecialKeyIp::new("1.2.3.4,5.6.7.8")
+            .symbol("BNBUSDT")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/apiKey/ip".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Put,
+                params: vec![
+                    ("ip".to_owned(), "1.2.3.4,5.6.7.8".to_string()),
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
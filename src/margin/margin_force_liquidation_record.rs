@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/margin/forceLiquidationRec`
 ///
@@ -13,6 +19,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = margin::margin_force_liquidation_record().current(1).size(100);
 /// ```
+#[derive(Clone)]
 pub struct MarginForceLiquidationRecord {
     start_time: Option<u64>,
     end_time: Option<u64>,
@@ -116,6 +123,52 @@ impl From<MarginForceLiquidationRecord> for Request {
     }
 }
 
+impl TimeWindowed for MarginForceLiquidationRecord {
+    type Page = Rows<ForceLiquidation>;
+    type Row = ForceLiquidation;
+
+    fn max_window(&self) -> Option<u64> {
+        None
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.current = Some(page as u64 + 1);
+        request.size = Some(100);
+        request.into()
+    }
+
+    fn rows(page: Rows<ForceLiquidation>) -> Vec<ForceLiquidation> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Forced liquidation returned by `GET /sapi/v1/margin/forceLiquidationRec`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidation {
+    pub avg_price: Decimal,
+    pub executed_qty: Decimal,
+    pub order_id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub side: String,
+    pub symbol: String,
+    pub time_in_force: String,
+    pub is_isolated: bool,
+    pub update_time: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::MarginForceLiquidationRecord;
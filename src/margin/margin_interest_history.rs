@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/margin/interestHistory`
 ///
@@ -20,6 +26,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = margin::margin_interest_history().asset("BNB").current(1).size(100);
 /// ```
+#[derive(Clone)]
 pub struct MarginInterestHistory {
     asset: Option<String>,
     isolated_symbol: Option<String>,
@@ -145,6 +152,53 @@ impl Default for MarginInterestHistory {
     }
 }
 
+impl TimeWindowed for MarginInterestHistory {
+    type Page = Rows<MarginInterest>;
+    type Row = MarginInterest;
+
+    fn max_window(&self) -> Option<u64> {
+        Some(30 * 86_400_000)
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.current = Some(page as u64 + 1);
+        request.size = Some(100);
+        request.into()
+    }
+
+    fn rows(page: Rows<MarginInterest>) -> Vec<MarginInterest> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Interest returned by `GET /sapi/v1/margin/interestHistory`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginInterest {
+    pub tx_id: u64,
+    pub interest_accured_time: u64,
+    pub asset: String,
+    #[serde(default)]
+    pub raw_asset: Option<String>,
+    pub principal: Decimal,
+    pub interest: Decimal,
+    pub interest_rate: Decimal,
+    pub r#type: String,
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::MarginInterestHistory;
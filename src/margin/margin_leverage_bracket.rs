@@ -0,0 +1,64 @@
+use crate::http::{request::Request, Method};
+
+/// `GET /sapi/v1/margin/leverageBracket`
+///
+/// Liability coin leverage bracket in Cross Margin Pro Mode
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_leverage_bracket();
+/// ```
+pub struct MarginLeverageBracket {}
+
+impl MarginLeverageBracket {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl From<MarginLeverageBracket> for Request {
+    fn from(_request: MarginLeverageBracket) -> Request {
+        let params = vec![];
+
+        Request {
+            path: "/sapi/v1/margin/leverageBracket".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: None,
+            sign: false,
+        }
+    }
+}
+
+impl Default for MarginLeverageBracket {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginLeverageBracket;
+    use crate::http::{request::Request, Method};
+
+    #[test]
+    fn margin_margin_leverage_bracket_convert_to_request_test() {
+        let request: Request = MarginLeverageBracket::new().into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/leverageBracket".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![],
+                sign: false
+            }
+        );
+    }
+}
@@ -0,0 +1,215 @@
+#![allow(clippy::wrong_self_convention)]
+
+use crate::http::{
+    pagination::{Paginate, SAPI_WEIGHT_LIMIT},
+    request::Request,
+    Credentials,
+};
+use crate::margin::margin_my_trades::MarginMyTrades;
+use crate::market::trade_history::{TradeCursor, TradePage, TradeRow, MAX_TRADES_LIMIT};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Paginated margin account trade history.
+///
+/// Walks `GET /sapi/v1/margin/myTrades` by trade id, from `from_id`, from `start_time`
+/// or from the first trade of the margin account on the symbol, until `end_time` or
+/// the most recent trade.
+///
+/// * The server only accepts time ranges of up to 24 hours: when starting from
+///   `start_time`, 24 hours windows are requested until a first trade is found.
+///
+/// The history is consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let history = margin::margin_my_trade_history("BNBUSDT")
+///     .is_isolated(true)
+///     .start_time(1640995200000)
+///     .end_time(1672531200000);
+/// ```
+pub struct MarginMyTradeHistory {
+    symbol: String,
+    cursor: TradeCursor,
+    is_isolated: Option<bool>,
+    weight_limit: Option<u32>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginMyTradeHistory {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            cursor: TradeCursor::new(86_400_000),
+            is_isolated: None,
+            weight_limit: Some(SAPI_WEIGHT_LIMIT),
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn is_isolated(mut self, is_isolated: bool) -> Self {
+        self.is_isolated = Some(is_isolated);
+        self
+    }
+
+    /// Start from the trade `from_id`, takes precedence over `start_time`.
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.cursor.from_id = Some(from_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.cursor.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.cursor.end_time = Some(end_time);
+        self
+    }
+
+    /// Number of trades per page, capped to 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cursor.limit = limit.clamp(1, MAX_TRADES_LIMIT);
+        self
+    }
+
+    /// Request weight per minute not to exceed, `None` to never wait between pages.
+    pub fn weight_limit(mut self, weight_limit: Option<u32>) -> Self {
+        self.weight_limit = weight_limit;
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+
+    /// Id of the last trade emitted.
+    pub fn last_id(&self) -> Option<u64> {
+        self.cursor.last_id()
+    }
+}
+
+impl Paginate for MarginMyTradeHistory {
+    type Page = Vec<MarginTrade>;
+    type Item = MarginTrade;
+
+    fn next_request(&self) -> Option<Request> {
+        let mut request = MarginMyTrades::new(&self.symbol).limit(self.cursor.limit);
+        if let Some(is_isolated) = self.is_isolated {
+            request = request.is_isolated(is_isolated);
+        }
+        request = match self.cursor.next_page()? {
+            TradePage::Time(start_time, end_time) => {
+                request.start_time(start_time).end_time(end_time)
+            }
+            TradePage::Id(from_id) => request.from_id(from_id),
+        };
+        if let Some(recv_window) = self.recv_window {
+            request = request.recv_window(recv_window);
+        }
+        if let Some(credentials) = &self.credentials {
+            request = request.credentials(credentials);
+        }
+
+        Some(request.into())
+    }
+
+    fn handle_page(&mut self, trades: Vec<MarginTrade>) -> Vec<MarginTrade> {
+        self.cursor.handle_page(trades)
+    }
+
+    fn page_weight(&self) -> u32 {
+        10
+    }
+
+    fn max_weight(&self) -> Option<u32> {
+        self.weight_limit
+    }
+
+    fn weight_header(&self) -> &'static str {
+        "x-sapi-used-ip-weight-1m"
+    }
+}
+
+/// Trade returned by `GET /sapi/v1/margin/myTrades`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub time: u64,
+    pub is_buyer: bool,
+    pub is_maker: bool,
+    pub is_best_match: bool,
+    pub is_isolated: bool,
+}
+
+impl TradeRow for MarginTrade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MarginMyTradeHistory, MarginTrade};
+    use crate::http::{pagination::Paginate, request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_my_trade_history_next_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let mut history = MarginMyTradeHistory::new("BNBBTC")
+            .is_isolated(true)
+            .start_time(1640995200000)
+            .limit(1)
+            .recv_window(5000)
+            .credentials(&credentials);
+
+        let data = r#"[{"commission":"0.00006000","commissionAsset":"BTC","id":28457,"isBestMatch":true,"isBuyer":false,"isMaker":false,"orderId":39324,"price":"0.02000000","qty":"3.00000000","symbol":"BNBBTC","isIsolated":true,"time":1640995300000}]"#;
+        let trades: Vec<MarginTrade> = serde_json::from_str(data).unwrap();
+        assert_eq!(history.handle_page(trades).len(), 1);
+
+        assert_eq!(
+            history.next_request(),
+            Some(Request {
+                path: "/sapi/v1/margin/myTrades".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BNBBTC".to_string()),
+                    ("isIsolated".to_owned(), "TRUE".to_string()),
+                    ("fromId".to_owned(), "28458".to_string()),
+                    ("limit".to_owned(), "1".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            })
+        );
+    }
+}
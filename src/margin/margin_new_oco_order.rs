@@ -40,6 +40,8 @@ pub struct MarginNewOCOOrder {
     stop_limit_time_in_force: Option<String>,
     new_order_resp_type: Option<String>,
     side_effect_type: Option<String>,
+    self_trade_prevention_mode: Option<String>,
+    auto_repay_at_cancel: Option<bool>,
     recv_window: Option<u64>,
     credentials: Option<Credentials>,
 }
@@ -68,6 +70,8 @@ impl MarginNewOCOOrder {
             stop_limit_time_in_force: None,
             new_order_resp_type: None,
             side_effect_type: None,
+            self_trade_prevention_mode: None,
+            auto_repay_at_cancel: None,
             recv_window: None,
             credentials: None,
         }
@@ -123,6 +127,16 @@ impl MarginNewOCOOrder {
         self
     }
 
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: &str) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode.to_owned());
+        self
+    }
+
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
     pub fn recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = Some(recv_window);
         self
@@ -187,6 +201,20 @@ impl From<MarginNewOCOOrder> for Request {
             params.push(("sideEffectType".to_owned(), side_effect_type));
         }
 
+        if let Some(self_trade_prevention_mode) = request.self_trade_prevention_mode {
+            params.push((
+                "selfTradePreventionMode".to_owned(),
+                self_trade_prevention_mode,
+            ));
+        }
+
+        if let Some(auto_repay_at_cancel) = request.auto_repay_at_cancel {
+            params.push((
+                "autoRepayAtCancel".to_owned(),
+                auto_repay_at_cancel.to_string(),
+            ));
+        }
+
         if let Some(recv_window) = request.recv_window {
             params.push(("recvWindow".to_owned(), recv_window.to_string()));
         }
@@ -218,6 +246,8 @@ mod tests {
             MarginNewOCOOrder::new("BNBUSDT", "SELL", dec!(0.1), dec!(400.15), dec!(390.3))
                 .stop_limit_price(dec!(290))
                 .stop_limit_time_in_force("GTC")
+                .self_trade_prevention_mode("EXPIRE_TAKER")
+                .auto_repay_at_cancel(false)
                 .recv_window(5000)
                 .credentials(&credentials)
                 .into();
@@ -236,6 +266,11 @@ mod tests {
                     ("stopPrice".to_owned(), "390.3".to_string()),
                     ("stopLimitPrice".to_owned(), "290".to_string()),
                     ("stopLimitTimeInForce".to_owned(), "GTC".to_string()),
+                    (
+                        "selfTradePreventionMode".to_owned(),
+                        "EXPIRE_TAKER".to_string()
+                    ),
+                    ("autoRepayAtCancel".to_owned(), "false".to_string()),
                     ("recvWindow".to_owned(), "5000".to_string()),
                 ],
                 sign: true
@@ -5,6 +5,8 @@ use rust_decimal::Decimal;
 ///
 /// Post a new order for margin account.
 ///
+/// * `autoRepayAtCancel` only applies when `sideEffectType` is `MARGIN_BUY` or `AUTO_BORROW_REPAY`; defaults to `true`.
+///
 /// Weight(UID): 6
 ///
 /// # Example
@@ -29,6 +31,8 @@ pub struct MarginNewOrder {
     new_order_resp_type: Option<String>,
     side_effect_type: Option<String>,
     time_in_force: Option<String>,
+    self_trade_prevention_mode: Option<String>,
+    auto_repay_at_cancel: Option<bool>,
     recv_window: Option<u64>,
     credentials: Option<Credentials>,
 }
@@ -49,6 +53,8 @@ impl MarginNewOrder {
             new_order_resp_type: None,
             side_effect_type: None,
             time_in_force: None,
+            self_trade_prevention_mode: None,
+            auto_repay_at_cancel: None,
             recv_window: None,
             credentials: None,
         }
@@ -104,6 +110,16 @@ impl MarginNewOrder {
         self
     }
 
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: &str) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode.to_owned());
+        self
+    }
+
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
     pub fn recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = Some(recv_window);
         self
@@ -166,6 +182,20 @@ impl From<MarginNewOrder> for Request {
             params.push(("timeInForce".to_owned(), time_in_force));
         }
 
+        if let Some(self_trade_prevention_mode) = request.self_trade_prevention_mode {
+            params.push((
+                "selfTradePreventionMode".to_owned(),
+                self_trade_prevention_mode,
+            ));
+        }
+
+        if let Some(auto_repay_at_cancel) = request.auto_repay_at_cancel {
+            params.push((
+                "autoRepayAtCancel".to_owned(),
+                auto_repay_at_cancel.to_string(),
+            ));
+        }
+
         if let Some(recv_window) = request.recv_window {
             params.push(("recvWindow".to_owned(), recv_window.to_string()));
         }
@@ -198,6 +228,8 @@ mod tests {
             .price(dec!(10))
             .stop_price(dec!(20.01))
             .time_in_force("GTC")
+            .self_trade_prevention_mode("EXPIRE_TAKER")
+            .auto_repay_at_cancel(false)
             .recv_window(5000)
             .credentials(&credentials)
             .into();
@@ -216,6 +248,11 @@ mod tests {
                     ("price".to_owned(), "10".to_string()),
                     ("stopPrice".to_owned(), "20.01".to_string()),
                     ("timeInForce".to_owned(), "GTC".to_string()),
+                    (
+                        "selfTradePreventionMode".to_owned(),
+                        "EXPIRE_TAKER".to_string()
+                    ),
+                    ("autoRepayAtCancel".to_owned(), "false".to_string()),
                     ("recvWindow".to_owned(), "5000".to_string()),
                 ],
                 sign: true
@@ -0,0 +1,508 @@
+use crate::http::{request::Request, Credentials, Method};
+use crate::trade::order::{NewOrderResponseType, Side, TimeInForce, WorkingMandatoryParams};
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/margin/order/otoco`
+///
+/// Post a new OTOCO order for margin account
+///
+/// * An OTOCO (One-Triggers-One-Cancels-the-Other) is an order list comprised of 3 orders.
+/// * The first order is called the working order and must be `LIMIT` or `LIMIT_MAKER`. Initially, only the working order goes on the order book.
+///   - The behavior of the working order is the same as the OTO.
+/// * OTOCO has 2 pending orders (pending above and pending below), forming an OCO pair. The pending orders are only placed on the order book when the working order gets fully filled.
+/// * `sideEffectType` is one of `NO_SIDE_EFFECT`, `MARGIN_BUY` or `AUTO_BORROW_REPAY`.
+/// * `autoRepayAtCancel` only applies with `MARGIN_BUY` or `AUTO_BORROW_REPAY`; defaults to `true`.
+///
+/// Weight(UID): 6
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+/// use binance_spot_connector_rust::trade::order::{Side, TimeInForce, WorkingMandatoryParams};
+/// use rust_decimal_macros::dec;
+///
+/// let request = margin::margin_new_otoco_order("BNBUSDT", WorkingMandatoryParams::new("LIMIT", Side::Buy, dec!(596.0), dec!(1.0)), Side::Sell, dec!(1.0), "LIMIT_MAKER").working_time_in_force(TimeInForce::Gtc).pending_above_price(dec!(610)).pending_below_type("STOP_LOSS_LIMIT").pending_below_stop_price(dec!(580)).pending_below_price(dec!(579)).pending_below_time_in_force(TimeInForce::Gtc);
+/// ```
+pub struct MarginNewOTOCOOrder {
+    symbol: String,
+    working_mandatory_params: WorkingMandatoryParams,
+    pending_side: Side,
+    pending_quantity: Decimal,
+    pending_above_type: String,
+    is_isolated: Option<bool>,
+    list_client_order_id: Option<String>,
+    new_order_resp_type: Option<NewOrderResponseType>,
+    side_effect_type: Option<String>,
+    self_trade_prevention_mode: Option<String>,
+    auto_repay_at_cancel: Option<bool>,
+    working_client_order_id: Option<String>,
+    working_iceberg_qty: Option<Decimal>,
+    working_time_in_force: Option<TimeInForce>,
+    pending_above_client_order_id: Option<String>,
+    pending_above_price: Option<Decimal>,
+    pending_above_stop_price: Option<Decimal>,
+    pending_above_trailing_delta: Option<Decimal>,
+    pending_above_iceberg_qty: Option<Decimal>,
+    pending_above_time_in_force: Option<TimeInForce>,
+    pending_below_type: Option<String>,
+    pending_below_client_order_id: Option<String>,
+    pending_below_price: Option<Decimal>,
+    pending_below_stop_price: Option<Decimal>,
+    pending_below_trailing_delta: Option<Decimal>,
+    pending_below_iceberg_qty: Option<Decimal>,
+    pending_below_time_in_force: Option<TimeInForce>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginNewOTOCOOrder {
+    pub fn new(
+        symbol: &str,
+        working_mandatory_params: WorkingMandatoryParams,
+        pending_side: Side,
+        pending_quantity: Decimal,
+        pending_above_type: &str,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            working_mandatory_params,
+            pending_side,
+            pending_quantity,
+            pending_above_type: pending_above_type.to_owned(),
+            is_isolated: None,
+            list_client_order_id: None,
+            new_order_resp_type: None,
+            side_effect_type: None,
+            self_trade_prevention_mode: None,
+            auto_repay_at_cancel: None,
+            working_client_order_id: None,
+            working_iceberg_qty: None,
+            working_time_in_force: None,
+            pending_above_client_order_id: None,
+            pending_above_price: None,
+            pending_above_stop_price: None,
+            pending_above_trailing_delta: None,
+            pending_above_iceberg_qty: None,
+            pending_above_time_in_force: None,
+            pending_below_type: None,
+            pending_below_client_order_id: None,
+            pending_below_price: None,
+            pending_below_stop_price: None,
+            pending_below_trailing_delta: None,
+            pending_below_iceberg_qty: None,
+            pending_below_time_in_force: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn is_isolated(mut self, is_isolated: bool) -> Self {
+        self.is_isolated = Some(is_isolated);
+        self
+    }
+
+    pub fn list_client_order_id(mut self, list_client_order_id: &str) -> Self {
+        self.list_client_order_id = Some(list_client_order_id.to_owned());
+        self
+    }
+
+    pub fn new_order_resp_type(mut self, new_order_resp_type: NewOrderResponseType) -> Self {
+        self.new_order_resp_type = Some(new_order_resp_type);
+        self
+    }
+
+    pub fn side_effect_type(mut self, side_effect_type: &str) -> Self {
+        self.side_effect_type = Some(side_effect_type.to_owned());
+        self
+    }
+
+    pub fn self_trade_prevention_mode(mut self, self_trade_prevention_mode: &str) -> Self {
+        self.self_trade_prevention_mode = Some(self_trade_prevention_mode.to_owned());
+        self
+    }
+
+    pub fn auto_repay_at_cancel(mut self, auto_repay_at_cancel: bool) -> Self {
+        self.auto_repay_at_cancel = Some(auto_repay_at_cancel);
+        self
+    }
+
+    pub fn working_client_order_id(mut self, working_client_order_id: &str) -> Self {
+        self.working_client_order_id = Some(working_client_order_id.to_owned());
+        self
+    }
+
+    pub fn working_iceberg_qty(mut self, working_iceberg_qty: Decimal) -> Self {
+        self.working_iceberg_qty = Some(working_iceberg_qty);
+        self
+    }
+
+    pub fn working_time_in_force(mut self, working_time_in_force: TimeInForce) -> Self {
+        self.working_time_in_force = Some(working_time_in_force);
+        self
+    }
+
+    pub fn pending_above_client_order_id(mut self, pending_above_client_order_id: &str) -> Self {
+        self.pending_above_client_order_id = Some(pending_above_client_order_id.to_owned());
+        self
+    }
+
+    pub fn pending_above_price(mut self, pending_above_price: Decimal) -> Self {
+        self.pending_above_price = Some(pending_above_price);
+        self
+    }
+
+    pub fn pending_above_stop_price(mut self, pending_above_stop_price: Decimal) -> Self {
+        self.pending_above_stop_price = Some(pending_above_stop_price);
+        self
+    }
+
+    pub fn pending_above_trailing_delta(mut self, pending_above_trailing_delta: Decimal) -> Self {
+        self.pending_above_trailing_delta = Some(pending_above_trailing_delta);
+        self
+    }
+
+    pub fn pending_above_iceberg_qty(mut self, pending_above_iceberg_qty: Decimal) -> Self {
+        self.pending_above_iceberg_qty = Some(pending_above_iceberg_qty);
+        self
+    }
+
+    pub fn pending_above_time_in_force(mut self, pending_above_time_in_force: TimeInForce) -> Self {
+        self.pending_above_time_in_force = Some(pending_above_time_in_force);
+        self
+    }
+
+    pub fn pending_below_type(mut self, pending_below_type: &str) -> Self {
+        self.pending_below_type = Some(pending_below_type.to_owned());
+        self
+    }
+
+    pub fn pending_below_client_order_id(mut self, pending_below_client_order_id: &str) -> Self {
+        self.pending_below_client_order_id = Some(pending_below_client_order_id.to_owned());
+        self
+    }
+
+    pub fn pending_below_price(mut self, pending_below_price: Decimal) -> Self {
+        self.pending_below_price = Some(pending_below_price);
+        self
+    }
+
+    pub fn pending_below_stop_price(mut self, pending_below_stop_price: Decimal) -> Self {
+        self.pending_below_stop_price = Some(pending_below_stop_price);
+        self
+    }
+
+    pub fn pending_below_trailing_delta(mut self, pending_below_trailing_delta: Decimal) -> Self {
+        self.pending_below_trailing_delta = Some(pending_below_trailing_delta);
+        self
+    }
+
+    pub fn pending_below_iceberg_qty(mut self, pending_below_iceberg_qty: Decimal) -> Self {
+        self.pending_below_iceberg_qty = Some(pending_below_iceberg_qty);
+        self
+    }
+
+    pub fn pending_below_time_in_force(mut self, pending_below_time_in_force: TimeInForce) -> Self {
+        self.pending_below_time_in_force = Some(pending_below_time_in_force);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginNewOTOCOOrder> for Request {
+    fn from(request: MarginNewOTOCOOrder) -> Request {
+        let mut params = vec![
+            ("symbol".to_owned(), request.symbol),
+            (
+                "workingType".to_owned(),
+                request.working_mandatory_params.working_type.to_string(),
+            ),
+            (
+                "workingSide".to_owned(),
+                request.working_mandatory_params.working_side.to_string(),
+            ),
+            (
+                "workingPrice".to_owned(),
+                request.working_mandatory_params.working_price.to_string(),
+            ),
+            (
+                "workingQuantity".to_owned(),
+                request
+                    .working_mandatory_params
+                    .working_quantity
+                    .to_string(),
+            ),
+            ("pendingSide".to_owned(), request.pending_side.to_string()),
+            (
+                "pendingQuantity".to_owned(),
+                request.pending_quantity.to_string(),
+            ),
+            ("pendingAboveType".to_owned(), request.pending_above_type),
+        ];
+
+        if let Some(is_isolated) = request.is_isolated {
+            params.push((
+                "isIsolated".to_owned(),
+                is_isolated.to_string().to_uppercase(),
+            ));
+        }
+
+        if let Some(list_client_order_id) = request.list_client_order_id {
+            params.push(("listClientOrderId".to_owned(), list_client_order_id));
+        }
+
+        if let Some(new_order_resp_type) = request.new_order_resp_type {
+            params.push((
+                "newOrderRespType".to_owned(),
+                new_order_resp_type.to_string(),
+            ));
+        }
+
+        if let Some(side_effect_type) = request.side_effect_type {
+            params.push(("sideEffectType".to_owned(), side_effect_type));
+        }
+
+        if let Some(self_trade_prevention_mode) = request.self_trade_prevention_mode {
+            params.push((
+                "selfTradePreventionMode".to_owned(),
+                self_trade_prevention_mode,
+            ));
+        }
+
+        if let Some(auto_repay_at_cancel) = request.auto_repay_at_cancel {
+            params.push((
+                "autoRepayAtCancel".to_owned(),
+                auto_repay_at_cancel.to_string(),
+            ));
+        }
+
+        if let Some(working_client_order_id) = request.working_client_order_id {
+            params.push(("workingClientOrderId".to_owned(), working_client_order_id));
+        }
+
+        if let Some(working_iceberg_qty) = request.working_iceberg_qty {
+            params.push((
+                "workingIcebergQty".to_owned(),
+                working_iceberg_qty.to_string(),
+            ));
+        }
+
+        if let Some(working_time_in_force) = request.working_time_in_force {
+            params.push((
+                "workingTimeInForce".to_owned(),
+                working_time_in_force.to_string(),
+            ));
+        }
+
+        if let Some(pending_above_client_order_id) = request.pending_above_client_order_id {
+            params.push((
+                "pendingAboveClientOrderId".to_owned(),
+                pending_above_client_order_id,
+            ));
+        }
+
+        if let Some(pending_above_price) = request.pending_above_price {
+            params.push((
+                "pendingAbovePrice".to_owned(),
+                pending_above_price.to_string(),
+            ));
+        }
+
+        if let Some(pending_above_stop_price) = request.pending_above_stop_price {
+            params.push((
+                "pendingAboveStopPrice".to_owned(),
+                pending_above_stop_price.to_string(),
+            ));
+        }
+
+        if let Some(pending_above_trailing_delta) = request.pending_above_trailing_delta {
+            params.push((
+                "pendingAboveTrailingDelta".to_owned(),
+                pending_above_trailing_delta.to_string(),
+            ));
+        }
+
+        if let Some(pending_above_iceberg_qty) = request.pending_above_iceberg_qty {
+            params.push((
+                "pendingAboveIcebergQty".to_owned(),
+                pending_above_iceberg_qty.to_string(),
+            ));
+        }
+
+        if let Some(pending_above_time_in_force) = request.pending_above_time_in_force {
+            params.push((
+                "pendingAboveTimeInForce".to_owned(),
+                pending_above_time_in_force.to_string(),
+            ));
+        }
+
+        if let Some(pending_below_type) = request.pending_below_type {
+            params.push(("pendingBelowType".to_owned(), pending_below_type));
+        }
+
+        if let Some(pending_below_client_order_id) = request.pending_below_client_order_id {
+            params.push((
+                "pendingBelowClientOrderId".to_owned(),
+                pending_below_client_order_id,
+            ));
+        }
+
+        if let Some(pending_below_price) = request.pending_below_price {
+            params.push((
+                "pendingBelowPrice".to_owned(),
+                pending_below_price.to_string(),
+            ));
+        }
+
+        if let Some(pending_below_stop_price) = request.pending_below_stop_price {
+            params.push((
+                "pendingBelowStopPrice".to_owned(),
+                pending_below_stop_price.to_string(),
+            ));
+        }
+
+        if let Some(pending_below_trailing_delta) = request.pending_below_trailing_delta {
+            params.push((
+                "pendingBelowTrailingDelta".to_owned(),
+                pending_below_trailing_delta.to_string(),
+            ));
+        }
+
+        if let Some(pending_below_iceberg_qty) = request.pending_below_iceberg_qty {
+            params.push((
+                "pendingBelowIcebergQty".to_owned(),
+                pending_below_iceberg_qty.to_string(),
+            ));
+        }
+
+        if let Some(pending_below_time_in_force) = request.pending_below_time_in_force {
+            params.push((
+                "pendingBelowTimeInForce".to_owned(),
+                pending_below_time_in_force.to_string(),
+            ));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/order/otoco".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        MarginNewOTOCOOrder, NewOrderResponseType, Side, TimeInForce, WorkingMandatoryParams,
+    };
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_new_otoco_order_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginNewOTOCOOrder::new(
+            "BNBUSDT",
+            WorkingMandatoryParams::new("LIMIT", Side::Buy, dec!(596.0), dec!(1.0)),
+            Side::Sell,
+            dec!(1.0),
+            "LIMIT_MAKER",
+        )
+        .is_isolated(true)
+        .list_client_order_id("list")
+        .new_order_resp_type(NewOrderResponseType::Ack)
+        .side_effect_type("MARGIN_BUY")
+        .self_trade_prevention_mode("EXPIRE_TAKER")
+        .auto_repay_at_cancel(false)
+        .working_client_order_id("working")
+        .working_iceberg_qty(dec!(0.5))
+        .working_time_in_force(TimeInForce::Gtc)
+        .pending_above_client_order_id("pending-above")
+        .pending_above_price(dec!(598.1))
+        .pending_above_stop_price(dec!(599))
+        .pending_above_trailing_delta(dec!(30))
+        .pending_above_iceberg_qty(dec!(0.5))
+        .pending_above_time_in_force(TimeInForce::Gtc)
+        .pending_below_type("STOP_LOSS_LIMIT")
+        .pending_below_client_order_id("pending-below")
+        .pending_below_price(dec!(598.1))
+        .pending_below_stop_price(dec!(599))
+        .pending_below_trailing_delta(dec!(30))
+        .pending_below_iceberg_qty(dec!(0.5))
+        .pending_below_time_in_force(TimeInForce::Gtc)
+        .recv_window(5000)
+        .credentials(&credentials)
+        .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/order/otoco".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("workingType".to_owned(), "LIMIT".to_string()),
+                    ("workingSide".to_owned(), "BUY".to_string()),
+                    ("workingPrice".to_owned(), "596.0".to_string()),
+                    ("workingQuantity".to_owned(), "1.0".to_string()),
+                    ("pendingSide".to_owned(), "SELL".to_string()),
+                    ("pendingQuantity".to_owned(), "1.0".to_string()),
+                    ("pendingAboveType".to_owned(), "LIMIT_MAKER".to_string()),
+                    ("isIsolated".to_owned(), "TRUE".to_string()),
+                    ("listClientOrderId".to_owned(), "list".to_string()),
+                    ("newOrderRespType".to_owned(), "ACK".to_string()),
+                    ("sideEffectType".to_owned(), "MARGIN_BUY".to_string()),
+                    (
+                        "selfTradePreventionMode".to_owned(),
+                        "EXPIRE_TAKER".to_string()
+                    ),
+                    ("autoRepayAtCancel".to_owned(), "false".to_string()),
+                    ("workingClientOrderId".to_owned(), "working".to_string()),
+                    ("workingIcebergQty".to_owned(), "0.5".to_string()),
+                    ("workingTimeInForce".to_owned(), "GTC".to_string()),
+                    (
+                        "pendingAboveClientOrderId".to_owned(),
+                        "pending-above".to_string()
+                    ),
+                    ("pendingAbovePrice".to_owned(), "598.1".to_string()),
+                    ("pendingAboveStopPrice".to_owned(), "599".to_string()),
+                    ("pendingAboveTrailingDelta".to_owned(), "30".to_string()),
+                    ("pendingAboveIcebergQty".to_owned(), "0.5".to_string()),
+                    ("pendingAboveTimeInForce".to_owned(), "GTC".to_string()),
+                    ("pendingBelowType".to_owned(), "STOP_LOSS_LIMIT".to_string()),
+                    (
+                        "pendingBelowClientOrderId".to_owned(),
+                        "pending-below".to_string()
+                    ),
+                    ("pendingBelowPrice".to_owned(), "598.1".to_string()),
+                    ("pendingBelowStopPrice".to_owned(), "599".to_string()),
+                    ("pendingBelowTrailingDelta".to_owned(), "30".to_string()),
+                    ("pendingBelowIcebergQty".to_owned(), "0.5".to_string()),
+                    ("pendingBelowTimeInForce".to_owned(), "GTC".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
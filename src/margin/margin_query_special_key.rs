@@ -0,0 +1,107 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/margin/apiKey`
+///
+/// Query the special key used for low-latency trading
+///
+/// Weight(UID): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_query_special_key();
+/// ```
+pub struct MarginQuerySpecialKey {
+    symbol: Option<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginQuerySpecialKey {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginQuerySpecialKey> for Request {
+    fn from(request: MarginQuerySpecialKey) -> Request {
+        let mut params = vec![];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/apiKey".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for MarginQuerySpecialKey {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginQuerySpecialKey;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_query_special_key_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginQuerySpecialKey::new()
+            .symbol("BNBUSDT")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/apiKey".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/margin/exchange-small-liability`
+///
+/// Small liability exchange
+///
+/// * Only available for cross margin accounts.
+/// * The assets to exchange are sent as a comma separated `assetNames` list.
+///
+/// Weight(UID): 3000
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_small_liability_exchange(vec!["BTC", "ETH"]);
+/// ```
+pub struct MarginSmallLiabilityExchange {
+    asset_names: Vec<String>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginSmallLiabilityExchange {
+    pub fn new(asset_names: Vec<&str>) -> Self {
+        Self {
+            asset_names: asset_names.iter().map(|s| s.to_string()).collect(),
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginSmallLiabilityExchange> for Request {
+    fn from(request: MarginSmallLiabilityExchange) -> Request {
+        let mut params = vec![("assetNames".to_owned(), request.asset_names.join(","))];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/exchange-small-liability".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginSmallLiabilityExchange;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_small_liability_exchange_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginSmallLiabilityExchange::new(vec!["BTC", "ETH"])
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/exchange-small-liability".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("assetNames".to_owned(), "BTC,ETH".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/margin/exchange-small-liability-history`
+///
+/// Get small liability exchange history
+///
+/// * If `startTime` and `endTime` are not sent, the recent 30 days' data will be returned.
+///
+/// Weight(UID): 100
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin;
+///
+/// let request = margin::margin_small_liability_exchange_history(1, 10);
+/// ```
+pub struct MarginSmallLiabilityExchangeHistory {
+    current: u64,
+    size: u64,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl MarginSmallLiabilityExchangeHistory {
+    pub fn new(current: u64, size: u64) -> Self {
+        Self {
+            current,
+            size,
+            start_time: None,
+            end_time: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<MarginSmallLiabilityExchangeHistory> for Request {
+    fn from(request: MarginSmallLiabilityExchangeHistory) -> Request {
+        let mut params = vec![
+            ("current".to_owned(), request.current.to_string()),
+            ("size".to_owned(), request.size.to_string()),
+        ];
+
+        if let Some(start_time) = request.start_time {
+            params.push(("startTime".to_owned(), start_time.to_string()));
+        }
+
+        if let Some(end_time) = request.end_time {
+            params.push(("endTime".to_owned(), end_time.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/margin/exchange-small-liability-history".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MarginSmallLiabilityExchangeHistory;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_margin_small_liability_exchange_history_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = MarginSmallLiabilityExchangeHistory::new(1, 10)
+            .start_time(1640995200000)
+            .end_time(1640995200000)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/margin/exchange-small-liability-history".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("current".to_owned(), "1".to_string()),
+                    ("size".to_owned(), "10".to_string()),
+                    ("startTime".to_owned(), "1640995200000".to_string()),
+                    ("endTime".to_owned(), "1640995200000".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/margin/transfer`
 ///
@@ -15,6 +21,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = margin::margin_transfer_history().asset("BNB").current(1).size(100);
 /// ```
+#[derive(Clone)]
 pub struct MarginTransferHistory {
     asset: Option<String>,
     r#type: Option<String>,
@@ -151,6 +158,52 @@ impl Default for MarginTransferHistory {
     }
 }
 
+impl TimeWindowed for MarginTransferHistory {
+    type Page = Rows<MarginTransfer>;
+    type Row = MarginTransfer;
+
+    fn max_window(&self) -> Option<u64> {
+        Some(30 * 86_400_000)
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.current = Some(page + 1);
+        request.size = Some(100);
+        request.into()
+    }
+
+    fn rows(page: Rows<MarginTransfer>) -> Vec<MarginTransfer> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Transfer returned by `GET /sapi/v1/margin/transfer`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTransfer {
+    pub amount: Decimal,
+    pub asset: String,
+    pub status: String,
+    pub timestamp: u64,
+    pub tx_id: u64,
+    pub r#type: String,
+    #[serde(default)]
+    pub trans_from: Option<String>,
+    #[serde(default)]
+    pub trans_to: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::MarginTransferHistory;
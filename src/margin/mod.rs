@@ -8,34 +8,52 @@ pub mod isolated_margin_disable_account;
 pub mod isolated_margin_enable_account;
 pub mod isolated_margin_fee_data;
 pub mod isolated_margin_tier_data;
+pub mod isolated_margin_transfer;
 pub mod margin_account;
 pub mod margin_account_borrow_repay;
+pub mod margin_adjust_max_leverage;
 pub mod margin_all_assets;
 pub mod margin_all_oco_order;
 pub mod margin_all_orders;
 pub mod margin_all_pairs;
+pub mod margin_available_inventory;
 pub mod margin_borrow_repay_records;
 pub mod margin_cancel_oco_order;
 pub mod margin_cancel_open_orders;
 pub mod margin_cancel_order;
+pub mod margin_capital_flow;
+pub mod margin_create_special_key;
+pub mod margin_cross_margin_collateral_ratio;
+pub mod margin_delete_special_key;
+pub mod margin_edit_special_key_ip;
 pub mod margin_fee_data;
 pub mod margin_force_liquidation_record;
 pub mod margin_interest_history;
 pub mod margin_interest_rate_history;
+pub mod margin_leverage_bracket;
 pub mod margin_max_borrowable;
 pub mod margin_max_transferable;
+pub mod margin_my_trade_history;
 pub mod margin_my_trades;
 pub mod margin_new_oco_order;
 pub mod margin_new_order;
+pub mod margin_new_oto_order;
+pub mod margin_new_otoco_order;
 pub mod margin_oco_order;
 pub mod margin_open_oco_order;
 pub mod margin_open_orders;
 pub mod margin_order;
 pub mod margin_order_count_usage;
 pub mod margin_price_index;
+pub mod margin_query_special_key;
+pub mod margin_query_special_key_list;
+pub mod margin_small_liability_exchange;
+pub mod margin_small_liability_exchange_coin_list;
+pub mod margin_small_liability_exchange_history;
 pub mod margin_transfer_history;
 pub mod toggle_bnb_burn;
 
+use crate::trade::order::{Side, WorkingMandatoryParams};
 use rust_decimal::Decimal;
 
 use bnb_burn_status::BNBBurnStatus;
@@ -46,31 +64,48 @@ use isolated_margin_disable_account::IsolatedMarginDisableAccount;
 use isolated_margin_enable_account::IsolatedMarginEnableAccount;
 use isolated_margin_fee_data::IsolatedMarginFeeData;
 use isolated_margin_tier_data::IsolatedMarginTierData;
+use isolated_margin_transfer::IsolatedMarginTransfer;
 use margin_account::MarginAccount;
 use margin_account_borrow_repay::MarginAccountBorrowRepay;
+use margin_adjust_max_leverage::MarginAdjustMaxLeverage;
 use margin_all_assets::MarginAllAssets;
 use margin_all_oco_order::MarginAllOCOOrder;
 use margin_all_orders::MarginAllOrders;
 use margin_all_pairs::MarginAllPairs;
+use margin_available_inventory::MarginAvailableInventory;
 use margin_borrow_repay_records::MarginBorrowRepayRecords;
 use margin_cancel_oco_order::MarginCancelOCOOrder;
 use margin_cancel_open_orders::MarginCancelOpenOrders;
 use margin_cancel_order::MarginCancelOrder;
+use margin_capital_flow::MarginCapitalFlow;
+use margin_create_special_key::MarginCreateSpecialKey;
+use margin_cross_margin_collateral_ratio::MarginCrossMarginCollateralRatio;
+use margin_delete_special_key::MarginDeleteSpecialKey;
+use margin_edit_special_key_ip::MarginEditSpecialKeyIp;
 use margin_fee_data::MarginFeeData;
 use margin_force_liquidation_record::MarginForceLiquidationRecord;
 use margin_interest_history::MarginInterestHistory;
 use margin_interest_rate_history::MarginInterestRateHistory;
+use margin_leverage_bracket::MarginLeverageBracket;
 use margin_max_borrowable::MarginMaxBorrowable;
 use margin_max_transferable::MarginMaxTransferable;
+use margin_my_trade_history::MarginMyTradeHistory;
 use margin_my_trades::MarginMyTrades;
 use margin_new_oco_order::MarginNewOCOOrder;
 use margin_new_order::MarginNewOrder;
+use margin_new_oto_order::MarginNewOTOOrder;
+use margin_new_otoco_order::MarginNewOTOCOOrder;
 use margin_oco_order::MarginOCOOrder;
 use margin_open_oco_order::MarginOpenOCOOrder;
 use margin_open_orders::MarginOpenOrders;
 use margin_order::MarginOrder;
 use margin_order_count_usage::MarginOrderCountUsage;
 use margin_price_index::MarginPriceIndex;
+use margin_query_special_key::MarginQuerySpecialKey;
+use margin_query_special_key_list::MarginQuerySpecialKeyList;
+use margin_small_liability_exchange::MarginSmallLiabilityExchange;
+use margin_small_liability_exchange_coin_list::MarginSmallLiabilityExchangeCoinList;
+use margin_small_liability_exchange_history::MarginSmallLiabilityExchangeHistory;
 use margin_transfer_history::MarginTransferHistory;
 use toggle_bnb_burn::ToggleBNBBurn;
 
@@ -170,6 +205,10 @@ pub fn margin_my_trades(symbol: &str) -> MarginMyTrades {
     MarginMyTrades::new(symbol)
 }
 
+pub fn margin_my_trade_history(symbol: &str) -> MarginMyTradeHistory {
+    MarginMyTradeHistory::new(symbol)
+}
+
 pub fn margin_max_borrowable(asset: &str) -> MarginMaxBorrowable {
     MarginMaxBorrowable::new(asset)
 }
@@ -225,3 +264,100 @@ pub fn isolated_margin_tier_data(symbol: &str) -> IsolatedMarginTierData {
 pub fn margin_order_count_usage() -> MarginOrderCountUsage {
     MarginOrderCountUsage::new()
 }
+
+pub fn isolated_margin_transfer(
+    asset: &str,
+    symbol: &str,
+    trans_from: &str,
+    trans_to: &str,
+    amount: Decimal,
+) -> IsolatedMarginTransfer {
+    IsolatedMarginTransfer::new(asset, symbol, trans_from, trans_to, amount)
+}
+
+pub fn margin_adjust_max_leverage(max_leverage: u32) -> MarginAdjustMaxLeverage {
+    MarginAdjustMaxLeverage::new(max_leverage)
+}
+
+pub fn margin_cross_margin_collateral_ratio() -> MarginCrossMarginCollateralRatio {
+    MarginCrossMarginCollateralRatio::new()
+}
+
+pub fn margin_capital_flow() -> MarginCapitalFlow {
+    MarginCapitalFlow::new()
+}
+
+pub fn margin_available_inventory(r#type: &str) -> MarginAvailableInventory {
+    MarginAvailableInventory::new(r#type)
+}
+
+pub fn margin_leverage_bracket() -> MarginLeverageBracket {
+    MarginLeverageBracket::new()
+}
+
+pub fn margin_small_liability_exchange(asset_names: Vec<&str>) -> MarginSmallLiabilityExchange {
+    MarginSmallLiabilityExchange::new(asset_names)
+}
+
+pub fn margin_small_liability_exchange_coin_list() -> MarginSmallLiabilityExchangeCoinList {
+    MarginSmallLiabilityExchangeCoinList::new()
+}
+
+pub fn margin_small_liability_exchange_history(
+    current: u64,
+    size: u64,
+) -> MarginSmallLiabilityExchangeHistory {
+    MarginSmallLiabilityExchangeHistory::new(current, size)
+}
+
+pub fn margin_create_special_key(api_name: &str) -> MarginCreateSpecialKey {
+    MarginCreateSpecialKey::new(api_name)
+}
+
+pub fn margin_delete_special_key() -> MarginDeleteSpecialKey {
+    MarginDeleteSpecialKey::new()
+}
+
+pub fn margin_edit_special_key_ip(ip: &str) -> MarginEditSpecialKeyIp {
+    MarginEditSpecialKeyIp::new(ip)
+}
+
+pub fn margin_query_special_key() -> MarginQuerySpecialKey {
+    MarginQuerySpecialKey::new()
+}
+
+pub fn margin_query_special_key_list() -> MarginQuerySpecialKeyList {
+    MarginQuerySpecialKeyList::new()
+}
+
+pub fn margin_new_oto_order(
+    symbol: &str,
+    working_mandatory_params: WorkingMandatoryParams,
+    pending_type: &str,
+    pending_side: Side,
+    pending_quantity: Decimal,
+) -> MarginNewOTOOrder {
+    MarginNewOTOOrder::new(
+        symbol,
+        working_mandatory_params,
+        pending_type,
+        pending_side,
+        pending_quantity,
+    )
+}
+
+pub fn margin_new_otoco_order(
+    symbol: &str,
+    working_mandatory_params: WorkingMandatoryParams,
+    pending_side: Side,
+    pending_quantity: Decimal,
+    pending_above_type: &str,
+) -> MarginNewOTOCOOrder {
+    MarginNewOTOCOOrder::new(
+        symbol,
+        working_mandatory_params,
+        pending_side,
+        pending_quantity,
+        pending_above_type,
+    )
+}
@@ -2,10 +2,12 @@
 
 pub mod close_listen_key;
 pub mod new_listen_key;
+pub mod new_listen_token;
 pub mod renew_listen_key;
 
 use close_listen_key::CloseListenKey;
 use new_listen_key::NewListenKey;
+use new_listen_token::NewListenToken;
 use renew_listen_key::RenewListenKey;
 
 pub fn new_listen_key() -> NewListenKey {
@@ -19,3 +21,7 @@ pub fn renew_listen_key(listen_key: &str) -> RenewListenKey {
 pub fn close_listen_key(listen_key: &str) -> CloseListenKey {
     CloseListenKey::new(listen_key)
 }
+
+pub fn new_listen_token() -> NewListenToken {
+    NewListenToken::new()
+}
@@ -0,0 +1,141 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/userListenToken`
+///
+/// Create a listen token for the margin user data stream.
+///
+/// The token is used to subscribe to the margin user data stream over the WebSocket API with `userDataStream.subscribe.listenToken`, replacing the `listenKey` issued by `POST /sapi/v1/userDataStream`.
+///
+/// * `validity` is the token lifetime in milliseconds, up to 24 hours.
+/// * Send `symbol` with `isIsolated` set to `true` to create a token for an isolated margin account.
+///
+/// Weight(UID): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::margin_stream;
+///
+/// let request = margin_stream::new_listen_token().validity(86400000);
+/// ```
+pub struct NewListenToken {
+    symbol: Option<String>,
+    is_isolated: Option<bool>,
+    validity: Option<u64>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl NewListenToken {
+    pub fn new() -> Self {
+        Self {
+            symbol: None,
+            is_isolated: None,
+            validity: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+
+    pub fn is_isolated(mut self, is_isolated: bool) -> Self {
+        self.is_isolated = Some(is_isolated);
+        self
+    }
+
+    pub fn validity(mut self, validity: u64) -> Self {
+        self.validity = Some(validity);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<NewListenToken> for Request {
+    fn from(request: NewListenToken) -> Request {
+        let mut params = vec![];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        if let Some(is_isolated) = request.is_isolated {
+            params.push((
+                "isIsolated".to_owned(),
+                is_isolated.to_string().to_uppercase(),
+            ));
+        }
+
+        if let Some(validity) = request.validity {
+            params.push(("validity".to_owned(), validity.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/userListenToken".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for NewListenToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NewListenToken;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn margin_stream_new_listen_token_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = NewListenToken::new()
+            .symbol("BNBUSDT")
+            .is_isolated(true)
+            .validity(86400000)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/userListenToken".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("isIsolated".to_owned(), "TRUE".to_string()),
+                    ("validity".to_owned(), "86400000".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
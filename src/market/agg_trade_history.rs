@@ -0,0 +1,188 @@
+#![allow(clippy::wrong_self_convention)]
+
+use crate::http::{
+    pagination::{Paginate, DEFAULT_WEIGHT_LIMIT},
+    request::Request,
+};
+use crate::market::agg_trades::AggTrades;
+use crate::market::trade_history::{TradeCursor, TradePage, TradeRow, MAX_TRADES_LIMIT};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Paginated aggregate trade history.
+///
+/// Walks `GET /api/v3/aggTrades` by aggregate trade id, from `from_id`, from
+/// `start_time` or from the first aggregate trade of the symbol, until `end_time`
+/// or the most recent aggregate trade.
+///
+/// * The server only accepts time ranges of up to 1 hour: when starting from
+///   `start_time`, 1 hour windows are requested until a first trade is found.
+///
+/// The history is consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::market;
+///
+/// let history = market::agg_trade_history("BNBUSDT")
+///     .start_time(1640995200000)
+///     .end_time(1672531200000);
+/// ```
+pub struct AggTradeHistory {
+    symbol: String,
+    cursor: TradeCursor,
+    weight_limit: Option<u32>,
+}
+
+impl AggTradeHistory {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            cursor: TradeCursor::new(3_600_000),
+            weight_limit: Some(DEFAULT_WEIGHT_LIMIT),
+        }
+    }
+
+    /// Start from the aggregate trade `from_id`, takes precedence over `start_time`.
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.cursor.from_id = Some(from_id);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.cursor.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.cursor.end_time = Some(end_time);
+        self
+    }
+
+    /// Number of aggregate trades per page, capped to 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cursor.limit = limit.clamp(1, MAX_TRADES_LIMIT);
+        self
+    }
+
+    /// Request weight per minute not to exceed, `None` to never wait between pages.
+    pub fn weight_limit(mut self, weight_limit: Option<u32>) -> Self {
+        self.weight_limit = weight_limit;
+        self
+    }
+
+    /// Id of the last aggregate trade emitted.
+    pub fn last_id(&self) -> Option<u64> {
+        self.cursor.last_id()
+    }
+}
+
+impl Paginate for AggTradeHistory {
+    type Page = Vec<AggTrade>;
+    type Item = AggTrade;
+
+    fn next_request(&self) -> Option<Request> {
+        let request = AggTrades::new(&self.symbol).limit(self.cursor.limit);
+        let request = match self.cursor.next_page()? {
+            TradePage::Time(start_time, end_time) => {
+                request.start_time(start_time).end_time(end_time)
+            }
+            TradePage::Id(from_id) => request.from_id(from_id),
+        };
+
+        Some(request.into())
+    }
+
+    fn handle_page(&mut self, trades: Vec<AggTrade>) -> Vec<AggTrade> {
+        self.cursor.handle_page(trades)
+    }
+
+    fn page_weight(&self) -> u32 {
+        2
+    }
+
+    fn max_weight(&self) -> Option<u32> {
+        self.weight_limit
+    }
+}
+
+/// Aggregate trade returned by `GET /api/v3/aggTrades`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "q")]
+    pub qty: Decimal,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+    #[serde(rename = "M")]
+    pub is_best_match: bool,
+}
+
+impl TradeRow for AggTrade {
+    fn id(&self) -> u64 {
+        self.agg_trade_id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AggTrade, AggTradeHistory};
+    use crate::http::{pagination::Paginate, request::Request, Method};
+
+    #[test]
+    fn market_agg_trade_history_next_request_test() {
+        let mut history = AggTradeHistory::new("BNBUSDT")
+            .start_time(1640995200000)
+            .end_time(1672531200000);
+
+        assert_eq!(
+            history.next_request(),
+            Some(Request {
+                path: "/api/v3/aggTrades".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("startTime".to_owned(), "1640995200000".to_string()),
+                    ("endTime".to_owned(), "1640998799999".to_string()),
+                    ("limit".to_owned(), "1000".to_string()),
+                ],
+                sign: false
+            })
+        );
+
+        let data = r#"[{"a":26129,"p":"0.01633102","q":"4.70443515","f":27781,"l":27781,"T":1640995300000,"m":true,"M":true}]"#;
+        let trades: Vec<AggTrade> = serde_json::from_str(data).unwrap();
+        assert_eq!(history.handle_page(trades).len(), 1);
+
+        assert_eq!(
+            history.next_request(),
+            Some(Request {
+                path: "/api/v3/aggTrades".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("fromId".to_owned(), "26130".to_string()),
+                    ("limit".to_owned(), "1000".to_string()),
+                ],
+                sign: false
+            })
+        );
+    }
+}
@@ -0,0 +1,390 @@
+use crate::http::{
+    pagination::{Paginate, DEFAULT_WEIGHT_LIMIT},
+    request::Request,
+};
+use crate::market::klines::{KlineInterval, Klines};
+use crate::market::ui_klines::UIKlines;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Maximum number of klines returned by a single `klines` or `uiKlines` request.
+pub const MAX_KLINES_LIMIT: u32 = 1000;
+
+/// Paginated kline history.
+///
+/// Walks `[start_time, end_time]` with `GET /api/v3/klines` or `GET /api/v3/uiKlines`
+/// requests of up to 1000 bars. Both endpoints have the same weight whatever the
+/// `limit`, so every page requests the maximum by default.
+///
+/// * Klines are de-duplicated on their open time.
+/// * A [`KlineHistoryItem::Gap`] is emitted when bars are missing between two
+///   consecutive klines, e.g. during a trading halt. Gaps cannot be detected for
+///   `1M` klines.
+/// * When the `x-mbx-used-weight-1m` response header shows that the next page would
+///   exceed the weight limit, the client waits for the next minute window.
+/// * [`KlineHistory::resume_after`] restarts the download after the last stored bar.
+///
+/// The history is consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::market::{self, klines::KlineInterval};
+///
+/// let history = market::kline_history("BTCUSDT", KlineInterval::Minutes1, 1654079109000)
+///     .end_time(1685615109000)
+///     .resume_after(1654162200000);
+/// ```
+pub struct KlineHistory {
+    symbol: String,
+    interval: KlineInterval,
+    start_time: u64,
+    end_time: Option<u64>,
+    time_zone: Option<String>,
+    limit: u32,
+    ui_klines: bool,
+    weight_limit: Option<u32>,
+    last_open_time: Option<u64>,
+    done: bool,
+}
+
+impl KlineHistory {
+    pub fn new(symbol: &str, interval: KlineInterval, start_time: u64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            interval,
+            start_time,
+            end_time: None,
+            time_zone: None,
+            limit: MAX_KLINES_LIMIT,
+            ui_klines: false,
+            weight_limit: Some(DEFAULT_WEIGHT_LIMIT),
+            last_open_time: None,
+            done: false,
+        }
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.to_owned());
+        self
+    }
+
+    /// Number of klines per page, capped to 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit.clamp(1, MAX_KLINES_LIMIT);
+        self
+    }
+
+    /// Download `uiKlines` instead of `klines`.
+    pub fn ui_klines(mut self, ui_klines: bool) -> Self {
+        self.ui_klines = ui_klines;
+        self
+    }
+
+    /// Request weight per minute not to exceed, `None` to never wait between pages.
+    pub fn weight_limit(mut self, weight_limit: Option<u32>) -> Self {
+        self.weight_limit = weight_limit;
+        self
+    }
+
+    /// Resume the download after the kline opened at `open_time`.
+    ///
+    /// Klines up to `open_time` are skipped and a gap after it is still detected.
+    pub fn resume_after(mut self, open_time: u64) -> Self {
+        self.last_open_time = Some(open_time);
+        self
+    }
+
+    /// Open time of the last kline emitted.
+    pub fn last_open_time(&self) -> Option<u64> {
+        self.last_open_time
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+}
+
+impl Paginate for KlineHistory {
+    type Page = Vec<Kline>;
+    type Item = KlineHistoryItem;
+
+    fn next_request(&self) -> Option<Request> {
+        if self.done {
+            return None;
+        }
+
+        let start_time = match self.last_open_time {
+            Some(last_open_time) => self.start_time.max(last_open_time + 1),
+            None => self.start_time,
+        };
+
+        if self.end_time.map(|end| start_time > end).unwrap_or(false) {
+            return None;
+        }
+
+        let request = if self.ui_klines {
+            let mut request = UIKlines::new(&self.symbol, self.interval)
+                .start_time(start_time)
+                .limit(self.limit);
+            if let Some(end_time) = self.end_time {
+                request = request.end_time(end_time);
+            }
+            if let Some(time_zone) = &self.time_zone {
+                request = request.time_zone(time_zone);
+            }
+            request.into()
+        } else {
+            let mut request = Klines::new(&self.symbol, self.interval)
+                .start_time(start_time)
+                .limit(self.limit);
+            if let Some(end_time) = self.end_time {
+                request = request.end_time(end_time);
+            }
+            if let Some(time_zone) = &self.time_zone {
+                request = request.time_zone(time_zone);
+            }
+            request.into()
+        };
+
+        Some(request)
+    }
+
+    fn handle_page(&mut self, klines: Vec<Kline>) -> Vec<KlineHistoryItem> {
+        let is_last_page = klines.len() < self.limit as usize;
+        let interval = self.interval.as_millis();
+
+        let mut items = vec![];
+        for kline in klines {
+            if let Some(last_open_time) = self.last_open_time {
+                if kline.open_time <= last_open_time {
+                    continue;
+                }
+
+                if let Some(interval) = interval {
+                    if kline.open_time > last_open_time + interval {
+                        items.push(KlineHistoryItem::Gap(KlineGap {
+                            start_time: last_open_time + interval,
+                            end_time: kline.open_time,
+                        }));
+                    }
+                }
+            }
+
+            self.last_open_time = Some(kline.open_time);
+            items.push(KlineHistoryItem::Kline(kline));
+        }
+
+        if is_last_page {
+            self.done = true;
+        }
+
+        items
+    }
+
+    fn page_weight(&self) -> u32 {
+        2
+    }
+
+    fn max_weight(&self) -> Option<u32> {
+        self.weight_limit
+    }
+}
+
+/// Item of a [`KlineHistory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KlineHistoryItem {
+    Kline(Kline),
+    /// No kline was opened between `start_time` included and `end_time` excluded.
+    Gap(KlineGap),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KlineGap {
+    pub start_time: u64,
+    pub end_time: u64,
+}
+
+/// Kline returned by `GET /api/v3/klines` and `GET /api/v3/uiKlines`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "KlineRow")]
+pub struct Kline {
+    pub open_time: u64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    pub volume: Decimal,
+    pub close_time: u64,
+    pub quote_asset_volume: Decimal,
+    pub number_of_trades: u64,
+    pub taker_buy_base_asset_volume: Decimal,
+    pub taker_buy_quote_asset_volume: Decimal,
+}
+
+#[derive(Deserialize)]
+struct KlineRow(
+    u64,
+    Decimal,
+    Decimal,
+    Decimal,
+    Decimal,
+    Decimal,
+    u64,
+    Decimal,
+    u64,
+    Decimal,
+    Decimal,
+    serde::de::IgnoredAny,
+);
+
+impl From<KlineRow> for Kline {
+    fn from(row: KlineRow) -> Kline {
+        Kline {
+            open_time: row.0,
+            open: row.1,
+            high: row.2,
+            low: row.3,
+            close: row.4,
+            volume: row.5,
+            close_time: row.6,
+            quote_asset_volume: row.7,
+            number_of_trades: row.8,
+            taker_buy_base_asset_volume: row.9,
+            taker_buy_quote_asset_volume: row.10,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Kline, KlineGap, KlineHistory, KlineHistoryItem};
+    use crate::http::{pagination::Paginate, request::Request, Method};
+    use crate::market::klines::KlineInterval;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    fn kline(open_time: u64) -> Kline {
+        Kline {
+            open_time,
+            open: dec!(1),
+            high: dec!(1),
+            low: dec!(1),
+            close: dec!(1),
+            volume: dec!(1),
+            close_time: open_time + 59_999,
+            quote_asset_volume: dec!(1),
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: dec!(1),
+            taker_buy_quote_asset_volume: dec!(1),
+        }
+    }
+
+    #[test]
+    fn market_kline_history_parse_kline_test() {
+        let data = r#"[[1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100","148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]]"#;
+
+        let klines: Vec<Kline> = serde_json::from_str(data).unwrap();
+
+        assert_eq!(klines[0].open_time, 1499040000000);
+        assert_eq!(klines[0].high, dec!(0.8));
+        assert_eq!(klines[0].number_of_trades, 308);
+        assert_eq!(klines[0].taker_buy_quote_asset_volume, dec!(28.46694368));
+    }
+
+    #[test]
+    fn market_kline_history_paginate_test() {
+        let mut history = KlineHistory::new("BTCUSDT", KlineInterval::Minutes1, 60_000)
+            .end_time(600_000)
+            .limit(3)
+            .ui_klines(true);
+
+        let request = history.next_request().unwrap();
+        assert_eq!(
+            request,
+            Request {
+                path: "/api/v3/uiKlines".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BTCUSDT".to_string()),
+                    ("interval".to_owned(), "1m".to_string()),
+                    ("startTime".to_owned(), "60000".to_string()),
+                    ("endTime".to_owned(), "600000".to_string()),
+                    ("limit".to_owned(), "3".to_string()),
+                ],
+                sign: false
+            }
+        );
+
+        let items = history.handle_page(vec![kline(60_000), kline(120_000), kline(300_000)]);
+        assert_eq!(
+            items,
+            vec![
+                KlineHistoryItem::Kline(kline(60_000)),
+                KlineHistoryItem::Kline(kline(120_000)),
+                KlineHistoryItem::Gap(KlineGap {
+                    start_time: 180_000,
+                    end_time: 300_000
+                }),
+                KlineHistoryItem::Kline(kline(300_000)),
+            ]
+        );
+
+        let request = history.next_request().unwrap();
+        assert!(request
+            .params
+            .contains(&("startTime".to_owned(), "300001".to_owned())));
+
+        let items = history.handle_page(vec![kline(300_000), kline(360_000)]);
+        assert_eq!(items, vec![KlineHistoryItem::Kline(kline(360_000))]);
+        assert!(history.is_done());
+        assert_eq!(history.next_request(), None);
+    }
+
+    #[test]
+    fn market_kline_history_resume_after_test() {
+        let mut history =
+            KlineHistory::new("BTCUSDT", KlineInterval::Minutes1, 0).resume_after(120_000);
+
+        let request = history.next_request().unwrap();
+        assert!(request
+            .params
+            .contains(&("startTime".to_owned(), "120001".to_owned())));
+
+        let items = history.handle_page(vec![kline(240_000)]);
+        assert_eq!(
+            items,
+            vec![
+                KlineHistoryItem::Gap(KlineGap {
+                    start_time: 180_000,
+                    end_time: 240_000
+                }),
+                KlineHistoryItem::Kline(kline(240_000)),
+            ]
+        );
+        assert_eq!(history.last_open_time(), Some(240_000));
+    }
+
+    #[test]
+    fn market_kline_history_throttle_test() {
+        let history = KlineHistory::new("BTCUSDT", KlineInterval::Minutes1, 0);
+
+        assert_eq!(history.throttle(Some(5998), 1_000_000), None);
+        assert_eq!(history.throttle(None, 1_000_000), None);
+        assert_eq!(
+            history.throttle(Some(5999), 1_000_000),
+            Some(Duration::from_millis(20_000))
+        );
+        assert_eq!(
+            history.weight_limit(None).throttle(Some(5999), 1_000_000),
+            None
+        );
+    }
+}
@@ -3,6 +3,8 @@ use strum::Display;
 
 #[derive(Copy, Clone, Display)]
 pub enum KlineInterval {
+    #[strum(serialize = "1s")]
+    Seconds1,
     #[strum(serialize = "1m")]
     Minutes1,
     #[strum(serialize = "3m")]
@@ -35,12 +37,43 @@ pub enum KlineInterval {
     Months1,
 }
 
+impl KlineInterval {
+    /// Duration of the interval in milliseconds, `None` for `1M` whose length varies.
+    pub fn as_millis(&self) -> Option<u64> {
+        const SECOND: u64 = 1_000;
+        const MINUTE: u64 = 60 * SECOND;
+        const HOUR: u64 = 60 * MINUTE;
+        const DAY: u64 = 24 * HOUR;
+
+        match self {
+            KlineInterval::Seconds1 => Some(SECOND),
+            KlineInterval::Minutes1 => Some(MINUTE),
+            KlineInterval::Minutes3 => Some(3 * MINUTE),
+            KlineInterval::Minutes5 => Some(5 * MINUTE),
+            KlineInterval::Minutes15 => Some(15 * MINUTE),
+            KlineInterval::Minutes30 => Some(30 * MINUTE),
+            KlineInterval::Hours1 => Some(HOUR),
+            KlineInterval::Hours2 => Some(2 * HOUR),
+            KlineInterval::Hours4 => Some(4 * HOUR),
+            KlineInterval::Hours6 => Some(6 * HOUR),
+            KlineInterval::Hours8 => Some(8 * HOUR),
+            KlineInterval::Hours12 => Some(12 * HOUR),
+            KlineInterval::Days1 => Some(DAY),
+            KlineInterval::Days3 => Some(3 * DAY),
+            KlineInterval::Weeks1 => Some(7 * DAY),
+            KlineInterval::Months1 => None,
+        }
+    }
+}
+
 /// `GET /api/v3/klines`
 ///
 /// Kline/candlestick bars for a symbol.
 /// Klines are uniquely identified by their open time.
 ///
 /// * If `startTime` and `endTime` are not sent, the most recent klines are returned.
+/// * With `timeZone`, e.g. `+08:00`, kline intervals are interpreted in that time zone.
+///   `startTime` and `endTime` are always interpreted in UTC.
 ///
 /// Weight(IP): 2
 ///
@@ -138,6 +171,7 @@ mod tests {
         let request: Request = Klines::new("BTCUSDT", KlineInterval::Minutes1)
             .start_time(1654079109000)
             .end_time(1654079209000)
+            .time_zone("+08:00")
             .limit(100)
             .into();
 
@@ -152,6 +186,7 @@ mod tests {
                     ("interval".to_owned(), "1m".to_string()),
                     ("startTime".to_owned(), "1654079109000".to_string()),
                     ("endTime".to_owned(), "1654079209000".to_string()),
+                    ("timeZone".to_owned(), "+08:00".to_string()),
                     ("limit".to_owned(), "100".to_string())
                 ],
                 sign: false
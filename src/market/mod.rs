@@ -1,11 +1,13 @@
 //! Market Data
 
+pub mod agg_trade_history;
 pub mod agg_trades;
 pub mod avg_price;
 pub mod book_ticker;
 pub mod depth;
 pub mod exchange_info;
 pub mod historical_trades;
+pub mod kline_history;
 pub mod klines;
 pub mod ping;
 pub mod rolling_window_price_change_statistics;
@@ -13,15 +15,18 @@ pub mod ticker_price;
 pub mod ticker_trading_day;
 pub mod ticker_twenty_four_hr;
 pub mod time;
+pub mod trade_history;
 pub mod trades;
 pub mod ui_klines;
 
+use agg_trade_history::AggTradeHistory;
 use agg_trades::AggTrades;
 use avg_price::AvgPrice;
 use book_ticker::BookTicker;
 use depth::Depth;
 use exchange_info::ExchangeInfo;
 use historical_trades::HistoricalTrades;
+use kline_history::KlineHistory;
 use klines::{KlineInterval, Klines};
 use ping::Ping;
 use rolling_window_price_change_statistics::RollingWindowPriceChangeStatistics;
@@ -29,6 +34,7 @@ use ticker_price::TickerPrice;
 use ticker_trading_day::TickerTradingDay;
 use ticker_twenty_four_hr::Ticker24hr;
 use time::Time;
+use trade_history::HistoricalTradeHistory;
 use trades::Trades;
 use ui_klines::UIKlines;
 
@@ -91,3 +97,15 @@ pub fn rolling_window_price_change_statistics() -> RollingWindowPriceChangeStati
 pub fn ui_klines(symbol: &str, interval: KlineInterval) -> UIKlines {
     UIKlines::new(symbol, interval)
 }
+
+pub fn kline_history(symbol: &str, interval: KlineInterval, start_time: u64) -> KlineHistory {
+    KlineHistory::new(symbol, interval, start_time)
+}
+
+pub fn historical_trade_history(symbol: &str) -> HistoricalTradeHistory {
+    HistoricalTradeHistory::new(symbol)
+}
+
+pub fn agg_trade_history(symbol: &str) -> AggTradeHistory {
+    AggTradeHistory::new(symbol)
+}
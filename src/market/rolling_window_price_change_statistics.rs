@@ -1,4 +1,6 @@
 use crate::http::{request::Request, Method};
+use std::error::Error;
+use std::fmt;
 use strum::Display;
 
 #[derive(Copy, Clone, Display)]
@@ -8,6 +10,90 @@ pub enum TickerType {
     Mini,
 }
 
+/// Window of the rolling window ticker streams.
+#[derive(Copy, Clone, Display)]
+pub enum RollingWindow {
+    #[strum(serialize = "1h")]
+    Hours1,
+    #[strum(serialize = "4h")]
+    Hours4,
+    #[strum(serialize = "1d")]
+    Days1,
+}
+
+/// Window used by `GET /api/v3/ticker` to compute the statistics.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WindowSize {
+    count: u8,
+    unit: WindowUnit,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum WindowUnit {
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl WindowSize {
+    /// 1 to 59 minutes.
+    pub fn minutes(minutes: u8) -> Result<Self, InvalidWindowSize> {
+        Self::new(minutes, WindowUnit::Minutes, 59)
+    }
+
+    /// 1 to 23 hours.
+    pub fn hours(hours: u8) -> Result<Self, InvalidWindowSize> {
+        Self::new(hours, WindowUnit::Hours, 23)
+    }
+
+    /// 1 to 7 days.
+    pub fn days(days: u8) -> Result<Self, InvalidWindowSize> {
+        Self::new(days, WindowUnit::Days, 7)
+    }
+
+    fn new(count: u8, unit: WindowUnit, max: u8) -> Result<Self, InvalidWindowSize> {
+        let window_size = Self { count, unit };
+        match (1..=max).contains(&count) {
+            true => Ok(window_size),
+            false => Err(InvalidWindowSize(window_size.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for WindowSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            WindowUnit::Minutes => "m",
+            WindowUnit::Hours => "h",
+            WindowUnit::Days => "d",
+        };
+        write!(f, "{}{}", self.count, unit)
+    }
+}
+
+impl From<RollingWindow> for WindowSize {
+    fn from(window: RollingWindow) -> WindowSize {
+        let (count, unit) = match window {
+            RollingWindow::Hours1 => (1, WindowUnit::Hours),
+            RollingWindow::Hours4 => (4, WindowUnit::Hours),
+            RollingWindow::Days1 => (1, WindowUnit::Days),
+        };
+        WindowSize { count, unit }
+    }
+}
+
+/// Window size outside of `1m` to `59m`, `1h` to `23h` and `1d` to `7d`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidWindowSize(pub String);
+
+impl fmt::Display for InvalidWindowSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid window size {}", self.0)
+    }
+}
+
+impl Error for InvalidWindowSize {}
+
 /// `GET /api/v3/ticker`
 ///
 /// The window used to compute statistics is typically slightly wider than requested windowSize.
@@ -23,14 +109,16 @@ pub enum TickerType {
 /// # Example
 ///
 /// ```
-/// use binance_spot_connector_rust::market;
+/// use binance_spot_connector_rust::market::{self, rolling_window_price_change_statistics::WindowSize};
 ///
-/// let request = market::rolling_window_price_change_statistics().symbols(vec!["BTCUSDT","BNBBTC"]);
+/// let request = market::rolling_window_price_change_statistics()
+///     .symbols(vec!["BTCUSDT","BNBBTC"])
+///     .window_size(WindowSize::minutes(15).expect("Invalid window size"));
 /// ```
 pub struct RollingWindowPriceChangeStatistics {
     symbol: Option<String>,
     symbols: Option<Vec<String>>,
-    window_size: Option<String>,
+    window_size: Option<WindowSize>,
     ticker_type: Option<TickerType>,
 }
 
@@ -54,8 +142,10 @@ impl RollingWindowPriceChangeStatistics {
         self
     }
 
-    pub fn window_size(mut self, window_size: &str) -> Self {
-        self.window_size = Some(window_size.to_owned());
+    /// Window of 1 to 59 minutes, 1 to 23 hours or 1 to 7 days, also
+    /// accepting the [`RollingWindow`] of the streams.
+    pub fn window_size(mut self, window_size: impl Into<WindowSize>) -> Self {
+        self.window_size = Some(window_size.into());
         self
     }
 
@@ -87,7 +177,7 @@ impl From<RollingWindowPriceChangeStatistics> for Request {
         }
 
         if let Some(window_size) = request.window_size {
-            params.push(("windowSize".to_owned(), window_size));
+            params.push(("windowSize".to_owned(), window_size.to_string()));
         }
 
         if let Some(ticker_type) = request.ticker_type {
@@ -106,13 +196,14 @@ impl From<RollingWindowPriceChangeStatistics> for Request {
 
 #[cfg(test)]
 mod tests {
-    use super::RollingWindowPriceChangeStatistics;
+    use super::{RollingWindow, RollingWindowPriceChangeStatistics, WindowSize};
     use crate::http::{request::Request, Method};
 
     #[test]
     fn market_rolling_window_price_change_statistics_convert_to_request_test() {
         let request: Request = RollingWindowPriceChangeStatistics::new()
             .symbols(vec!["BTCUSDT", "BNBBTC"])
+            .window_size(RollingWindow::Hours4)
             .into();
 
         assert_eq!(
@@ -121,9 +212,33 @@ mod tests {
                 path: "/api/v3/ticker".to_owned(),
                 credentials: None,
                 method: Method::Get,
-                params: vec![("symbols".to_owned(), "[\"BTCUSDT\",\"BNBBTC\"]".to_string())],
+                params: vec![
+                    ("symbols".to_owned(), "[\"BTCUSDT\",\"BNBBTC\"]".to_string()),
+                    ("windowSize".to_owned(), "4h".to_string()),
+                ],
                 sign: false
             }
         );
+
+        let request: Request = RollingWindowPriceChangeStatistics::new()
+            .symbol("BTCUSDT")
+            .window_size(WindowSize::days(2).unwrap())
+            .into();
+        assert_eq!(
+            request.params,
+            vec![
+                ("symbol".to_owned(), "BTCUSDT".to_string()),
+                ("windowSize".to_owned(), "2d".to_string()),
+            ]
+        );
+        assert_eq!(WindowSize::minutes(15).unwrap().to_string(), "15m");
+        assert_eq!(WindowSize::hours(23).unwrap().to_string(), "23h");
+        assert!(WindowSize::minutes(0).is_err());
+        assert!(WindowSize::minutes(60).is_err());
+        assert!(WindowSize::hours(24).is_err());
+        assert_eq!(
+            WindowSize::days(8).unwrap_err().to_string(),
+            "invalid window size 8d"
+        );
     }
 }
@@ -0,0 +1,348 @@
+#![allow(clippy::wrong_self_convention)]
+
+use crate::http::{
+    pagination::{Paginate, DEFAULT_WEIGHT_LIMIT},
+    request::Request,
+    Credentials,
+};
+use crate::market::historical_trades::HistoricalTrades;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum number of trades returned by a single trades request.
+pub const MAX_TRADES_LIMIT: u32 = 1000;
+
+/// Paginated market trade history.
+///
+/// Walks `GET /api/v3/historicalTrades` by trade id, from `from_id` or the first
+/// trade of the symbol, until `end_time` or the most recent trade.
+///
+/// The history is consumed with `ureq::BinanceHttpClient::paginate` (blocking) or
+/// `hyper::BinanceHttpClient::paginate` (async).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::market;
+///
+/// let history = market::historical_trade_history("BNBUSDT")
+///     .from_id(28457)
+///     .end_time(1640995200000);
+/// ```
+pub struct HistoricalTradeHistory {
+    symbol: String,
+    cursor: TradeCursor,
+    weight_limit: Option<u32>,
+    credentials: Option<Credentials>,
+}
+
+impl HistoricalTradeHistory {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            cursor: TradeCursor::new(0),
+            weight_limit: Some(DEFAULT_WEIGHT_LIMIT),
+            credentials: None,
+        }
+    }
+
+    pub fn from_id(mut self, from_id: u64) -> Self {
+        self.cursor.from_id = Some(from_id);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.cursor.end_time = Some(end_time);
+        self
+    }
+
+    /// Number of trades per page, capped to 1000.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.cursor.limit = limit.clamp(1, MAX_TRADES_LIMIT);
+        self
+    }
+
+    /// Request weight per minute not to exceed, `None` to never wait between pages.
+    pub fn weight_limit(mut self, weight_limit: Option<u32>) -> Self {
+        self.weight_limit = weight_limit;
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+
+    /// Id of the last trade emitted.
+    pub fn last_id(&self) -> Option<u64> {
+        self.cursor.last_id()
+    }
+}
+
+impl Paginate for HistoricalTradeHistory {
+    type Page = Vec<Trade>;
+    type Item = Trade;
+
+    fn next_request(&self) -> Option<Request> {
+        let from_id = match self.cursor.next_page()? {
+            TradePage::Id(from_id) => from_id,
+            TradePage::Time(..) => unreachable!("Historical trades are only paged by id"),
+        };
+
+        let mut request = HistoricalTrades::new(&self.symbol)
+            .from_id(from_id)
+            .limit(self.cursor.limit);
+        if let Some(credentials) = &self.credentials {
+            request = request.credentials(credentials);
+        }
+
+        Some(request.into())
+    }
+
+    fn handle_page(&mut self, trades: Vec<Trade>) -> Vec<Trade> {
+        self.cursor.handle_page(trades)
+    }
+
+    fn page_weight(&self) -> u32 {
+        25
+    }
+
+    fn max_weight(&self) -> Option<u32> {
+        self.weight_limit
+    }
+}
+
+/// Trade returned by `GET /api/v3/trades` and `GET /api/v3/historicalTrades`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub quote_qty: Decimal,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+impl TradeRow for Trade {
+    fn id(&self) -> u64 {
+        self.id
+    }
+
+    fn time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// Trade identified by an id increasing with time.
+pub(crate) trait TradeRow {
+    fn id(&self) -> u64;
+    fn time(&self) -> u64;
+}
+
+/// Page of a [`TradeCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TradePage {
+    /// Trades between `startTime` and `endTime`.
+    Time(u64, u64),
+    /// Trades from `fromId`.
+    Id(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TradeCursorState {
+    Time(u64),
+    Id(u64),
+    Done,
+}
+
+/// Cursor shared by the trade histories.
+///
+/// Trades are paged by id. When only a `start_time` is known, windows of
+/// `max_window` milliseconds are requested until a first trade is found,
+/// then paging continues from its id.
+pub(crate) struct TradeCursor {
+    pub(crate) from_id: Option<u64>,
+    pub(crate) start_time: Option<u64>,
+    pub(crate) end_time: Option<u64>,
+    pub(crate) limit: u32,
+    max_window: u64,
+    last_id: Option<u64>,
+    state: Option<TradeCursorState>,
+}
+
+impl TradeCursor {
+    pub(crate) fn new(max_window: u64) -> Self {
+        Self {
+            from_id: None,
+            start_time: None,
+            end_time: None,
+            limit: MAX_TRADES_LIMIT,
+            max_window,
+            last_id: None,
+            state: None,
+        }
+    }
+
+    pub(crate) fn last_id(&self) -> Option<u64> {
+        self.last_id
+    }
+
+    fn state(&self) -> TradeCursorState {
+        match (self.state, self.from_id, self.start_time) {
+            (Some(state), _, _) => state,
+            (None, Some(from_id), _) => TradeCursorState::Id(from_id),
+            (None, None, Some(start_time)) if self.max_window > 0 => {
+                TradeCursorState::Time(start_time)
+            }
+            _ => TradeCursorState::Id(0),
+        }
+    }
+
+    pub(crate) fn next_page(&self) -> Option<TradePage> {
+        match self.state() {
+            TradeCursorState::Time(start_time) => {
+                let mut end_time = start_time + self.max_window - 1;
+                if let Some(max_end_time) = self.end_time {
+                    end_time = end_time.min(max_end_time);
+                }
+
+                Some(TradePage::Time(start_time, end_time))
+            }
+            TradeCursorState::Id(from_id) => Some(TradePage::Id(from_id)),
+            TradeCursorState::Done => None,
+        }
+    }
+
+    /// Consume a page of trades, returning the trades not seen yet up to `end_time`.
+    pub(crate) fn handle_page<T: TradeRow>(&mut self, trades: Vec<T>) -> Vec<T> {
+        let page = match self.next_page() {
+            Some(page) => page,
+            None => return vec![],
+        };
+        let is_full_page = trades.len() >= self.limit as usize;
+
+        let mut items = vec![];
+        for trade in trades {
+            if self.last_id.map(|id| trade.id() <= id).unwrap_or(false) {
+                continue;
+            }
+
+            if self.end_time.map(|end| trade.time() > end).unwrap_or(false) {
+                self.state = Some(TradeCursorState::Done);
+                return items;
+            }
+
+            self.last_id = Some(trade.id());
+            items.push(trade);
+        }
+
+        let state = match (page, self.last_id) {
+            (TradePage::Time(_, end_time), None) => {
+                // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+                let max_end_time = self.end_time.unwrap_or_else(|| {
+                    SystemTime::now()
+                        .duration_since(UNIX_EPOCH)
+                        .expect("Clock may have gone backwards")
+                        .as_millis() as u64
+                });
+
+                if end_time < max_end_time {
+                    TradeCursorState::Time(end_time + 1)
+                } else {
+                    TradeCursorState::Done
+                }
+            }
+            (TradePage::Time(..), Some(last_id)) => TradeCursorState::Id(last_id + 1),
+            (TradePage::Id(_), Some(last_id)) if is_full_page => TradeCursorState::Id(last_id + 1),
+            (TradePage::Id(_), _) => TradeCursorState::Done,
+        };
+        self.state = Some(state);
+
+        items
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{HistoricalTradeHistory, Trade, TradeCursor, TradePage};
+    use crate::http::{pagination::Paginate, request::Request, Method};
+    use rust_decimal_macros::dec;
+
+    fn trade(id: u64, time: u64) -> Trade {
+        Trade {
+            id,
+            price: dec!(4.000001),
+            qty: dec!(12),
+            quote_qty: dec!(48.000012),
+            time,
+            is_buyer_maker: true,
+            is_best_match: true,
+        }
+    }
+
+    #[test]
+    fn market_trade_history_cursor_by_time_test() {
+        let mut cursor = TradeCursor::new(3_600_000);
+        cursor.start_time = Some(0);
+        cursor.end_time = Some(10_000_000);
+        cursor.limit = 2;
+
+        assert_eq!(cursor.next_page(), Some(TradePage::Time(0, 3_599_999)));
+        assert_eq!(cursor.handle_page::<Trade>(vec![]), vec![]);
+
+        assert_eq!(
+            cursor.next_page(),
+            Some(TradePage::Time(3_600_000, 7_199_999))
+        );
+        let trades = cursor.handle_page(vec![trade(10, 5_000_000), trade(11, 6_000_000)]);
+        assert_eq!(trades.len(), 2);
+
+        assert_eq!(cursor.next_page(), Some(TradePage::Id(12)));
+        let trades = cursor.handle_page(vec![trade(12, 8_000_000), trade(13, 11_000_000)]);
+        assert_eq!(trades, vec![trade(12, 8_000_000)]);
+
+        assert_eq!(cursor.next_page(), None);
+        assert_eq!(cursor.last_id(), Some(12));
+    }
+
+    #[test]
+    fn market_trade_history_cursor_by_id_test() {
+        let mut cursor = TradeCursor::new(3_600_000);
+        cursor.from_id = Some(10);
+        cursor.start_time = Some(0);
+        cursor.limit = 2;
+
+        assert_eq!(cursor.next_page(), Some(TradePage::Id(10)));
+        cursor.handle_page(vec![trade(10, 0), trade(11, 0)]);
+        assert_eq!(cursor.next_page(), Some(TradePage::Id(12)));
+        cursor.handle_page(vec![trade(12, 0)]);
+        assert_eq!(cursor.next_page(), None);
+    }
+
+    #[test]
+    fn market_historical_trade_history_next_request_test() {
+        let mut history = HistoricalTradeHistory::new("BNBUSDT")
+            .from_id(28457)
+            .limit(2);
+
+        history.handle_page(vec![trade(28457, 0), trade(28458, 0)]);
+
+        assert_eq!(
+            history.next_request(),
+            Some(Request {
+                path: "/api/v3/historicalTrades".to_owned(),
+                credentials: None,
+                method: Method::Get,
+                params: vec![
+                    ("symbol".to_owned(), "BNBUSDT".to_string()),
+                    ("limit".to_owned(), "2".to_string()),
+                    ("fromId".to_owned(), "28459".to_string()),
+                ],
+                sign: false
+            })
+        );
+    }
+}
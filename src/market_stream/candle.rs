@@ -0,0 +1,453 @@
+use crate::market::{agg_trade_history::AggTrade, kline_history::Kline, trade_history::Trade};
+use crate::market_stream::event::{AggTradeEvent, TradeEvent};
+use rust_decimal::Decimal;
+use std::error::Error;
+use std::fmt;
+
+/// Size of the candles built by a [`CandleBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CandleSize {
+    /// Candles lasting a number of milliseconds, aligned on the UNIX epoch like klines.
+    Time(u64),
+    /// Candles closed once their base asset volume reaches the threshold.
+    Volume(Decimal),
+    /// Candles closed once their quote asset volume reaches the threshold (dollar bars).
+    QuoteVolume(Decimal),
+}
+
+/// Candle size of zero, or a negative volume threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidCandleSize(pub CandleSize);
+
+impl fmt::Display for InvalidCandleSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            CandleSize::Time(interval) => write!(f, "invalid candle interval {}ms", interval),
+            CandleSize::Volume(threshold) | CandleSize::QuoteVolume(threshold) => {
+                write!(f, "invalid candle volume threshold {}", threshold)
+            }
+        }
+    }
+}
+
+impl Error for InvalidCandleSize {}
+
+/// Trade aggregated by a [`CandleBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandleTrade {
+    pub time: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub is_buyer_maker: bool,
+    /// Number of trades, more than 1 for aggregate trades.
+    pub count: u64,
+}
+
+impl From<&TradeEvent> for CandleTrade {
+    fn from(event: &TradeEvent) -> CandleTrade {
+        CandleTrade {
+            time: event.trade_time,
+            price: event.price,
+            qty: event.qty,
+            is_buyer_maker: event.is_buyer_maker,
+            count: 1,
+        }
+    }
+}
+
+impl From<&AggTradeEvent> for CandleTrade {
+    fn from(event: &AggTradeEvent) -> CandleTrade {
+        CandleTrade {
+            time: event.trade_time,
+            price: event.price,
+            qty: event.qty,
+            is_buyer_maker: event.is_buyer_maker,
+            count: event.last_trade_id - event.first_trade_id + 1,
+        }
+    }
+}
+
+impl From<&Trade> for CandleTrade {
+    fn from(trade: &Trade) -> CandleTrade {
+        CandleTrade {
+            time: trade.time,
+            price: trade.price,
+            qty: trade.qty,
+            is_buyer_maker: trade.is_buyer_maker,
+            count: 1,
+        }
+    }
+}
+
+impl From<&AggTrade> for CandleTrade {
+    fn from(trade: &AggTrade) -> CandleTrade {
+        CandleTrade {
+            time: trade.time,
+            price: trade.price,
+            qty: trade.qty,
+            is_buyer_maker: trade.is_buyer_maker,
+            count: trade.last_trade_id - trade.first_trade_id + 1,
+        }
+    }
+}
+
+impl From<CandleTrade> for Kline {
+    fn from(trade: CandleTrade) -> Kline {
+        let quote_qty = trade.price * trade.qty;
+        let (taker_buy_qty, taker_buy_quote_qty) = if trade.is_buyer_maker {
+            (Decimal::ZERO, Decimal::ZERO)
+        } else {
+            (trade.qty, quote_qty)
+        };
+
+        Kline {
+            open_time: trade.time,
+            open: trade.price,
+            high: trade.price,
+            low: trade.price,
+            close: trade.price,
+            volume: trade.qty,
+            close_time: trade.time,
+            quote_asset_volume: quote_qty,
+            number_of_trades: trade.count,
+            taker_buy_base_asset_volume: taker_buy_qty,
+            taker_buy_quote_asset_volume: taker_buy_quote_qty,
+        }
+    }
+}
+
+/// Candle aggregator over trades or finer klines.
+///
+/// Builds candles of any duration, e.g. `10s` or `7m`, as well as volume and
+/// quote volume (dollar) bars from [`TradeEvent`], [`AggTradeEvent`], REST trades
+/// or closed klines of a smaller interval.
+///
+/// * Time candles are closed by the first trade of a later candle, or by
+///   [`CandleBuilder::close_until`] when no trade is received.
+/// * Like klines, time candles without trades are emitted with the previous
+///   close price and no volume, unless disabled with [`CandleBuilder::fill_empty`].
+/// * Trades older than the current time candle are dropped.
+/// * Volume candles are closed by the trade reaching the threshold, which is
+///   not split between candles.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::market_stream::candle::{CandleBuilder, CandleSize, CandleTrade};
+/// use rust_decimal_macros::dec;
+///
+/// let mut builder = CandleBuilder::new(CandleSize::Time(10_000)).unwrap();
+///
+/// let trade = CandleTrade { time: 1_000, price: dec!(1.5), qty: dec!(2), is_buyer_maker: false, count: 1 };
+/// assert!(builder.push_trade(trade).is_empty());
+///
+/// let closed = builder.close_until(10_000);
+/// assert_eq!(closed[0].close, dec!(1.5));
+/// ```
+pub struct CandleBuilder {
+    size: CandleSize,
+    fill_empty: bool,
+    current: Option<Kline>,
+    next_open_time: Option<u64>,
+    last_close: Option<Decimal>,
+}
+
+impl CandleBuilder {
+    /// The interval or threshold of `size` must be greater than zero.
+    pub fn new(size: CandleSize) -> Result<Self, InvalidCandleSize> {
+        let is_valid = match size {
+            CandleSize::Time(interval) => interval > 0,
+            CandleSize::Volume(threshold) | CandleSize::QuoteVolume(threshold) => {
+                threshold > Decimal::ZERO
+            }
+        };
+        if !is_valid {
+            return Err(InvalidCandleSize(size));
+        }
+
+        Ok(Self {
+            size,
+            fill_empty: true,
+            current: None,
+            next_open_time: None,
+            last_close: None,
+        })
+    }
+
+    /// Emit time candles without trades, `true` by default.
+    pub fn fill_empty(mut self, fill_empty: bool) -> Self {
+        self.fill_empty = fill_empty;
+        self
+    }
+
+    /// Candle in progress.
+    pub fn current(&self) -> Option<&Kline> {
+        self.current.as_ref()
+    }
+
+    /// Add a trade, returning the candles it closed.
+    pub fn push_trade(&mut self, trade: impl Into<CandleTrade>) -> Vec<Kline> {
+        self.push(trade.into().into())
+    }
+
+    /// Add a closed kline, returning the candles it closed.
+    ///
+    /// For time candles, the kline interval must divide the candle duration.
+    pub fn push_kline(&mut self, kline: &Kline) -> Vec<Kline> {
+        self.push(kline.clone())
+    }
+
+    /// Close the time candles ending at or before `time`, e.g. on a timer
+    /// when no trade is received.
+    pub fn close_until(&mut self, time: u64) -> Vec<Kline> {
+        let interval = match self.size {
+            CandleSize::Time(interval) => interval,
+            _ => return vec![],
+        };
+
+        let mut closed = vec![];
+        if let Some(candle) = &self.current {
+            if candle.open_time + interval > time {
+                return closed;
+            }
+            if let Some(candle) = self.close_current() {
+                closed.push(candle);
+            }
+        }
+
+        if let (true, Some(mut open_time), Some(close)) =
+            (self.fill_empty, self.next_open_time, self.last_close)
+        {
+            while open_time + interval <= time {
+                closed.push(empty_candle(open_time, interval, close));
+                open_time += interval;
+            }
+            self.next_open_time = Some(open_time);
+        }
+
+        closed
+    }
+
+    /// Close the candle in progress, e.g. at the end of a replay.
+    pub fn flush(&mut self) -> Option<Kline> {
+        self.close_current()
+    }
+
+    fn close_current(&mut self) -> Option<Kline> {
+        let candle = self.current.take()?;
+        self.last_close = Some(candle.close);
+        if let CandleSize::Time(_) = self.size {
+            self.next_open_time = Some(candle.close_time + 1);
+        }
+
+        Some(candle)
+    }
+
+    fn push(&mut self, part: Kline) -> Vec<Kline> {
+        match self.size {
+            CandleSize::Time(interval) => self.push_time(part, interval),
+            CandleSize::Volume(threshold) => {
+                self.push_volume(part, |candle| candle.volume >= threshold)
+            }
+            CandleSize::QuoteVolume(threshold) => {
+                self.push_volume(part, |candle| candle.quote_asset_volume >= threshold)
+            }
+        }
+    }
+
+    fn push_time(&mut self, part: Kline, interval: u64) -> Vec<Kline> {
+        let open_time = part.open_time - part.open_time % interval;
+
+        let current_open_time = self.current.as_ref().map(|candle| candle.open_time);
+        if current_open_time.or(self.next_open_time).unwrap_or(0) > open_time {
+            log::warn!(
+                "Dropped trade at {} before the current candle",
+                part.open_time
+            );
+            return vec![];
+        }
+
+        let closed = self.close_until(open_time);
+        match &mut self.current {
+            Some(candle) => merge(candle, &part),
+            None => {
+                self.current = Some(Kline {
+                    open_time,
+                    close_time: open_time + interval - 1,
+                    ..part
+                })
+            }
+        }
+
+        closed
+    }
+
+    fn push_volume(&mut self, part: Kline, is_full: impl Fn(&Kline) -> bool) -> Vec<Kline> {
+        let candle = match &mut self.current {
+            Some(candle) => {
+                merge(candle, &part);
+                candle.close_time = part.close_time;
+                candle
+            }
+            None => self.current.insert(part),
+        };
+
+        if is_full(candle) {
+            self.close_current().into_iter().collect()
+        } else {
+            vec![]
+        }
+    }
+}
+
+fn merge(candle: &mut Kline, part: &Kline) {
+    candle.high = candle.high.max(part.high);
+    candle.low = candle.low.min(part.low);
+    candle.close = part.close;
+    candle.volume += part.volume;
+    candle.quote_asset_volume += part.quote_asset_volume;
+    candle.number_of_trades += part.number_of_trades;
+    candle.taker_buy_base_asset_volume += part.taker_buy_base_asset_volume;
+    candle.taker_buy_quote_asset_volume += part.taker_buy_quote_asset_volume;
+}
+
+fn empty_candle(open_time: u64, interval: u64, close: Decimal) -> Kline {
+    Kline {
+        open_time,
+        open: close,
+        high: close,
+        low: close,
+        close,
+        volume: Decimal::ZERO,
+        close_time: open_time + interval - 1,
+        quote_asset_volume: Decimal::ZERO,
+        number_of_trades: 0,
+        taker_buy_base_asset_volume: Decimal::ZERO,
+        taker_buy_quote_asset_volume: Decimal::ZERO,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CandleBuilder, CandleSize, CandleTrade, InvalidCandleSize};
+    use crate::market_stream::event::AggTradeEvent;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    fn trade(time: u64, price: Decimal, qty: Decimal) -> CandleTrade {
+        CandleTrade {
+            time,
+            price,
+            qty,
+            is_buyer_maker: false,
+            count: 1,
+        }
+    }
+
+    #[test]
+    fn market_stream_candle_time_test() {
+        let mut builder = CandleBuilder::new(CandleSize::Time(10_000)).unwrap();
+
+        assert!(builder
+            .push_trade(trade(1_000, dec!(2), dec!(1)))
+            .is_empty());
+        assert!(builder
+            .push_trade(trade(9_999, dec!(3), dec!(1)))
+            .is_empty());
+
+        let closed = builder.push_trade(trade(35_000, dec!(1), dec!(1)));
+        assert_eq!(closed.len(), 3);
+        assert_eq!(closed[0].open_time, 0);
+        assert_eq!(closed[0].close_time, 9_999);
+        assert_eq!(closed[0].open, dec!(2));
+        assert_eq!(closed[0].high, dec!(3));
+        assert_eq!(closed[0].close, dec!(3));
+        assert_eq!(closed[0].volume, dec!(2));
+        assert_eq!(closed[0].number_of_trades, 2);
+        assert_eq!(closed[1].open_time, 10_000);
+        assert_eq!(closed[1].open, dec!(3));
+        assert_eq!(closed[1].volume, dec!(0));
+        assert_eq!(closed[2].open_time, 20_000);
+        assert_eq!(builder.current().unwrap().open_time, 30_000);
+
+        // Trade of a closed candle is dropped
+        assert!(builder
+            .push_trade(trade(25_000, dec!(1), dec!(1)))
+            .is_empty());
+        assert_eq!(builder.current().unwrap().volume, dec!(1));
+
+        let closed = builder.close_until(50_000);
+        assert_eq!(closed.len(), 2);
+        assert_eq!(closed[1].open_time, 40_000);
+        assert_eq!(closed[1].close, dec!(1));
+        assert!(builder.current().is_none());
+    }
+
+    #[test]
+    fn market_stream_candle_time_without_empty_test() {
+        let mut builder = CandleBuilder::new(CandleSize::Time(10_000))
+            .unwrap()
+            .fill_empty(false);
+
+        builder.push_trade(trade(1_000, dec!(2), dec!(1)));
+        let closed = builder.push_trade(trade(35_000, dec!(1), dec!(1)));
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, 0);
+    }
+
+    #[test]
+    fn market_stream_candle_kline_test() {
+        let mut minutes = CandleBuilder::new(CandleSize::Time(60_000)).unwrap();
+        let mut builder = CandleBuilder::new(CandleSize::Time(180_000)).unwrap();
+
+        let mut klines = vec![];
+        for (time, price) in [(0, dec!(1)), (70_000, dec!(4)), (130_000, dec!(2))] {
+            klines.extend(minutes.push_trade(trade(time, price, dec!(1))));
+        }
+        klines.extend(minutes.flush());
+        assert_eq!(klines.len(), 3);
+
+        let mut closed = vec![];
+        for kline in &klines {
+            closed.extend(builder.push_kline(kline));
+        }
+        closed.extend(builder.flush());
+
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, 0);
+        assert_eq!(closed[0].close_time, 179_999);
+        assert_eq!(closed[0].high, dec!(4));
+        assert_eq!(closed[0].close, dec!(2));
+        assert_eq!(closed[0].volume, dec!(3));
+    }
+
+    #[test]
+    fn market_stream_candle_volume_test() {
+        let mut builder = CandleBuilder::new(CandleSize::QuoteVolume(dec!(100))).unwrap();
+
+        let data = r#"{"e":"aggTrade","E":1672515782136,"s":"BNBBTC","a":12345,"p":"10","q":"6","f":100,"l":105,"T":1672515782136,"m":true,"M":true}"#;
+        let event: AggTradeEvent = serde_json::from_str(data).unwrap();
+
+        assert!(builder.push_trade(&event).is_empty());
+        let closed = builder.push_trade(trade(1672515782200, dec!(10), dec!(5)));
+
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].open_time, 1672515782136);
+        assert_eq!(closed[0].close_time, 1672515782200);
+        assert_eq!(closed[0].quote_asset_volume, dec!(110));
+        assert_eq!(closed[0].taker_buy_base_asset_volume, dec!(5));
+        assert_eq!(closed[0].number_of_trades, 7);
+        assert!(builder.current().is_none());
+
+        for size in [
+            CandleSize::Time(0),
+            CandleSize::Volume(Decimal::ZERO),
+            CandleSize::QuoteVolume(dec!(-1)),
+        ] {
+            assert_eq!(
+                CandleBuilder::new(size).err(),
+                Some(InvalidCandleSize(size))
+            );
+        }
+    }
+}
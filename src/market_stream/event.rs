@@ -0,0 +1,430 @@
+use crate::market::kline_history::Kline;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// Raw trade pushed on a `<symbol>@trade` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#trade-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "q")]
+    pub qty: Decimal,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// Aggregate trade pushed on a `<symbol>@aggTrade` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#aggregate-trade-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AggTradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "a")]
+    pub agg_trade_id: u64,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "q")]
+    pub qty: Decimal,
+    #[serde(rename = "f")]
+    pub first_trade_id: u64,
+    #[serde(rename = "l")]
+    pub last_trade_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    #[serde(rename = "m")]
+    pub is_buyer_maker: bool,
+}
+
+/// Kline update pushed on a `<symbol>@kline_<interval>` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#klinecandlestick-streams-for-utc)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct KlineEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "k")]
+    pub kline: KlineData,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct KlineData {
+    #[serde(rename = "t")]
+    pub open_time: u64,
+    #[serde(rename = "T")]
+    pub close_time: u64,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "f")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "o")]
+    pub open: Decimal,
+    #[serde(rename = "c")]
+    pub close: Decimal,
+    #[serde(rename = "h")]
+    pub high: Decimal,
+    #[serde(rename = "l")]
+    pub low: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+    /// Whether the kline is closed, otherwise it is still updated.
+    #[serde(rename = "x")]
+    pub is_closed: bool,
+    #[serde(rename = "q")]
+    pub quote_asset_volume: Decimal,
+    #[serde(rename = "V")]
+    pub taker_buy_base_asset_volume: Decimal,
+    #[serde(rename = "Q")]
+    pub taker_buy_quote_asset_volume: Decimal,
+}
+
+impl From<&KlineData> for Kline {
+    fn from(data: &KlineData) -> Kline {
+        Kline {
+            open_time: data.open_time,
+            open: data.open,
+            high: data.high,
+            low: data.low,
+            close: data.close,
+            volume: data.volume,
+            close_time: data.close_time,
+            quote_asset_volume: data.quote_asset_volume,
+            number_of_trades: data.number_of_trades,
+            taker_buy_base_asset_volume: data.taker_buy_base_asset_volume,
+            taker_buy_quote_asset_volume: data.taker_buy_quote_asset_volume,
+        }
+    }
+}
+
+/// 24hr rolling window mini ticker pushed on a `<symbol>@miniTicker` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#individual-symbol-mini-ticker-stream)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MiniTickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub close_price: Decimal,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+}
+
+/// 24hr rolling window ticker pushed on a `<symbol>@ticker` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#individual-symbol-ticker-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub price_change: Decimal,
+    #[serde(rename = "P")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "w")]
+    pub weighted_avg_price: Decimal,
+    #[serde(rename = "x")]
+    pub first_trade_price: Decimal,
+    #[serde(rename = "c")]
+    pub last_price: Decimal,
+    #[serde(rename = "Q")]
+    pub last_qty: Decimal,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_qty: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_qty: Decimal,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "O")]
+    pub open_time: u64,
+    #[serde(rename = "C")]
+    pub close_time: u64,
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+}
+
+/// Rolling window ticker pushed on a `<symbol>@ticker_<window_size>` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#individual-symbol-rolling-window-statistics-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RollingWindowTickerEvent {
+    /// `1hTicker`, `4hTicker` or `1dTicker`.
+    #[serde(rename = "e")]
+    pub event_type: String,
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p")]
+    pub price_change: Decimal,
+    #[serde(rename = "P")]
+    pub price_change_percent: Decimal,
+    #[serde(rename = "o")]
+    pub open_price: Decimal,
+    #[serde(rename = "h")]
+    pub high_price: Decimal,
+    #[serde(rename = "l")]
+    pub low_price: Decimal,
+    #[serde(rename = "c")]
+    pub last_price: Decimal,
+    #[serde(rename = "w")]
+    pub weighted_avg_price: Decimal,
+    #[serde(rename = "v")]
+    pub volume: Decimal,
+    #[serde(rename = "q")]
+    pub quote_volume: Decimal,
+    #[serde(rename = "O")]
+    pub open_time: u64,
+    #[serde(rename = "C")]
+    pub close_time: u64,
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+}
+
+/// Average price pushed on a `<symbol>@avgPrice` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#average-price)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AvgPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub interval: String,
+    #[serde(rename = "w")]
+    pub price: Decimal,
+    #[serde(rename = "T")]
+    pub last_trade_time: u64,
+}
+
+/// Best bid or ask update pushed on a `<symbol>@bookTicker` stream.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#individual-symbol-book-ticker-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BookTickerEvent {
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_qty: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_qty: Decimal,
+}
+
+/// Order book update pushed on a `<symbol>@depth` stream.
+///
+/// Price levels are `(price, qty)`, a zero quantity removes the level.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#diff-depth-stream)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct DepthUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub final_update_id: u64,
+    #[serde(rename = "b")]
+    pub bids: Vec<(Decimal, Decimal)>,
+    #[serde(rename = "a")]
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+/// Top order book levels pushed on a `<symbol>@depth<levels>` stream.
+///
+/// Price levels are `(price, qty)`.
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#partial-book-depth-streams)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PartialDepthEvent {
+    pub last_update_id: u64,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
+}
+
+/// Market stream event.
+///
+/// Decoded from the payload of a stream with [`MarketEvent::from_value`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarketEvent {
+    Trade(TradeEvent),
+    AggTrade(AggTradeEvent),
+    Kline(KlineEvent),
+    MiniTicker(MiniTickerEvent),
+    Ticker(Box<TickerEvent>),
+    RollingWindowTicker(Box<RollingWindowTickerEvent>),
+    AvgPrice(AvgPriceEvent),
+    BookTicker(BookTickerEvent),
+    DepthUpdate(DepthUpdateEvent),
+    PartialDepth(PartialDepthEvent),
+    /// `!miniTicker@arr` stream.
+    MiniTickers(Vec<MiniTickerEvent>),
+    /// `!ticker@arr` stream.
+    Tickers(Vec<TickerEvent>),
+    /// `!ticker_<window_size>@arr` stream.
+    RollingWindowTickers(Vec<RollingWindowTickerEvent>),
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl MarketEvent {
+    /// Decode the raw payload of a market stream.
+    ///
+    /// Returns `None` if the payload is not a market event, e.g. a user data
+    /// event, and an error if it is a market event that could not be decoded.
+    pub fn from_value(data: &serde_json::Value) -> Result<Option<Self>, serde_json::Error> {
+        use serde_json::Value;
+
+        let event = match data {
+            Value::Array(items) => {
+                match items
+                    .first()
+                    .and_then(|item| item.get("e"))
+                    .and_then(Value::as_str)
+                {
+                    Some("24hrMiniTicker") => {
+                        MarketEvent::MiniTickers(Deserialize::deserialize(data)?)
+                    }
+                    Some("24hrTicker") => MarketEvent::Tickers(Deserialize::deserialize(data)?),
+                    Some("1hTicker" | "4hTicker" | "1dTicker") => {
+                        MarketEvent::RollingWindowTickers(Deserialize::deserialize(data)?)
+                    }
+                    _ => return Ok(None),
+                }
+            }
+            Value::Object(map) => match map.get("e").and_then(Value::as_str) {
+                Some("trade") => MarketEvent::Trade(Deserialize::deserialize(data)?),
+                Some("aggTrade") => MarketEvent::AggTrade(Deserialize::deserialize(data)?),
+                Some("kline") => MarketEvent::Kline(Deserialize::deserialize(data)?),
+                Some("24hrMiniTicker") => MarketEvent::MiniTicker(Deserialize::deserialize(data)?),
+                Some("24hrTicker") => MarketEvent::Ticker(Deserialize::deserialize(data)?),
+                Some("1hTicker" | "4hTicker" | "1dTicker") => {
+                    MarketEvent::RollingWindowTicker(Deserialize::deserialize(data)?)
+                }
+                Some("avgPrice") => MarketEvent::AvgPrice(Deserialize::deserialize(data)?),
+                Some("depthUpdate") => MarketEvent::DepthUpdate(Deserialize::deserialize(data)?),
+                Some(_) => return Ok(None),
+                // Book ticker and partial depth payloads have no event type.
+                None if map.contains_key("lastUpdateId") => {
+                    MarketEvent::PartialDepth(Deserialize::deserialize(data)?)
+                }
+                None if map.contains_key("u") && map.contains_key("b") && map.contains_key("a") => {
+                    MarketEvent::BookTicker(Deserialize::deserialize(data)?)
+                }
+                None => return Ok(None),
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+#[cfg(all(
+    test,
+    any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite")
+))]
+mod tests {
+    use super::MarketEvent;
+    use rust_decimal_macros::dec;
+
+    fn decode(data: &str) -> Option<MarketEvent> {
+        MarketEvent::from_value(&serde_json::from_str(data).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn market_stream_decode_event_test() {
+        match decode(
+            r#"{"e":"trade","E":1672515782136,"s":"BNBBTC","t":12345,"p":"0.001","q":"100","T":1672515782136,"m":true,"M":true}"#,
+        ) {
+            Some(MarketEvent::Trade(trade)) => {
+                assert_eq!(trade.trade_id, 12345);
+                assert_eq!(trade.price, dec!(0.001));
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        match decode(
+            r#"{"u":400900217,"s":"BNBUSDT","b":"25.35190000","B":"31.21000000","a":"25.36520000","A":"40.66000000"}"#,
+        ) {
+            Some(MarketEvent::BookTicker(ticker)) => assert_eq!(ticker.best_ask_qty, dec!(40.66)),
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        match decode(r#"{"lastUpdateId":160,"bids":[["0.0024","10"]],"asks":[["0.0026","100"]]}"#) {
+            Some(MarketEvent::PartialDepth(depth)) => {
+                assert_eq!(depth.bids, vec![(dec!(0.0024), dec!(10))]);
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        match decode(
+            r#"[{"e":"1hTicker","E":1672515782136,"s":"BNBBTC","p":"0.0015","P":"250.00","o":"0.0010","h":"0.0025","l":"0.0010","c":"0.0025","w":"0.0018","v":"10000","q":"18","O":0,"C":1675216573749,"F":0,"L":18150,"n":18151}]"#,
+        ) {
+            Some(MarketEvent::RollingWindowTickers(tickers)) => {
+                assert_eq!(tickers[0].event_type, "1hTicker");
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        assert_eq!(decode(r#"{"e":"executionReport","E":1499405658658}"#), None);
+        assert!(MarketEvent::from_value(&serde_json::json!({"e":"trade"})).is_err());
+    }
+}
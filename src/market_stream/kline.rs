@@ -1,13 +1,16 @@
 use crate::market::klines::KlineInterval;
 use crate::websocket::Stream;
 
-/// Kline/Candlestick Streams for UTC
+/// Kline/Candlestick Streams
 ///
 /// The Kline/Candlestick Stream push updates to the current klines/candlestick every second.
 ///
-/// Update Speed: 2000ms
+/// * Kline intervals are in UTC unless a time zone offset is set, e.g. `+08:00`.
+///   Event times are always in UTC.
 ///
-/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#klinecandlestick-streams-for-utc)
+/// Update Speed: 1000ms for `1s`, 2000ms for the other intervals
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#klinecandlestick-streams-with-timezone-offset)
 ///
 /// # Example
 ///
@@ -15,10 +18,12 @@ use crate::websocket::Stream;
 /// use binance_spot_connector_rust::{ market::klines::KlineInterval, market_stream::kline::KlineStream };
 ///
 /// let stream = KlineStream::new("BTCUSDT", KlineInterval::Minutes1);
+/// let stream_with_offset = KlineStream::new("BTCUSDT", KlineInterval::Hours1).time_zone("+08:00");
 /// ```
 pub struct KlineStream {
     symbol: String,
     interval: KlineInterval,
+    time_zone: Option<String>,
 }
 
 impl KlineStream {
@@ -26,13 +31,23 @@ impl KlineStream {
         Self {
             symbol: symbol.to_lowercase(),
             interval,
+            time_zone: None,
         }
     }
+
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.to_owned());
+        self
+    }
 }
 
 impl From<KlineStream> for Stream {
-    /// Returns stream name as `<symbol>@kline_interval`
+    /// Returns stream name as `<symbol>@kline_<interval>` or `<symbol>@kline_<interval>@<time_zone>`
     fn from(stream: KlineStream) -> Stream {
-        Stream::new(&format!("{}@kline_{}", stream.symbol, stream.interval))
+        Stream::new(&if let Some(time_zone) = stream.time_zone {
+            format!("{}@kline_{}@{}", stream.symbol, stream.interval, time_zone)
+        } else {
+            format!("{}@kline_{}", stream.symbol, stream.interval)
+        })
     }
 }
@@ -4,7 +4,9 @@
 pub mod agg_trade;
 pub mod avg_price;
 pub mod book_ticker;
+pub mod candle;
 pub mod diff_depth;
+pub mod event;
 pub mod kline;
 pub mod mini_ticker;
 pub mod partial_depth;
@@ -12,7 +14,7 @@ pub mod rolling_window_ticker;
 pub mod ticker;
 pub mod trade;
 
-use crate::market::klines::KlineInterval;
+use crate::market::{klines::KlineInterval, rolling_window_price_change_statistics::RollingWindow};
 
 use agg_trade::AggTradeStream;
 use avg_price::AvgPriceStream;
@@ -71,12 +73,12 @@ pub fn partial_depth_100ms(symbol: &str, levels: u16) -> PartialDepthStream {
 
 pub fn individual_symbol_rolling_window_ticker(
     symbol: &str,
-    window: &str,
+    window: RollingWindow,
 ) -> RollingWindowTickerStream {
     RollingWindowTickerStream::from_symbol(symbol, window)
 }
 
-pub fn all_market_rolling_window_ticker(window: &str) -> RollingWindowTickerStream {
+pub fn all_market_rolling_window_ticker(window: RollingWindow) -> RollingWindowTickerStream {
     RollingWindowTickerStream::all_symbols(window)
 }
 
@@ -1,3 +1,4 @@
+use crate::market::rolling_window_price_change_statistics::RollingWindow;
 use crate::websocket::Stream;
 
 /// Rolling Window Statistics Stream
@@ -13,27 +14,30 @@ use crate::websocket::Stream;
 /// # Example
 ///
 /// ```
-/// use binance_spot_connector_rust::market_stream::rolling_window_ticker::RollingWindowTickerStream;
+/// use binance_spot_connector_rust::{
+///     market::rolling_window_price_change_statistics::RollingWindow,
+///     market_stream::rolling_window_ticker::RollingWindowTickerStream,
+/// };
 ///
-/// let individual_symbol_stream = RollingWindowTickerStream::from_symbol("BTCUSDT", "4h");
-/// let all_symbols_stream = RollingWindowTickerStream::all_symbols("4h");
+/// let individual_symbol_stream = RollingWindowTickerStream::from_symbol("BTCUSDT", RollingWindow::Hours4);
+/// let all_symbols_stream = RollingWindowTickerStream::all_symbols(RollingWindow::Hours4);
 /// ```
 pub struct RollingWindowTickerStream {
-    window: String,
+    window: RollingWindow,
     symbol: Option<String>,
 }
 
 impl RollingWindowTickerStream {
-    pub fn all_symbols(window: &str) -> Self {
+    pub fn all_symbols(window: RollingWindow) -> Self {
         Self {
             symbol: None,
-            window: window.to_string(),
+            window,
         }
     }
 
-    pub fn from_symbol(window: &str, symbol: &str) -> Self {
+    pub fn from_symbol(symbol: &str, window: RollingWindow) -> Self {
         Self {
-            window: window.to_lowercase(),
+            window,
             symbol: Some(symbol.to_lowercase()),
         }
     }
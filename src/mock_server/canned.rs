@@ -0,0 +1,292 @@
+//! Default responses of the market data and account endpoints, served when
+//! no [`Mock`](super::Mock) matches the request.
+//!
+//! The market is static: `BTCUSDT`, `ETHUSDT` and `BNBUSDT` trade at a fixed
+//! price, with a flat order book and flat klines around it.
+
+use crate::http::Method;
+use crate::mock_server::rest::{missing_param, now, param};
+use crate::mock_server::MockResponse;
+use serde_json::{json, Value};
+
+/// Symbols of the canned market, with their base asset, quote asset and price.
+const SYMBOLS: &[(&str, &str, &str, &str)] = &[
+    ("BTCUSDT", "BTC", "USDT", "60000.00"),
+    ("ETHUSDT", "ETH", "USDT", "3000.00"),
+    ("BNBUSDT", "BNB", "USDT", "600.00"),
+];
+
+/// Price step of the order book levels.
+const TICK: &str = "0.01";
+
+/// Response of the canned endpoint, `None` if the endpoint has none.
+pub(super) fn respond(
+    method: &Method,
+    path: &str,
+    params: &[(String, String)],
+) -> Option<MockResponse> {
+    if *method != Method::Get {
+        return None;
+    }
+
+    let response = match path {
+        "/api/v3/exchangeInfo" => symbols(params).map(|symbols| {
+            json!({
+                "timezone": "UTC",
+                "serverTime": now(),
+                "rateLimits": [
+                    {"rateLimitType": "REQUEST_WEIGHT", "interval": "MINUTE", "intervalNum": 1, "limit": 6000},
+                    {"rateLimitType": "ORDERS", "interval": "SECOND", "intervalNum": 10, "limit": 100},
+                    {"rateLimitType": "ORDERS", "interval": "DAY", "intervalNum": 1, "limit": 200000},
+                ],
+                "exchangeFilters": [],
+                "symbols": symbols.iter().map(symbol_info).collect::<Vec<Value>>(),
+            })
+        }),
+        "/api/v3/depth" => symbol(params).map(|(_, _, _, price)| {
+            let levels = param(params, "limit")
+                .and_then(|limit| limit.parse::<usize>().ok())
+                .unwrap_or(100)
+                .min(5000);
+            let level = |offset: i64| json!([offset_price(price, offset), "1.00000000"]);
+            json!({
+                "lastUpdateId": 1,
+                "bids": (1..=levels as i64).map(|i| level(-i)).collect::<Vec<Value>>(),
+                "asks": (1..=levels as i64).map(level).collect::<Vec<Value>>(),
+            })
+        }),
+        "/api/v3/klines" | "/api/v3/uiKlines" => return Some(klines(params)),
+        "/api/v3/avgPrice" => symbol(params).map(|(_, _, _, price)| {
+            json!({"mins": 5, "price": price, "closeTime": now()})
+        }),
+        "/api/v3/ticker/price" => tickers(params, |(symbol, _, _, price)| {
+            json!({"symbol": symbol, "price": price})
+        }),
+        "/api/v3/ticker/bookTicker" => tickers(params, |(symbol, _, _, price)| {
+            json!({
+                "symbol": symbol,
+                "bidPrice": offset_price(price, -1),
+                "bidQty": "1.00000000",
+                "askPrice": offset_price(price, 1),
+                "askQty": "1.00000000",
+            })
+        }),
+        "/api/v3/ticker" | "/api/v3/ticker/24hr" | "/api/v3/ticker/tradingDay" => {
+            tickers(params, |(symbol, _, _, price)| {
+                let close_time = now();
+                json!({
+                    "symbol": symbol,
+                    "priceChange": "0.00",
+                    "priceChangePercent": "0.000",
+                    "weightedAvgPrice": price,
+                    "prevClosePrice": price,
+                    "lastPrice": price,
+                    "lastQty": "1.00000000",
+                    "bidPrice": offset_price(price, -1),
+                    "bidQty": "1.00000000",
+                    "askPrice": offset_price(price, 1),
+                    "askQty": "1.00000000",
+                    "openPrice": price,
+                    "highPrice": price,
+                    "lowPrice": price,
+                    "volume": "0.00000000",
+                    "quoteVolume": "0.00000000",
+                    "openTime": close_time - 86_400_000,
+                    "closeTime": close_time,
+                    "firstId": -1,
+                    "lastId": -1,
+                    "count": 0,
+                })
+            })
+        }
+        "/api/v3/account" => Ok(json!({
+            "makerCommission": 10,
+            "takerCommission": 10,
+            "buyerCommission": 0,
+            "sellerCommission": 0,
+            "commissionRates": {"maker": "0.00100000", "taker": "0.00100000", "buyer": "0.00000000", "seller": "0.00000000"},
+            "canTrade": true,
+            "canWithdraw": true,
+            "canDeposit": true,
+            "brokered": false,
+            "requireSelfTradePrevention": false,
+            "preventSor": false,
+            "updateTime": now(),
+            "accountType": "SPOT",
+            "balances": [
+                {"asset": "BTC", "free": "1.00000000", "locked": "0.00000000"},
+                {"asset": "ETH", "free": "10.00000000", "locked": "0.00000000"},
+                {"asset": "BNB", "free": "100.00000000", "locked": "0.00000000"},
+                {"asset": "USDT", "free": "100000.00000000", "locked": "0.00000000"},
+            ],
+            "permissions": ["SPOT"],
+            "uid": 1,
+        })),
+        "/sapi/v1/system/status" => Ok(json!({"status": 0, "msg": "normal"})),
+        _ => return None,
+    };
+
+    Some(match response {
+        Ok(body) => MockResponse::new(200, body.to_string()),
+        Err(response) => response,
+    })
+}
+
+type Symbol = (&'static str, &'static str, &'static str, &'static str);
+
+/// Symbol of the `symbol` parameter.
+fn symbol(params: &[(String, String)]) -> Result<Symbol, MockResponse> {
+    let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+    find(symbol)
+}
+
+/// Symbols of the `symbol` or `symbols` parameter, all symbols without them.
+fn symbols(params: &[(String, String)]) -> Result<Vec<Symbol>, MockResponse> {
+    if let Some(symbol) = param(params, "symbol") {
+        return Ok(vec![find(symbol)?]);
+    }
+    match param(params, "symbols") {
+        Some(symbols) => serde_json::from_str::<Vec<String>>(symbols)
+            .map_err(|_| {
+                MockResponse::error(
+                    400,
+                    -1100,
+                    "Illegal characters found in parameter 'symbols'.",
+                )
+            })?
+            .iter()
+            .map(|symbol| find(symbol))
+            .collect(),
+        None => Ok(SYMBOLS.to_vec()),
+    }
+}
+
+fn find(symbol: &str) -> Result<Symbol, MockResponse> {
+    SYMBOLS
+        .iter()
+        .find(|(name, ..)| *name == symbol)
+        .copied()
+        .ok_or_else(|| MockResponse::error(400, -1121, "Invalid symbol."))
+}
+
+/// Ticker of the `symbol` parameter, or array of the tickers of the `symbols`
+/// parameter or of all symbols.
+fn tickers(
+    params: &[(String, String)],
+    ticker: impl Fn(Symbol) -> Value,
+) -> Result<Value, MockResponse> {
+    if param(params, "symbol").is_some() {
+        return symbol(params).map(ticker);
+    }
+
+    Ok(Value::Array(
+        symbols(params)?.into_iter().map(ticker).collect(),
+    ))
+}
+
+fn symbol_info(&(symbol, base_asset, quote_asset, _): &Symbol) -> Value {
+    json!({
+        "symbol": symbol,
+        "status": "TRADING",
+        "baseAsset": base_asset,
+        "baseAssetPrecision": 8,
+        "quoteAsset": quote_asset,
+        "quotePrecision": 8,
+        "quoteAssetPrecision": 8,
+        "baseCommissionPrecision": 8,
+        "quoteCommissionPrecision": 8,
+        "orderTypes": ["LIMIT", "LIMIT_MAKER", "MARKET", "STOP_LOSS", "STOP_LOSS_LIMIT", "TAKE_PROFIT", "TAKE_PROFIT_LIMIT"],
+        "icebergAllowed": true,
+        "ocoAllowed": true,
+        "otoAllowed": true,
+        "quoteOrderQtyMarketAllowed": true,
+        "allowTrailingStop": true,
+        "cancelReplaceAllowed": true,
+        "isSpotTradingAllowed": true,
+        "isMarginTradingAllowed": false,
+        "filters": [
+            {"filterType": "PRICE_FILTER", "minPrice": TICK, "maxPrice": "1000000.00", "tickSize": TICK},
+            {"filterType": "LOT_SIZE", "minQty": "0.00001000", "maxQty": "9000.00000000", "stepSize": "0.00001000"},
+            {"filterType": "NOTIONAL", "minNotional": "5.00000000", "applyMinToMarket": true, "maxNotional": "9000000.00000000", "applyMaxToMarket": false, "avgPriceMins": 5},
+        ],
+        "permissions": [],
+        "permissionSets": [["SPOT"]],
+        "defaultSelfTradePreventionMode": "EXPIRE_MAKER",
+        "allowedSelfTradePreventionModes": ["EXPIRE_TAKER", "EXPIRE_MAKER", "EXPIRE_BOTH"],
+    })
+}
+
+/// Flat klines at the price of the symbol, the last `limit` ones up to
+/// `endTime` or the first `limit` ones from `startTime`.
+fn klines(params: &[(String, String)]) -> MockResponse {
+    let (_, _, _, price) = match symbol(params) {
+        Ok(symbol) => symbol,
+        Err(response) => return response,
+    };
+    let interval = match param(params, "interval") {
+        Some(interval) => interval,
+        None => return missing_param("interval"),
+    };
+    let interval = match interval_millis(interval) {
+        Some(interval) => interval,
+        None => return MockResponse::error(400, -1120, "Invalid interval."),
+    };
+    let number = |key: &str| param(params, key).and_then(|value| value.parse::<u64>().ok());
+    let limit = number("limit").unwrap_or(500).clamp(1, 1000);
+    let end_time = number("endTime").unwrap_or_else(now);
+
+    let first_open_time = match number("startTime") {
+        Some(start_time) => (start_time + interval - 1) / interval * interval,
+        None => (end_time / interval).saturating_sub(limit - 1) * interval,
+    };
+    let klines: Vec<Value> = (0..limit)
+        .map(|i| first_open_time + i * interval)
+        .take_while(|open_time| *open_time <= end_time)
+        .map(|open_time| {
+            json!([
+                open_time,
+                price,
+                price,
+                price,
+                price,
+                "0.00000000",
+                open_time + interval - 1,
+                "0.00000000",
+                0,
+                "0.00000000",
+                "0.00000000",
+                "0"
+            ])
+        })
+        .collect();
+
+    MockResponse::new(200, Value::Array(klines).to_string())
+}
+
+/// Length of a kline interval, e.g. `15m`, in milliseconds.
+fn interval_millis(interval: &str) -> Option<u64> {
+    let unit = interval.chars().last()?;
+    let count: u64 = interval[..interval.len() - 1]
+        .parse()
+        .ok()
+        .filter(|count| *count > 0)?;
+    let unit = match unit {
+        's' => 1_000,
+        'm' => 60_000,
+        'h' => 3_600_000,
+        'd' => 86_400_000,
+        'w' => 7 * 86_400_000,
+        // Months are approximated to 30 days.
+        'M' => 30 * 86_400_000,
+        _ => return None,
+    };
+
+    Some(count * unit)
+}
+
+/// `price` moved by `offset` ticks.
+fn offset_price(price: &str, offset: i64) -> String {
+    let tick: rust_decimal::Decimal = TICK.parse().unwrap();
+    let price: rust_decimal::Decimal = price.parse().unwrap();
+    (price + tick * rust_decimal::Decimal::from(offset)).to_string()
+}
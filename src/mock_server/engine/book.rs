@@ -0,0 +1,117 @@
+use crate::mock_server::engine::order::Side;
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// Order book and last price of a symbol, as seen on the market streams.
+#[derive(Debug, Default)]
+pub(super) struct Market {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    pub(super) last_price: Option<Decimal>,
+}
+
+impl Market {
+    /// Replace the book with a snapshot.
+    pub(super) fn set_book(&mut self, bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) {
+        self.bids.clear();
+        self.asks.clear();
+        self.update_book(bids, asks);
+    }
+
+    /// Apply a diff, a zero quantity removes the level.
+    pub(super) fn update_book(&mut self, bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) {
+        for (levels, updates) in [(&mut self.bids, bids), (&mut self.asks, asks)] {
+            for (price, qty) in updates {
+                if qty.is_zero() {
+                    levels.remove(price);
+                } else {
+                    levels.insert(*price, *qty);
+                }
+            }
+        }
+    }
+
+    /// Set the best levels, dropping the levels they cross.
+    pub(super) fn set_top(&mut self, bid: (Decimal, Decimal), ask: (Decimal, Decimal)) {
+        self.bids
+            .retain(|price, _| *price < bid.0 && *price < ask.0);
+        self.asks
+            .retain(|price, _| *price > ask.0 && *price > bid.0);
+        self.update_book(&[bid], &[ask]);
+    }
+
+    pub(super) fn best_bid(&self) -> Option<Decimal> {
+        self.bids.keys().next_back().copied()
+    }
+
+    pub(super) fn best_ask(&self) -> Option<Decimal> {
+        self.asks.keys().next().copied()
+    }
+
+    /// Price levels a taker order on `side` trades against within `limit`,
+    /// best first.
+    ///
+    /// Without book, the last price is used with unlimited quantity.
+    pub(super) fn liquidity(&self, side: Side, limit: Option<Decimal>) -> Vec<(Decimal, Decimal)> {
+        let within = |price: &Decimal| match (side, limit) {
+            (_, None) => true,
+            (Side::Buy, Some(limit)) => *price <= limit,
+            (Side::Sell, Some(limit)) => *price >= limit,
+        };
+
+        let levels: Vec<(Decimal, Decimal)> = match side {
+            Side::Buy => self.asks.iter().map(|(p, q)| (*p, *q)).collect(),
+            Side::Sell => self.bids.iter().rev().map(|(p, q)| (*p, *q)).collect(),
+        };
+        if !levels.is_empty() {
+            return levels
+                .into_iter()
+                .take_while(|(price, _)| within(price))
+                .collect();
+        }
+
+        match self.last_price {
+            Some(price) if within(&price) => vec![(price, Decimal::MAX)],
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Market;
+    use crate::mock_server::engine::order::Side;
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn mock_server_market_liquidity_test() {
+        let mut market = Market::default();
+        assert!(market.liquidity(Side::Buy, None).is_empty());
+
+        market.last_price = Some(dec!(10));
+        assert_eq!(
+            market.liquidity(Side::Buy, Some(dec!(11))),
+            vec![(dec!(10), Decimal::MAX)]
+        );
+        assert!(market.liquidity(Side::Buy, Some(dec!(9))).is_empty());
+
+        market.set_book(
+            &[(dec!(9), dec!(1)), (dec!(8), dec!(2))],
+            &[(dec!(11), dec!(1)), (dec!(12), dec!(2))],
+        );
+        assert_eq!(
+            market.liquidity(Side::Sell, Some(dec!(8.5))),
+            vec![(dec!(9), dec!(1))]
+        );
+        assert_eq!(market.liquidity(Side::Buy, None).len(), 2);
+
+        market.update_book(&[(dec!(9), dec!(0))], &[]);
+        assert_eq!(market.best_bid(), Some(dec!(8)));
+
+        market.set_top((dec!(11.5), dec!(1)), (dec!(11.6), dec!(1)));
+        assert_eq!(market.best_bid(), Some(dec!(11.5)));
+        assert_eq!(market.best_ask(), Some(dec!(11.6)));
+        assert_eq!(market.liquidity(Side::Buy, None).len(), 2);
+    }
+}
@@ -0,0 +1,1594 @@
+mod book;
+mod order;
+
+use crate::http::Method;
+use crate::market_stream::event::{MarketEvent, PartialDepthEvent};
+use crate::mock_server::rest::{missing_param, now, param};
+use crate::mock_server::MockResponse;
+use book::Market;
+use order::{Fill, Order, OrderType, Side, Status, TimeInForce};
+use rand::{distributions::Alphanumeric, Rng};
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Trading rules of a symbol, the filters of `exchangeInfo`.
+///
+/// A zero tick size, step size or minimum notional disables the filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolRules {
+    base_asset: String,
+    quote_asset: String,
+    tick_size: Decimal,
+    step_size: Decimal,
+    min_notional: Decimal,
+}
+
+impl SymbolRules {
+    pub fn new(base_asset: &str, quote_asset: &str) -> Self {
+        Self {
+            base_asset: base_asset.to_owned(),
+            quote_asset: quote_asset.to_owned(),
+            tick_size: Decimal::ZERO,
+            step_size: Decimal::ZERO,
+            min_notional: Decimal::ZERO,
+        }
+    }
+
+    /// `PRICE_FILTER` tick size.
+    pub fn tick_size(mut self, tick_size: Decimal) -> Self {
+        self.tick_size = tick_size;
+        self
+    }
+
+    /// `LOT_SIZE` step size.
+    pub fn step_size(mut self, step_size: Decimal) -> Self {
+        self.step_size = step_size;
+        self
+    }
+
+    /// `NOTIONAL` minimum notional.
+    pub fn min_notional(mut self, min_notional: Decimal) -> Self {
+        self.min_notional = min_notional;
+        self
+    }
+}
+
+/// Balance of an asset of the paper trading account.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Balance {
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+#[derive(Debug)]
+struct OrderList {
+    symbol: String,
+    list_client_order_id: String,
+    orders: Vec<u64>,
+    /// Balance locked by the orders of the list.
+    locked: Decimal,
+    done: bool,
+    time: u64,
+}
+
+#[derive(Debug)]
+struct OrderRequest {
+    symbol: String,
+    side: Side,
+    order_type: OrderType,
+    time_in_force: TimeInForce,
+    quantity: Decimal,
+    quote_order_qty: Decimal,
+    price: Decimal,
+    stop_price: Decimal,
+    client_order_id: Option<String>,
+}
+
+/// Paper trading account matching the orders against the market streams.
+///
+/// Served by [`MockServer::paper_trading`](crate::mock_server::MockServer::paper_trading),
+/// the engine:
+///
+/// * Validates new orders against the [`SymbolRules`] and the balances, and
+///   locks the balance they may spend.
+/// * Fills marketable orders against the order book, or the last price with
+///   unlimited quantity when no book was received.
+/// * Fills resting orders when a trade reaches their price or the book
+///   crosses them, as maker.
+/// * Triggers `STOP_LOSS` and `TAKE_PROFIT` orders on the last trade price
+///   and expires the other order of an OCO once an order is triggered or filled.
+/// * Charges the commission in the received asset.
+///
+/// Each change is pushed as `executionReport`, `listStatus` and
+/// `outboundAccountPosition` events to the connections subscribed to a
+/// listen key.
+///
+/// Served endpoints: `order` (new, test, query and cancel), `openOrders`
+/// (query and cancel), `allOrders`, `orderList/oco`, `orderList` (cancel),
+/// `account` and `myTrades`.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::mock_server::{MatchingEngine, SymbolRules};
+/// use rust_decimal_macros::dec;
+///
+/// let engine = MatchingEngine::new()
+///     .symbol(
+///         "BNBUSDT",
+///         SymbolRules::new("BNB", "USDT")
+///             .tick_size(dec!(0.1))
+///             .step_size(dec!(0.001))
+///             .min_notional(dec!(5)),
+///     )
+///     .balance("USDT", dec!(1000))
+///     .commission(dec!(0.00075));
+/// ```
+#[derive(Debug)]
+pub struct MatchingEngine {
+    symbols: HashMap<String, SymbolRules>,
+    markets: HashMap<String, Market>,
+    balances: BTreeMap<String, Balance>,
+    commission: Decimal,
+    orders: BTreeMap<u64, Order>,
+    lists: BTreeMap<i64, OrderList>,
+    next_order_id: u64,
+    next_list_id: i64,
+    next_trade_id: u64,
+    events: Vec<Value>,
+    changed_assets: BTreeSet<String>,
+}
+
+impl Default for MatchingEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MatchingEngine {
+    /// Engine without symbol nor balance, charging a 0.1% commission.
+    pub fn new() -> Self {
+        Self {
+            symbols: HashMap::new(),
+            markets: HashMap::new(),
+            balances: BTreeMap::new(),
+            commission: Decimal::new(1, 3),
+            orders: BTreeMap::new(),
+            lists: BTreeMap::new(),
+            next_order_id: 1,
+            next_list_id: 1,
+            next_trade_id: 1,
+            events: vec![],
+            changed_assets: BTreeSet::new(),
+        }
+    }
+
+    /// Accept orders on `symbol`, e.g. `BNBUSDT`.
+    pub fn symbol(mut self, symbol: &str, rules: SymbolRules) -> Self {
+        self.symbols.insert(symbol.to_owned(), rules);
+        self
+    }
+
+    /// Set the free balance of `asset`.
+    pub fn balance(mut self, asset: &str, free: Decimal) -> Self {
+        self.balances.entry(asset.to_owned()).or_default().free = free;
+        self
+    }
+
+    /// Commission rate charged to makers and takers.
+    pub fn commission(mut self, commission: Decimal) -> Self {
+        self.commission = commission;
+        self
+    }
+
+    pub fn balances(&self) -> &BTreeMap<String, Balance> {
+        &self.balances
+    }
+
+    /// Update the market with a trade, depth or book ticker event and match
+    /// the orders. Other events are ignored.
+    pub fn feed(&mut self, event: &MarketEvent) {
+        match event {
+            MarketEvent::Trade(trade) => self.on_trade(&trade.symbol, trade.price, trade.qty),
+            MarketEvent::AggTrade(trade) => self.on_trade(&trade.symbol, trade.price, trade.qty),
+            MarketEvent::BookTicker(ticker) => {
+                if let Some(market) = self.market_mut(&ticker.symbol) {
+                    market.set_top(
+                        (ticker.best_bid_price, ticker.best_bid_qty),
+                        (ticker.best_ask_price, ticker.best_ask_qty),
+                    );
+                    self.on_book(&ticker.symbol);
+                }
+            }
+            MarketEvent::DepthUpdate(depth) => {
+                if let Some(market) = self.market_mut(&depth.symbol) {
+                    market.update_book(&depth.bids, &depth.asks);
+                    self.on_book(&depth.symbol);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Replace the order book of `symbol` with a partial depth snapshot,
+    /// which does not carry the symbol.
+    pub fn feed_partial_depth(&mut self, symbol: &str, depth: &PartialDepthEvent) {
+        if let Some(market) = self.market_mut(symbol) {
+            market.set_book(&depth.bids, &depth.asks);
+            self.on_book(symbol);
+        }
+    }
+
+    /// Response to a request on a trading endpoint, `None` if not served.
+    pub(super) fn handle(
+        &mut self,
+        method: &Method,
+        path: &str,
+        params: &[(String, String)],
+    ) -> Option<MockResponse> {
+        let result = match (method, path) {
+            (Method::Post, "/api/v3/order") => self.new_order(params),
+            (Method::Post, "/api/v3/order/test") => self.test_order(params),
+            (Method::Get, "/api/v3/order") => self.query_order(params),
+            (Method::Delete, "/api/v3/order") => self.cancel_order(params),
+            (Method::Get, "/api/v3/openOrders") => Ok(self.open_orders(params)),
+            (Method::Delete, "/api/v3/openOrders") => self.cancel_open_orders(params),
+            (Method::Get, "/api/v3/allOrders") => self.all_orders(params),
+            (Method::Post, "/api/v3/orderList/oco") => self.new_oco(params),
+            (Method::Delete, "/api/v3/orderList") => self.cancel_order_list(params),
+            (Method::Get, "/api/v3/account") => Ok(self.account()),
+            (Method::Get, "/api/v3/myTrades") => self.my_trades(params),
+            _ => return None,
+        };
+
+        Some(match result {
+            Ok(data) => MockResponse::new(200, data.to_string()),
+            Err(response) => response,
+        })
+    }
+
+    /// User data events since the last call, followed by an
+    /// `outboundAccountPosition` event if a balance changed.
+    pub(super) fn take_events(&mut self) -> Vec<Value> {
+        let mut events = std::mem::take(&mut self.events);
+        let assets = std::mem::take(&mut self.changed_assets);
+        if !assets.is_empty() {
+            let now = now();
+            let balances: Vec<Value> = assets
+                .iter()
+                .map(|asset| {
+                    let balance = self.balances.get(asset).copied().unwrap_or_default();
+                    json!({
+                        "a": asset,
+                        "f": balance.free.to_string(),
+                        "l": balance.locked.to_string(),
+                    })
+                })
+                .collect();
+            events.push(json!({
+                "e": "outboundAccountPosition",
+                "E": now,
+                "u": now,
+                "B": balances,
+            }));
+        }
+
+        events
+    }
+
+    fn market_mut(&mut self, symbol: &str) -> Option<&mut Market> {
+        if !self.symbols.contains_key(symbol) {
+            return None;
+        }
+
+        Some(self.markets.entry(symbol.to_owned()).or_default())
+    }
+
+    // Endpoints
+
+    fn new_order(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let request = parse_order(params, "")?;
+        let response_type = param(params, "newOrderRespType").unwrap_or(match request.order_type {
+            OrderType::Limit | OrderType::Market => "FULL",
+            _ => "ACK",
+        });
+        let locked = self.validate(&request)?;
+
+        let order_id = self.place(request, locked, -1);
+        self.activate(order_id);
+
+        Ok(self.orders[&order_id].to_response(response_type))
+    }
+
+    fn test_order(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let request = parse_order(params, "")?;
+        self.validate(&request)?;
+
+        Ok(json!({}))
+    }
+
+    fn query_order(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let order_id = self.find_order(params, -2013, "Order does not exist.")?;
+
+        Ok(self.orders[&order_id].to_json())
+    }
+
+    fn cancel_order(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let unknown_order = || MockResponse::error(400, -2011, "Unknown order sent.");
+        let order_id = self.find_order(params, -2011, "Unknown order sent.")?;
+        let order = &self.orders[&order_id];
+        if !order.status.is_open() {
+            return Err(unknown_order());
+        }
+
+        let cancel_client_order_id = param(params, "newClientOrderId")
+            .map(str::to_owned)
+            .unwrap_or_else(client_order_id);
+        // Canceling an order of a list cancels the whole list.
+        let order_ids = match self.lists.get(&order.order_list_id) {
+            Some(list) => list.orders.clone(),
+            None => vec![order_id],
+        };
+        for id in order_ids {
+            if self.orders[&id].status.is_open() {
+                self.finish(id, Status::Canceled, Some(&cancel_client_order_id));
+            }
+        }
+
+        Ok(self.cancel_response(order_id, &cancel_client_order_id))
+    }
+
+    fn open_orders(&self, params: &[(String, String)]) -> Value {
+        let symbol = param(params, "symbol");
+        self.orders
+            .values()
+            .filter(|order| order.status.is_open())
+            .filter(|order| symbol.map_or(true, |symbol| order.symbol == symbol))
+            .map(Order::to_json)
+            .collect()
+    }
+
+    fn cancel_open_orders(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+        let order_ids: Vec<u64> = self
+            .orders
+            .values()
+            .filter(|order| order.symbol == symbol && order.status.is_open())
+            .map(|order| order.order_id)
+            .collect();
+
+        let mut responses = vec![];
+        for order_id in order_ids {
+            let cancel_client_order_id = client_order_id();
+            if self.orders[&order_id].status.is_open() {
+                self.finish(order_id, Status::Canceled, Some(&cancel_client_order_id));
+                responses.push(self.cancel_response(order_id, &cancel_client_order_id));
+            }
+        }
+
+        Ok(responses.into())
+    }
+
+    fn all_orders(&self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+        let from_id: u64 = parse(params, "orderId")?.unwrap_or(0);
+        let limit: usize = parse(params, "limit")?.unwrap_or(500);
+
+        Ok(self
+            .orders
+            .range(from_id..)
+            .map(|(_, order)| order)
+            .filter(|order| order.symbol == symbol)
+            .take(limit)
+            .map(Order::to_json)
+            .collect())
+    }
+
+    fn new_oco(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let above = parse_order(params, "above")?;
+        let below = parse_order(params, "below")?;
+        for leg in [&above, &below] {
+            if !(leg.order_type == OrderType::LimitMaker || leg.order_type.has_stop_price()) {
+                return Err(MockResponse::error(400, -1116, "Invalid orderType."));
+            }
+        }
+        let leg_price = |leg: &OrderRequest| match leg.order_type.has_stop_price() {
+            true => leg.stop_price,
+            false => leg.price,
+        };
+        if leg_price(&above) <= leg_price(&below) {
+            return Err(MockResponse::error(
+                400,
+                -2010,
+                "The relationship of the prices for the orders is not correct.",
+            ));
+        }
+        let locked = self.validate(&above)?.max(self.validate(&below)?);
+
+        let order_list_id = self.next_list_id;
+        self.next_list_id += 1;
+        let symbol = above.symbol.clone();
+        let side = above.side;
+        self.lock(&self.lock_asset(&symbol, side), locked);
+        let above = self.place(above, Decimal::ZERO, order_list_id);
+        let below = self.place(below, Decimal::ZERO, order_list_id);
+        self.lists.insert(
+            order_list_id,
+            OrderList {
+                symbol,
+                list_client_order_id: param(params, "listClientOrderId")
+                    .map(str::to_owned)
+                    .unwrap_or_else(client_order_id),
+                orders: vec![above, below],
+                locked,
+                done: false,
+                time: now(),
+            },
+        );
+        let list_status = self.list_status(order_list_id);
+        self.events.push(list_status);
+        self.activate(above);
+        self.activate(below);
+
+        let mut response = self.list_json(order_list_id);
+        response["orderReports"] = [above, below]
+            .iter()
+            .map(|order_id| self.orders[order_id].to_response("RESULT"))
+            .collect();
+        Ok(response)
+    }
+
+    fn cancel_order_list(&mut self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+        let order_list_id: Option<i64> = parse(params, "orderListId")?;
+        let list_client_order_id = param(params, "listClientOrderId");
+        if order_list_id.is_none() && list_client_order_id.is_none() {
+            return Err(missing_param("orderListId"));
+        }
+
+        let order_list_id = self
+            .lists
+            .iter()
+            .find(|(id, list)| {
+                list.symbol == symbol
+                    && !list.done
+                    && order_list_id.map_or(true, |order_list_id| order_list_id == **id)
+                    && list_client_order_id.map_or(true, |client_order_id| {
+                        client_order_id == list.list_client_order_id
+                    })
+            })
+            .map(|(id, _)| *id)
+            .ok_or_else(|| MockResponse::error(400, -2011, "Unknown order list sent."))?;
+
+        let cancel_client_order_id = param(params, "newClientOrderId")
+            .map(str::to_owned)
+            .unwrap_or_else(client_order_id);
+        let order_ids = self.lists[&order_list_id].orders.clone();
+        for order_id in &order_ids {
+            if self.orders[order_id].status.is_open() {
+                self.finish(*order_id, Status::Canceled, Some(&cancel_client_order_id));
+            }
+        }
+
+        let mut response = self.list_json(order_list_id);
+        response["orderReports"] = order_ids
+            .iter()
+            .map(|order_id| self.cancel_response(*order_id, &cancel_client_order_id))
+            .collect();
+        Ok(response)
+    }
+
+    fn account(&self) -> Value {
+        let commission = (self.commission * Decimal::from(10_000))
+            .round()
+            .to_string()
+            .parse::<u64>()
+            .unwrap_or_default();
+        let balances: Vec<Value> = self
+            .balances
+            .iter()
+            .map(|(asset, balance)| {
+                json!({
+                    "asset": asset,
+                    "free": balance.free.to_string(),
+                    "locked": balance.locked.to_string(),
+                })
+            })
+            .collect();
+
+        json!({
+            "makerCommission": commission,
+            "takerCommission": commission,
+            "buyerCommission": 0,
+            "sellerCommission": 0,
+            "commissionRates": {
+                "maker": self.commission.to_string(),
+                "taker": self.commission.to_string(),
+                "buyer": "0",
+                "seller": "0",
+            },
+            "canTrade": true,
+            "canWithdraw": true,
+            "canDeposit": true,
+            "brokered": false,
+            "requireSelfTradePrevention": false,
+            "preventSor": false,
+            "updateTime": now(),
+            "accountType": "SPOT",
+            "balances": balances,
+            "permissions": ["SPOT"],
+            "uid": 0,
+        })
+    }
+
+    fn my_trades(&self, params: &[(String, String)]) -> Result<Value, MockResponse> {
+        let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+        let order_id: Option<u64> = parse(params, "orderId")?;
+        let limit: usize = parse(params, "limit")?.unwrap_or(500);
+
+        let mut trades: Vec<(&Order, &Fill)> = self
+            .orders
+            .values()
+            .filter(|order| order.symbol == symbol)
+            .filter(|order| order_id.map_or(true, |order_id| order.order_id == order_id))
+            .flat_map(|order| order.fills.iter().map(move |fill| (order, fill)))
+            .collect();
+        trades.sort_by_key(|(_, fill)| fill.trade_id);
+
+        Ok(trades
+            .into_iter()
+            .take(limit)
+            .map(|(order, fill)| {
+                json!({
+                    "symbol": order.symbol,
+                    "id": fill.trade_id,
+                    "orderId": order.order_id,
+                    "orderListId": order.order_list_id,
+                    "price": fill.price.to_string(),
+                    "qty": fill.qty.to_string(),
+                    "quoteQty": (fill.price * fill.qty).to_string(),
+                    "commission": fill.commission.to_string(),
+                    "commissionAsset": fill.commission_asset,
+                    "time": fill.time,
+                    "isBuyer": order.side == Side::Buy,
+                    "isMaker": fill.is_maker,
+                    "isBestMatch": true,
+                })
+            })
+            .collect())
+    }
+
+    // Orders
+
+    /// Check the order against the filters and the balances.
+    ///
+    /// Returns the balance to lock.
+    fn validate(&self, request: &OrderRequest) -> Result<Decimal, MockResponse> {
+        let rules = self
+            .symbols
+            .get(&request.symbol)
+            .ok_or_else(|| MockResponse::error(400, -1121, "Invalid symbol."))?;
+        let default_market = Market::default();
+        let market = self.markets.get(&request.symbol).unwrap_or(&default_market);
+        let filter_failure =
+            |filter: &str| MockResponse::error(400, -1013, &format!("Filter failure: {}", filter));
+        let rejected = |msg: &str| MockResponse::error(400, -2010, msg);
+
+        for (price, required) in [
+            (request.price, request.order_type.has_price()),
+            (request.stop_price, request.order_type.has_stop_price()),
+        ] {
+            if required && (price <= Decimal::ZERO || !is_multiple(price, rules.tick_size)) {
+                return Err(filter_failure("PRICE_FILTER"));
+            }
+        }
+        let quote_order = !request.quote_order_qty.is_zero();
+        if !quote_order
+            && (request.quantity <= Decimal::ZERO
+                || !is_multiple(request.quantity, rules.step_size))
+        {
+            return Err(filter_failure("LOT_SIZE"));
+        }
+
+        let reference_price = [request.price, request.stop_price]
+            .into_iter()
+            .find(|price| !price.is_zero())
+            .or(market.last_price)
+            .or(match request.side {
+                Side::Buy => market.best_ask(),
+                Side::Sell => market.best_bid(),
+            });
+        let notional = match quote_order {
+            true => Some(request.quote_order_qty),
+            false => reference_price
+                .map(|price| {
+                    price
+                        .checked_mul(request.quantity)
+                        .ok_or_else(|| filter_failure("NOTIONAL"))
+                })
+                .transpose()?,
+        };
+        if notional.map_or(false, |notional| {
+            notional <= Decimal::ZERO || notional < rules.min_notional
+        }) {
+            return Err(filter_failure("NOTIONAL"));
+        }
+
+        if request.order_type == OrderType::LimitMaker {
+            let takes = match request.side {
+                Side::Buy => market.best_ask().map_or(false, |ask| request.price >= ask),
+                Side::Sell => market.best_bid().map_or(false, |bid| request.price <= bid),
+            };
+            if takes {
+                return Err(rejected("Order would immediately match and take."));
+            }
+        }
+        if request.order_type.has_stop_price()
+            && market.last_price.map_or(false, |last_price| {
+                triggers(
+                    request.side,
+                    request.order_type,
+                    request.stop_price,
+                    last_price,
+                )
+            })
+        {
+            return Err(rejected("Order would trigger immediately."));
+        }
+
+        if let Some(client_order_id) = &request.client_order_id {
+            if self
+                .orders
+                .values()
+                .any(|order| order.status.is_open() && order.client_order_id == *client_order_id)
+            {
+                return Err(rejected("Duplicate order sent."));
+            }
+        }
+
+        let locked = match (request.side, quote_order) {
+            (Side::Buy, true) => request.quote_order_qty,
+            (Side::Buy, false) => match reference_price {
+                Some(price) if request.order_type != OrderType::Market => {
+                    price.checked_mul(request.quantity)
+                }
+                _ => market_cost(market, request.quantity),
+            }
+            .ok_or_else(|| filter_failure("NOTIONAL"))?,
+            // Estimated from the reference price, the fills spend the free
+            // balance beyond the lock.
+            (Side::Sell, true) => match reference_price {
+                Some(price) => request
+                    .quote_order_qty
+                    .checked_div(price)
+                    .and_then(|qty| round_down(qty, rules.step_size))
+                    .ok_or_else(|| filter_failure("NOTIONAL"))?,
+                None => Decimal::ZERO,
+            },
+            (Side::Sell, false) => request.quantity,
+        };
+        let asset = self.lock_asset(&request.symbol, request.side);
+        let free = self
+            .balances
+            .get(&asset)
+            .map_or(Decimal::ZERO, |balance| balance.free);
+        if locked > free {
+            return Err(rejected(
+                "Account has insufficient balance for requested action.",
+            ));
+        }
+
+        Ok(locked)
+    }
+
+    /// Add the order, `order_list_id` is `-1` for an order outside of a list.
+    fn place(&mut self, request: OrderRequest, locked: Decimal, order_list_id: i64) -> u64 {
+        let order_id = self.next_order_id;
+        self.next_order_id += 1;
+        self.lock(&self.lock_asset(&request.symbol, request.side), locked);
+
+        let time = now();
+        let order = Order {
+            symbol: request.symbol,
+            order_id,
+            order_list_id,
+            client_order_id: request.client_order_id.unwrap_or_else(client_order_id),
+            side: request.side,
+            order_type: request.order_type,
+            time_in_force: request.time_in_force,
+            price: request.price,
+            quantity: request.quantity,
+            quote_order_qty: request.quote_order_qty,
+            stop_price: request.stop_price,
+            executed_qty: Decimal::ZERO,
+            cummulative_quote_qty: Decimal::ZERO,
+            status: Status::New,
+            triggered: !request.order_type.has_stop_price(),
+            locked,
+            time,
+            update_time: time,
+            fills: vec![],
+        };
+        self.events
+            .push(order.execution_report("NEW", "", None, time));
+        self.orders.insert(order_id, order);
+
+        order_id
+    }
+
+    /// Trigger and match a new or waiting order.
+    fn activate(&mut self, order_id: u64) {
+        let order = &self.orders[&order_id];
+        if !order.status.is_open() {
+            return;
+        }
+
+        if !order.triggered {
+            let last_price = self
+                .markets
+                .get(&order.symbol)
+                .and_then(|market| market.last_price);
+            if !last_price.map_or(false, |last_price| {
+                triggers(order.side, order.order_type, order.stop_price, last_price)
+            }) {
+                return;
+            }
+
+            let order = self.orders.get_mut(&order_id).unwrap();
+            order.triggered = true;
+            order.update_time = now();
+            self.expire_other_orders(order_id);
+        }
+
+        let order = &self.orders[&order_id];
+        match order.order_type {
+            OrderType::LimitMaker => {}
+            OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit => {
+                self.take(order_id, None);
+                if self.orders[&order_id].status.is_open() {
+                    self.finish(order_id, Status::Expired, None);
+                }
+            }
+            OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit => {
+                let limit = Some(order.price);
+                let time_in_force = order.time_in_force;
+                if time_in_force == TimeInForce::Fok
+                    && self.available(order_id, limit) < order.remaining()
+                {
+                    self.finish(order_id, Status::Expired, None);
+                    return;
+                }
+
+                self.take(order_id, limit);
+                if time_in_force != TimeInForce::Gtc && self.orders[&order_id].status.is_open() {
+                    self.finish(order_id, Status::Expired, None);
+                }
+            }
+        }
+    }
+
+    /// Quantity the order can take within `limit`.
+    fn available(&self, order_id: u64, limit: Option<Decimal>) -> Decimal {
+        let order = &self.orders[&order_id];
+        self.markets
+            .get(&order.symbol)
+            .map_or(vec![], |market| market.liquidity(order.side, limit))
+            .iter()
+            .fold(Decimal::ZERO, |sum, (_, qty)| sum.saturating_add(*qty))
+    }
+
+    /// Fill the order as taker against the market, within `limit`.
+    fn take(&mut self, order_id: u64, limit: Option<Decimal>) {
+        let order = &self.orders[&order_id];
+        let step_size = self.symbols[&order.symbol].step_size;
+        let levels = self
+            .markets
+            .get(&order.symbol)
+            .map_or(vec![], |market| market.liquidity(order.side, limit));
+
+        for (price, available) in levels {
+            let order = &self.orders[&order_id];
+            let qty = match order.is_quote_order() {
+                true => order
+                    .remaining_quote()
+                    .checked_div(price)
+                    .and_then(|qty| round_down(qty, step_size)),
+                false => Some(order.remaining()),
+            };
+            let qty = match qty {
+                Some(qty) => qty.min(available),
+                None => break,
+            };
+            if qty <= Decimal::ZERO || !self.fill(order_id, price, qty, false) {
+                break;
+            }
+            if !self.orders[&order_id].status.is_open() {
+                break;
+            }
+        }
+    }
+
+    /// Trade `qty` of the order at `price` and settle the balances.
+    ///
+    /// Returns `false` if the balance is insufficient, or if the amounts
+    /// overflow.
+    fn fill(&mut self, order_id: u64, price: Decimal, qty: Decimal, is_maker: bool) -> bool {
+        let order = &self.orders[&order_id];
+        let rules = &self.symbols[&order.symbol];
+        let quote_qty = match price.checked_mul(qty) {
+            Some(quote_qty) => quote_qty,
+            None => return false,
+        };
+        let (spent_asset, spent, reserved, received_asset, received) = match order.side {
+            Side::Buy => (
+                rules.quote_asset.clone(),
+                quote_qty,
+                match order.price.is_zero() {
+                    true => Some(quote_qty),
+                    false => order.price.checked_mul(qty),
+                },
+                rules.base_asset.clone(),
+                qty,
+            ),
+            Side::Sell => (
+                rules.base_asset.clone(),
+                qty,
+                Some(qty),
+                rules.quote_asset.clone(),
+                quote_qty,
+            ),
+        };
+        let step_size = rules.step_size;
+        let cummulative_quote_qty = order.cummulative_quote_qty.checked_add(quote_qty);
+        let commission = received.checked_mul(self.commission);
+        let (reserved, commission, cummulative_quote_qty) =
+            match (reserved, commission, cummulative_quote_qty) {
+                (Some(reserved), Some(commission), Some(cummulative_quote_qty)) => {
+                    (reserved, commission, cummulative_quote_qty)
+                }
+                _ => return false,
+            };
+
+        let released = reserved.min(*self.locked_mut(order_id));
+        let balance = self.balances.entry(spent_asset.clone()).or_default();
+        let spent_free = match balance.free.checked_add(released) {
+            Some(available) if available >= spent => available - spent,
+            _ => return false,
+        };
+        let received_free = match self.balances.get(&received_asset) {
+            Some(balance) => balance.free.checked_add(received - commission),
+            None => Some(received - commission),
+        };
+        let received_free = match received_free {
+            Some(received_free) => received_free,
+            None => return false,
+        };
+        *self.locked_mut(order_id) -= released;
+        let balance = self.balances.entry(spent_asset.clone()).or_default();
+        balance.locked -= released;
+        balance.free = spent_free;
+        self.balances
+            .entry(received_asset.clone())
+            .or_default()
+            .free = received_free;
+        self.changed_assets.insert(spent_asset);
+        self.changed_assets.insert(received_asset.clone());
+
+        let time = now();
+        let fill = Fill {
+            trade_id: self.next_trade_id,
+            price,
+            qty,
+            commission,
+            commission_asset: received_asset,
+            time,
+            is_maker,
+        };
+        self.next_trade_id += 1;
+
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.executed_qty += qty;
+        order.cummulative_quote_qty = cummulative_quote_qty;
+        order.update_time = time;
+        let filled = match order.is_quote_order() {
+            true => {
+                order.quantity = order.executed_qty;
+                let step_size = match step_size.is_zero() {
+                    true => Decimal::new(1, 8),
+                    false => step_size,
+                };
+                price
+                    .checked_mul(step_size)
+                    .map_or(true, |min_quote| order.remaining_quote() < min_quote)
+            }
+            false => order.remaining().is_zero(),
+        };
+        order.status = match filled {
+            true => Status::Filled,
+            false => Status::PartiallyFilled,
+        };
+        self.events
+            .push(order.execution_report("TRADE", "", Some(&fill), time));
+        order.fills.push(fill);
+
+        self.expire_other_orders(order_id);
+        if filled {
+            self.close(order_id);
+        }
+
+        true
+    }
+
+    /// Cancel or expire an open order.
+    fn finish(&mut self, order_id: u64, status: Status, cancel_client_order_id: Option<&str>) {
+        let time = now();
+        let order = self.orders.get_mut(&order_id).unwrap();
+        order.status = status;
+        order.update_time = time;
+
+        let mut report = order.execution_report(status.as_str(), "", None, time);
+        if let Some(cancel_client_order_id) = cancel_client_order_id {
+            report["c"] = cancel_client_order_id.into();
+            report["C"] = order.client_order_id.clone().into();
+        }
+        self.events.push(report);
+
+        self.close(order_id);
+    }
+
+    /// Release the balance locked by a closed order, or by its list once all
+    /// the orders of the list are closed.
+    fn close(&mut self, order_id: u64) {
+        let order = &self.orders[&order_id];
+        let asset = self.lock_asset(&order.symbol, order.side);
+        let list = self.lists.get(&order.order_list_id);
+        if list.map_or(false, |list| {
+            list.orders
+                .iter()
+                .any(|order_id| self.orders[order_id].status.is_open())
+        }) {
+            return;
+        }
+
+        let released = std::mem::take(self.locked_mut(order_id));
+        self.unlock(&asset, released);
+        let order_list_id = self.orders[&order_id].order_list_id;
+        if let Some(list) = self.lists.get_mut(&order_list_id) {
+            list.done = true;
+            let list_status = self.list_status(order_list_id);
+            self.events.push(list_status);
+        }
+    }
+
+    /// Expire the open orders of the list of a triggered or filled order.
+    fn expire_other_orders(&mut self, order_id: u64) {
+        let order_list_id = self.orders[&order_id].order_list_id;
+        let order_ids = match self.lists.get(&order_list_id) {
+            Some(list) => list.orders.clone(),
+            None => return,
+        };
+        for id in order_ids {
+            if id != order_id && self.orders[&id].status.is_open() {
+                self.finish(id, Status::Expired, None);
+            }
+        }
+    }
+
+    // Market
+
+    fn on_trade(&mut self, symbol: &str, price: Decimal, qty: Decimal) {
+        match self.market_mut(symbol) {
+            Some(market) => market.last_price = Some(price),
+            None => return,
+        }
+
+        let waiting: Vec<u64> = self
+            .orders
+            .values()
+            .filter(|order| order.symbol == symbol && order.status.is_open() && !order.triggered)
+            .map(|order| order.order_id)
+            .collect();
+        for order_id in waiting {
+            self.activate(order_id);
+        }
+
+        self.match_resting(symbol, Side::Buy, price, qty);
+        self.match_resting(symbol, Side::Sell, price, qty);
+    }
+
+    fn on_book(&mut self, symbol: &str) {
+        // Resting buy orders are crossed by the best ask, sell orders by the
+        // best bid.
+        let market = &self.markets[symbol];
+        let best_levels = [
+            (
+                Side::Buy,
+                market.best_ask(),
+                market.liquidity(Side::Buy, None),
+            ),
+            (
+                Side::Sell,
+                market.best_bid(),
+                market.liquidity(Side::Sell, None),
+            ),
+        ]
+        .map(|(side, best, levels)| (side, best.and(levels.first().copied())));
+
+        for (side, level) in best_levels {
+            if let Some((price, qty)) = level {
+                self.match_resting(symbol, side, price, qty);
+            }
+        }
+    }
+
+    /// Fill the resting orders on `side` reached by `price` as maker, best
+    /// price first, up to `qty`.
+    fn match_resting(&mut self, symbol: &str, side: Side, price: Decimal, qty: Decimal) {
+        let mut resting: Vec<&Order> = self
+            .orders
+            .values()
+            .filter(|order| {
+                order.symbol == symbol
+                    && order.side == side
+                    && order.status.is_open()
+                    && order.triggered
+                    && order.order_type.has_price()
+            })
+            .filter(|order| match side {
+                Side::Buy => order.price >= price,
+                Side::Sell => order.price <= price,
+            })
+            .collect();
+        resting.sort_by_key(|order| match side {
+            Side::Buy => (-order.price, order.order_id),
+            Side::Sell => (order.price, order.order_id),
+        });
+        let resting: Vec<(u64, Decimal, Decimal)> = resting
+            .into_iter()
+            .map(|order| (order.order_id, order.price, order.remaining()))
+            .collect();
+
+        let mut left = qty;
+        for (order_id, price, remaining) in resting {
+            let qty = remaining.min(left);
+            if qty <= Decimal::ZERO {
+                break;
+            }
+            if self.fill(order_id, price, qty, true) {
+                left -= qty;
+            }
+        }
+    }
+
+    // Helpers
+
+    fn find_order(
+        &self,
+        params: &[(String, String)],
+        code: i64,
+        msg: &str,
+    ) -> Result<u64, MockResponse> {
+        let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+        let order_id: Option<u64> = parse(params, "orderId")?;
+        let client_order_id = param(params, "origClientOrderId");
+        if order_id.is_none() && client_order_id.is_none() {
+            return Err(missing_param("orderId"));
+        }
+
+        self.orders
+            .values()
+            .rev()
+            .find(|order| {
+                order.symbol == symbol
+                    && order_id.map_or(true, |order_id| order.order_id == order_id)
+                    && client_order_id.map_or(true, |client_order_id| {
+                        order.client_order_id == client_order_id
+                    })
+            })
+            .map(|order| order.order_id)
+            .ok_or_else(|| MockResponse::error(400, code, msg))
+    }
+
+    fn cancel_response(&self, order_id: u64, cancel_client_order_id: &str) -> Value {
+        let order = &self.orders[&order_id];
+        let mut response = order.to_json();
+        response["origClientOrderId"] = order.client_order_id.clone().into();
+        response["clientOrderId"] = cancel_client_order_id.into();
+        response["transactTime"] = order.update_time.into();
+        for key in [
+            "time",
+            "updateTime",
+            "isWorking",
+            "workingTime",
+            "icebergQty",
+        ] {
+            if let Value::Object(response) = &mut response {
+                response.remove(key);
+            }
+        }
+
+        response
+    }
+
+    fn list_json(&self, order_list_id: i64) -> Value {
+        let list = &self.lists[&order_list_id];
+        let (list_status_type, list_order_status) = match list.done {
+            true => ("ALL_DONE", "ALL_DONE"),
+            false => ("EXEC_STARTED", "EXECUTING"),
+        };
+        let orders: Vec<Value> = list
+            .orders
+            .iter()
+            .map(|order_id| {
+                let order = &self.orders[order_id];
+                json!({
+                    "symbol": order.symbol,
+                    "orderId": order.order_id,
+                    "clientOrderId": order.client_order_id,
+                })
+            })
+            .collect();
+
+        json!({
+            "orderListId": order_list_id,
+            "contingencyType": "OCO",
+            "listStatusType": list_status_type,
+            "listOrderStatus": list_order_status,
+            "listClientOrderId": list.list_client_order_id,
+            "transactionTime": list.time,
+            "symbol": list.symbol,
+            "orders": orders,
+        })
+    }
+
+    /// `listStatus` user data event.
+    fn list_status(&self, order_list_id: i64) -> Value {
+        let list = self.list_json(order_list_id);
+        let orders: Vec<Value> = list["orders"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|order| {
+                json!({
+                    "s": order["symbol"],
+                    "i": order["orderId"],
+                    "c": order["clientOrderId"],
+                })
+            })
+            .collect();
+
+        json!({
+            "e": "listStatus",
+            "E": now(),
+            "s": list["symbol"],
+            "g": order_list_id,
+            "c": "OCO",
+            "l": list["listStatusType"],
+            "L": list["listOrderStatus"],
+            "r": "NONE",
+            "C": list["listClientOrderId"],
+            "T": list["transactionTime"],
+            "O": orders,
+        })
+    }
+
+    /// Asset spent by an order on `side`.
+    fn lock_asset(&self, symbol: &str, side: Side) -> String {
+        let rules = &self.symbols[symbol];
+        match side {
+            Side::Buy => rules.quote_asset.clone(),
+            Side::Sell => rules.base_asset.clone(),
+        }
+    }
+
+    /// Balance locked by the order, or by its list.
+    fn locked_mut(&mut self, order_id: u64) -> &mut Decimal {
+        let order = self.orders.get_mut(&order_id).unwrap();
+        match self.lists.get_mut(&order.order_list_id) {
+            Some(list) => &mut list.locked,
+            None => &mut order.locked,
+        }
+    }
+
+    fn lock(&mut self, asset: &str, amount: Decimal) {
+        self.unlock(asset, -amount);
+    }
+
+    fn unlock(&mut self, asset: &str, amount: Decimal) {
+        if amount.is_zero() {
+            return;
+        }
+
+        let balance = self.balances.entry(asset.to_owned()).or_default();
+        balance.locked -= amount;
+        balance.free += amount;
+        self.changed_assets.insert(asset.to_owned());
+    }
+}
+
+/// Parse the order parameters, prefixed with `above` or `below` for the
+/// orders of an OCO.
+fn parse_order(params: &[(String, String)], prefix: &str) -> Result<OrderRequest, MockResponse> {
+    let key = |name: &str| match prefix {
+        "" => name.to_owned(),
+        prefix => format!("{}{}{}", prefix, name[..1].to_uppercase(), &name[1..]),
+    };
+
+    let symbol = param(params, "symbol").ok_or_else(|| missing_param("symbol"))?;
+    let side = param(params, "side").ok_or_else(|| missing_param("side"))?;
+    let side = Side::parse(side).ok_or_else(|| illegal_param("side"))?;
+    let order_type_key = key("type");
+    let order_type =
+        param(params, &order_type_key).ok_or_else(|| missing_param(&order_type_key))?;
+    let order_type = OrderType::parse(order_type)
+        .ok_or_else(|| MockResponse::error(400, -1116, "Invalid orderType."))?;
+
+    let time_in_force_key = key("timeInForce");
+    let time_in_force = match param(params, &time_in_force_key) {
+        Some(time_in_force) => TimeInForce::parse(time_in_force)
+            .ok_or_else(|| MockResponse::error(400, -1115, "Invalid timeInForce."))?,
+        None if order_type.has_time_in_force() => return Err(missing_param(&time_in_force_key)),
+        None => TimeInForce::Gtc,
+    };
+
+    let quantity: Option<Decimal> = parse(params, "quantity")?;
+    let quote_order_qty: Option<Decimal> = match order_type {
+        OrderType::Market => parse(params, "quoteOrderQty")?,
+        _ => None,
+    };
+    if quantity.is_none() && quote_order_qty.is_none() {
+        return Err(missing_param("quantity"));
+    }
+
+    let price_key = key("price");
+    let price: Option<Decimal> = parse(params, &price_key)?;
+    if order_type.has_price() && price.is_none() {
+        return Err(missing_param(&price_key));
+    }
+    let stop_price_key = key("stopPrice");
+    let stop_price: Option<Decimal> = parse(params, &stop_price_key)?;
+    if order_type.has_stop_price() && stop_price.is_none() {
+        return Err(missing_param(&stop_price_key));
+    }
+
+    let client_order_id_key = match prefix {
+        "" => "newClientOrderId".to_owned(),
+        prefix => format!("{}ClientOrderId", prefix),
+    };
+
+    Ok(OrderRequest {
+        symbol: symbol.to_owned(),
+        side,
+        order_type,
+        time_in_force,
+        quantity: quantity.unwrap_or_default(),
+        quote_order_qty: quote_order_qty.unwrap_or_default(),
+        price: price.filter(|_| order_type.has_price()).unwrap_or_default(),
+        stop_price: stop_price
+            .filter(|_| order_type.has_stop_price())
+            .unwrap_or_default(),
+        client_order_id: param(params, &client_order_id_key).map(str::to_owned),
+    })
+}
+
+fn parse<T: std::str::FromStr>(
+    params: &[(String, String)],
+    key: &str,
+) -> Result<Option<T>, MockResponse> {
+    param(params, key)
+        .map(|value| value.parse().map_err(|_| illegal_param(key)))
+        .transpose()
+}
+
+fn illegal_param(key: &str) -> MockResponse {
+    MockResponse::error(
+        400,
+        -1100,
+        &format!("Illegal characters found in parameter '{}'.", key),
+    )
+}
+
+/// Whether the last price reaches the stop price of the order.
+fn triggers(side: Side, order_type: OrderType, stop_price: Decimal, last_price: Decimal) -> bool {
+    let stop_loss = matches!(order_type, OrderType::StopLoss | OrderType::StopLossLimit);
+    match (side, stop_loss) {
+        (Side::Buy, true) | (Side::Sell, false) => last_price >= stop_price,
+        (Side::Sell, true) | (Side::Buy, false) => last_price <= stop_price,
+    }
+}
+
+/// Quote cost of buying `qty` on the market, `None` on overflow.
+fn market_cost(market: &Market, qty: Decimal) -> Option<Decimal> {
+    let mut left = qty;
+    let mut cost = Decimal::ZERO;
+    for (price, available) in market.liquidity(Side::Buy, None) {
+        let filled = left.min(available);
+        cost = cost.checked_add(price.checked_mul(filled)?)?;
+        left -= filled;
+        if left.is_zero() {
+            break;
+        }
+    }
+
+    Some(cost)
+}
+
+fn is_multiple(value: Decimal, step: Decimal) -> bool {
+    step.is_zero() || (value % step).is_zero()
+}
+
+/// Round `qty` down to the step size, or to 8 decimals without step size.
+///
+/// Returns `None` on overflow.
+fn round_down(qty: Decimal, step_size: Decimal) -> Option<Decimal> {
+    match step_size.is_zero() {
+        true => Some(qty.round_dp_with_strategy(8, RoundingStrategy::ToZero)),
+        false => qty.checked_div(step_size)?.floor().checked_mul(step_size),
+    }
+}
+
+fn client_order_id() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(22)
+        .map(char::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchingEngine, SymbolRules};
+    use crate::http::Method;
+    use crate::market_stream::event::{MarketEvent, PartialDepthEvent, TradeEvent};
+    use rust_decimal::Decimal;
+    use rust_decimal_macros::dec;
+    use serde_json::Value;
+
+    fn send(
+        engine: &mut MatchingEngine,
+        method: Method,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Value {
+        let params: Vec<(String, String)> = params
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        let response = engine.handle(&method, path, &params).unwrap();
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    fn trade(symbol: &str, price: Decimal, qty: Decimal) -> MarketEvent {
+        MarketEvent::Trade(TradeEvent {
+            event_time: 1,
+            symbol: symbol.to_owned(),
+            trade_id: 1,
+            price,
+            qty,
+            trade_time: 1,
+            is_buyer_maker: true,
+        })
+    }
+
+    #[test]
+    fn mock_server_matching_engine_test() {
+        let mut engine = MatchingEngine::new()
+            .symbol(
+                "BNBUSDT",
+                SymbolRules::new("BNB", "USDT")
+                    .tick_size(dec!(0.1))
+                    .step_size(dec!(0.01))
+                    .min_notional(dec!(5)),
+            )
+            .balance("USDT", dec!(1000));
+        engine.feed(&trade("BNBUSDT", dec!(300), dec!(1)));
+
+        let order = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BNBUSDT"),
+                ("side", "BUY"),
+                ("type", "MARKET"),
+                ("quantity", "1"),
+            ],
+        );
+        assert_eq!(order["status"], "FILLED");
+        assert_eq!(order["fills"][0]["price"], "300");
+        assert_eq!(engine.balances()["USDT"].free, dec!(700));
+        assert_eq!(engine.balances()["BNB"].free, dec!(0.999));
+
+        for (price, quantity, code) in [
+            ("300.05", "0.1", -1013),
+            ("300", "0.001", -1013),
+            ("300", "0.01", -1013),
+            ("79228162514264337593543950335", "2", -1013),
+            ("300", "10", -2010),
+        ] {
+            let err = send(
+                &mut engine,
+                Method::Post,
+                "/api/v3/order",
+                &[
+                    ("symbol", "BNBUSDT"),
+                    ("side", "BUY"),
+                    ("type", "LIMIT"),
+                    ("timeInForce", "GTC"),
+                    ("price", price),
+                    ("quantity", quantity),
+                ],
+            );
+            assert_eq!(err["code"], code);
+        }
+        let err = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BNBUSDT"),
+                ("side", "BUY"),
+                ("type", "MARKET"),
+                ("quantity", "79228162514264337593543950335"),
+            ],
+        );
+        assert_eq!(err["code"], -1013);
+
+        let order = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BNBUSDT"),
+                ("side", "SELL"),
+                ("type", "LIMIT"),
+                ("timeInForce", "GTC"),
+                ("price", "310"),
+                ("quantity", "0.5"),
+            ],
+        );
+        assert_eq!(order["status"], "NEW");
+        assert_eq!(engine.balances()["BNB"].locked, dec!(0.5));
+
+        engine.take_events();
+        engine.feed(&trade("BNBUSDT", dec!(310), dec!(0.2)));
+        let events = engine.take_events();
+        assert_eq!(events[0]["x"], "TRADE");
+        assert_eq!(events[0]["X"], "PARTIALLY_FILLED");
+        assert_eq!(events[0]["m"], true);
+        assert_eq!(events[1]["e"], "outboundAccountPosition");
+        assert_eq!(engine.balances()["USDT"].free, dec!(761.938));
+
+        let order_id = order["orderId"].to_string();
+        let canceled = send(
+            &mut engine,
+            Method::Delete,
+            "/api/v3/order",
+            &[("symbol", "BNBUSDT"), ("orderId", &order_id)],
+        );
+        assert_eq!(canceled["status"], "CANCELED");
+        assert_eq!(canceled["executedQty"], "0.2");
+        assert_eq!(engine.balances()["BNB"].locked, dec!(0));
+        assert_eq!(engine.balances()["BNB"].free, dec!(0.799));
+
+        let order = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BNBUSDT"),
+                ("side", "SELL"),
+                ("type", "STOP_LOSS"),
+                ("stopPrice", "290"),
+                ("quantity", "0.5"),
+            ],
+        );
+        assert_eq!(order["orderId"], 3);
+        engine.feed(&trade("BNBUSDT", dec!(295), dec!(1)));
+        let open_orders = send(&mut engine, Method::Get, "/api/v3/openOrders", &[]);
+        assert_eq!(open_orders[0]["isWorking"], false);
+
+        engine.feed(&trade("BNBUSDT", dec!(289), dec!(1)));
+        let order = send(
+            &mut engine,
+            Method::Get,
+            "/api/v3/order",
+            &[("symbol", "BNBUSDT"), ("orderId", "3")],
+        );
+        assert_eq!(order["status"], "FILLED");
+        assert_eq!(order["cummulativeQuoteQty"], "144.5");
+
+        let trades = send(
+            &mut engine,
+            Method::Get,
+            "/api/v3/myTrades",
+            &[("symbol", "BNBUSDT")],
+        );
+        assert_eq!(trades.as_array().unwrap().len(), 3);
+        assert_eq!(trades[1]["isMaker"], true);
+    }
+
+    #[test]
+    fn mock_server_matching_engine_oco_test() {
+        let mut engine = MatchingEngine::new()
+            .symbol("BTCUSDT", SymbolRules::new("BTC", "USDT"))
+            .balance("BTC", dec!(1))
+            .balance("USDT", dec!(1000))
+            .commission(dec!(0));
+        engine.feed_partial_depth(
+            "BTCUSDT",
+            &PartialDepthEvent {
+                last_update_id: 1,
+                bids: vec![(dec!(100), dec!(1))],
+                asks: vec![(dec!(101), dec!(1))],
+            },
+        );
+        engine.feed(&trade("BTCUSDT", dec!(100.5), dec!(1)));
+
+        let err = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BTCUSDT"),
+                ("side", "BUY"),
+                ("type", "LIMIT_MAKER"),
+                ("price", "101"),
+                ("quantity", "1"),
+            ],
+        );
+        assert_eq!(err["code"], -2010);
+
+        let order = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/order",
+            &[
+                ("symbol", "BTCUSDT"),
+                ("side", "BUY"),
+                ("type", "LIMIT"),
+                ("timeInForce", "FOK"),
+                ("price", "101"),
+                ("quantity", "2"),
+            ],
+        );
+        assert_eq!(order["status"], "EXPIRED");
+        assert_eq!(engine.balances()["USDT"].free, dec!(1000));
+
+        let list = send(
+            &mut engine,
+            Method::Post,
+            "/api/v3/orderList/oco",
+            &[
+                ("symbol", "BTCUSDT"),
+                ("side", "SELL"),
+                ("quantity", "1"),
+                ("aboveType", "LIMIT_MAKER"),
+                ("abovePrice", "110"),
+                ("belowType", "STOP_LOSS_LIMIT"),
+                ("belowPrice", "94"),
+                ("belowStopPrice", "95"),
+                ("belowTimeInForce", "GTC"),
+            ],
+        );
+        assert_eq!(list["listOrderStatus"], "EXECUTING");
+        assert_eq!(engine.balances()["BTC"].locked, dec!(1));
+
+        engine.take_events();
+        engine.feed(&trade("BTCUSDT", dec!(110), dec!(0.4)));
+        let events = engine.take_events();
+        assert_eq!(events[0]["X"], "PARTIALLY_FILLED");
+        assert_eq!(events[1]["X"], "EXPIRED");
+        assert_eq!(engine.balances()["BTC"].locked, dec!(0.6));
+
+        engine.feed(&trade("BTCUSDT", dec!(110), dec!(1)));
+        let events = engine.take_events();
+        assert_eq!(events[0]["X"], "FILLED");
+        assert_eq!(events[1]["e"], "listStatus");
+        assert_eq!(events[1]["L"], "ALL_DONE");
+        assert_eq!(engine.balances()["BTC"].free, dec!(0));
+        assert_eq!(engine.balances()["BTC"].locked, dec!(0));
+        assert_eq!(engine.balances()["USDT"].free, dec!(1110));
+    }
+}
@@ -0,0 +1,324 @@
+use rust_decimal::Decimal;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Side {
+    Buy,
+    Sell,
+}
+
+impl Side {
+    pub(super) fn parse(side: &str) -> Option<Self> {
+        match side {
+            "BUY" => Some(Side::Buy),
+            "SELL" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            Side::Buy => "BUY",
+            Side::Sell => "SELL",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum OrderType {
+    Limit,
+    LimitMaker,
+    Market,
+    StopLoss,
+    StopLossLimit,
+    TakeProfit,
+    TakeProfitLimit,
+}
+
+impl OrderType {
+    pub(super) fn parse(order_type: &str) -> Option<Self> {
+        match order_type {
+            "LIMIT" => Some(OrderType::Limit),
+            "LIMIT_MAKER" => Some(OrderType::LimitMaker),
+            "MARKET" => Some(OrderType::Market),
+            "STOP_LOSS" => Some(OrderType::StopLoss),
+            "STOP_LOSS_LIMIT" => Some(OrderType::StopLossLimit),
+            "TAKE_PROFIT" => Some(OrderType::TakeProfit),
+            "TAKE_PROFIT_LIMIT" => Some(OrderType::TakeProfitLimit),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            OrderType::Limit => "LIMIT",
+            OrderType::LimitMaker => "LIMIT_MAKER",
+            OrderType::Market => "MARKET",
+            OrderType::StopLoss => "STOP_LOSS",
+            OrderType::StopLossLimit => "STOP_LOSS_LIMIT",
+            OrderType::TakeProfit => "TAKE_PROFIT",
+            OrderType::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+        }
+    }
+
+    /// Whether the order has a limit `price`.
+    pub(super) fn has_price(self) -> bool {
+        matches!(
+            self,
+            OrderType::Limit
+                | OrderType::LimitMaker
+                | OrderType::StopLossLimit
+                | OrderType::TakeProfitLimit
+        )
+    }
+
+    /// Whether the order waits for the last price to reach `stopPrice`.
+    pub(super) fn has_stop_price(self) -> bool {
+        matches!(
+            self,
+            OrderType::StopLoss
+                | OrderType::StopLossLimit
+                | OrderType::TakeProfit
+                | OrderType::TakeProfitLimit
+        )
+    }
+
+    pub(super) fn has_time_in_force(self) -> bool {
+        matches!(
+            self,
+            OrderType::Limit | OrderType::StopLossLimit | OrderType::TakeProfitLimit
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum TimeInForce {
+    Gtc,
+    Ioc,
+    Fok,
+}
+
+impl TimeInForce {
+    pub(super) fn parse(time_in_force: &str) -> Option<Self> {
+        match time_in_force {
+            "GTC" => Some(TimeInForce::Gtc),
+            "IOC" => Some(TimeInForce::Ioc),
+            "FOK" => Some(TimeInForce::Fok),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            TimeInForce::Gtc => "GTC",
+            TimeInForce::Ioc => "IOC",
+            TimeInForce::Fok => "FOK",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Status {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Expired,
+}
+
+impl Status {
+    pub(super) fn as_str(self) -> &'static str {
+        match self {
+            Status::New => "NEW",
+            Status::PartiallyFilled => "PARTIALLY_FILLED",
+            Status::Filled => "FILLED",
+            Status::Canceled => "CANCELED",
+            Status::Expired => "EXPIRED",
+        }
+    }
+
+    pub(super) fn is_open(self) -> bool {
+        matches!(self, Status::New | Status::PartiallyFilled)
+    }
+}
+
+/// Trade of an order.
+#[derive(Debug, Clone)]
+pub(super) struct Fill {
+    pub(super) trade_id: u64,
+    pub(super) price: Decimal,
+    pub(super) qty: Decimal,
+    pub(super) commission: Decimal,
+    pub(super) commission_asset: String,
+    pub(super) time: u64,
+    pub(super) is_maker: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct Order {
+    pub(super) symbol: String,
+    pub(super) order_id: u64,
+    pub(super) order_list_id: i64,
+    pub(super) client_order_id: String,
+    pub(super) side: Side,
+    pub(super) order_type: OrderType,
+    pub(super) time_in_force: TimeInForce,
+    pub(super) price: Decimal,
+    /// Zero for market orders sent with `quoteOrderQty`, until filled.
+    pub(super) quantity: Decimal,
+    pub(super) quote_order_qty: Decimal,
+    pub(super) stop_price: Decimal,
+    pub(super) executed_qty: Decimal,
+    pub(super) cummulative_quote_qty: Decimal,
+    pub(super) status: Status,
+    /// Whether the stop price was reached, always `true` without stop price.
+    pub(super) triggered: bool,
+    /// Balance locked by the order, the order list holds the lock of its orders.
+    pub(super) locked: Decimal,
+    pub(super) time: u64,
+    pub(super) update_time: u64,
+    pub(super) fills: Vec<Fill>,
+}
+
+impl Order {
+    /// Quantity left to fill, in base asset.
+    pub(super) fn remaining(&self) -> Decimal {
+        self.quantity - self.executed_qty
+    }
+
+    /// Quote quantity left to spend by a market order sent with `quoteOrderQty`.
+    pub(super) fn remaining_quote(&self) -> Decimal {
+        self.quote_order_qty - self.cummulative_quote_qty
+    }
+
+    pub(super) fn is_quote_order(&self) -> bool {
+        !self.quote_order_qty.is_zero()
+    }
+
+    fn working_time(&self) -> i64 {
+        match self.triggered {
+            true => self.update_time as i64,
+            false => -1,
+        }
+    }
+
+    /// Order as returned by `GET /api/v3/order`.
+    pub(super) fn to_json(&self) -> Value {
+        json!({
+            "symbol": self.symbol,
+            "orderId": self.order_id,
+            "orderListId": self.order_list_id,
+            "clientOrderId": self.client_order_id,
+            "price": self.price.to_string(),
+            "origQty": self.quantity.to_string(),
+            "executedQty": self.executed_qty.to_string(),
+            "cummulativeQuoteQty": self.cummulative_quote_qty.to_string(),
+            "status": self.status.as_str(),
+            "timeInForce": self.time_in_force.as_str(),
+            "type": self.order_type.as_str(),
+            "side": self.side.as_str(),
+            "stopPrice": self.stop_price.to_string(),
+            "icebergQty": "0",
+            "time": self.time,
+            "updateTime": self.update_time,
+            "isWorking": self.triggered,
+            "workingTime": self.working_time(),
+            "origQuoteOrderQty": self.quote_order_qty.to_string(),
+            "selfTradePreventionMode": "NONE",
+        })
+    }
+
+    /// Order as returned by `POST /api/v3/order` with `newOrderRespType`.
+    pub(super) fn to_response(&self, response_type: &str) -> Value {
+        let mut response = json!({
+            "symbol": self.symbol,
+            "orderId": self.order_id,
+            "orderListId": self.order_list_id,
+            "clientOrderId": self.client_order_id,
+            "transactTime": self.time,
+        });
+        if response_type == "ACK" {
+            return response;
+        }
+
+        let order = self.to_json();
+        for key in [
+            "price",
+            "origQty",
+            "executedQty",
+            "cummulativeQuoteQty",
+            "status",
+            "timeInForce",
+            "type",
+            "side",
+            "workingTime",
+            "selfTradePreventionMode",
+        ] {
+            response[key] = order[key].clone();
+        }
+        if self.order_type.has_stop_price() {
+            response["stopPrice"] = order["stopPrice"].clone();
+        }
+        if response_type == "FULL" {
+            response["fills"] = self
+                .fills
+                .iter()
+                .map(|fill| {
+                    json!({
+                        "price": fill.price.to_string(),
+                        "qty": fill.qty.to_string(),
+                        "commission": fill.commission.to_string(),
+                        "commissionAsset": fill.commission_asset,
+                        "tradeId": fill.trade_id,
+                    })
+                })
+                .collect();
+        }
+
+        response
+    }
+
+    /// `executionReport` user data event.
+    pub(super) fn execution_report(
+        &self,
+        execution_type: &str,
+        original_client_order_id: &str,
+        fill: Option<&Fill>,
+        event_time: u64,
+    ) -> Value {
+        json!({
+            "e": "executionReport",
+            "E": event_time,
+            "s": self.symbol,
+            "c": self.client_order_id,
+            "S": self.side.as_str(),
+            "o": self.order_type.as_str(),
+            "f": self.time_in_force.as_str(),
+            "q": self.quantity.to_string(),
+            "p": self.price.to_string(),
+            "P": self.stop_price.to_string(),
+            "F": "0",
+            "g": self.order_list_id,
+            "C": original_client_order_id,
+            "x": execution_type,
+            "X": self.status.as_str(),
+            "r": "NONE",
+            "i": self.order_id,
+            "l": fill.map_or(Decimal::ZERO, |fill| fill.qty).to_string(),
+            "z": self.executed_qty.to_string(),
+            "L": fill.map_or(Decimal::ZERO, |fill| fill.price).to_string(),
+            "n": fill.map_or(Decimal::ZERO, |fill| fill.commission).to_string(),
+            "N": fill.map(|fill| fill.commission_asset.clone()),
+            "T": self.update_time,
+            "t": fill.map_or(-1, |fill| fill.trade_id as i64),
+            "w": self.status.is_open() && self.triggered,
+            "m": fill.map_or(false, |fill| fill.is_maker),
+            "O": self.time,
+            "Z": self.cummulative_quote_qty.to_string(),
+            "Y": fill.map_or(Decimal::ZERO, |fill| fill.price * fill.qty).to_string(),
+            "Q": self.quote_order_qty.to_string(),
+            "W": self.working_time(),
+            "V": "NONE",
+        })
+    }
+}
@@ -0,0 +1,195 @@
+use crate::http::Method;
+use std::time::Duration;
+
+/// Market data endpoints accessible without API key.
+const PUBLIC_PATHS: &[&str] = &[
+    "/api/v3/ping",
+    "/api/v3/time",
+    "/api/v3/exchangeInfo",
+    "/api/v3/depth",
+    "/api/v3/trades",
+    "/api/v3/historicalTrades",
+    "/api/v3/aggTrades",
+    "/api/v3/klines",
+    "/api/v3/uiKlines",
+    "/api/v3/avgPrice",
+    "/api/v3/ticker",
+    "/api/v3/ticker/24hr",
+    "/api/v3/ticker/tradingDay",
+    "/api/v3/ticker/price",
+    "/api/v3/ticker/bookTicker",
+    "/sapi/v1/system/status",
+];
+
+/// Listen key endpoints requiring an API key only.
+pub(super) const LISTEN_KEY_PATHS: &[&str] = &[
+    "/api/v3/userDataStream",
+    "/sapi/v1/userDataStream",
+    "/sapi/v1/userDataStream/isolated",
+];
+
+/// Authentication required by an endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Security {
+    /// Public endpoint.
+    None,
+    /// The `X-MBX-APIKEY` header must hold a known API key.
+    ApiKey,
+    /// The request must also carry a valid `timestamp` and `signature`.
+    Signed,
+}
+
+impl Security {
+    /// Security of `path` on the Binance servers.
+    ///
+    /// Market data endpoints are public, listen key endpoints require an API
+    /// key and other `/api` and `/sapi` endpoints are signed.
+    pub fn of(path: &str) -> Self {
+        if PUBLIC_PATHS.contains(&path) {
+            Security::None
+        } else if LISTEN_KEY_PATHS.contains(&path) {
+            Security::ApiKey
+        } else if path.starts_with("/api/") || path.starts_with("/sapi/") {
+            Security::Signed
+        } else {
+            Security::None
+        }
+    }
+}
+
+/// Response sent by the mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: vec![],
+            body: body.into(),
+        }
+    }
+
+    /// Binance error `{"code":<code>,"msg":<msg>}`.
+    pub fn error(status: u16, code: i64, msg: &str) -> Self {
+        let body = serde_json::json!({ "code": code, "msg": msg });
+        Self::new(status, body.to_string())
+    }
+}
+
+/// Canned response to the requests matching a method, a path and parameters.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::{http::Method, mock_server::Mock};
+/// use std::time::Duration;
+///
+/// // Reject the first order with a timestamp error, after 100ms.
+/// let mock = Mock::new(Method::Post, "/api/v3/order")
+///     .param("symbol", "BNBUSDT")
+///     .error(400, -1021, "Timestamp for this request is outside of the recvWindow.")
+///     .latency(Duration::from_millis(100))
+///     .times(1);
+///
+/// // Then rate limit the requests.
+/// let mock = Mock::new(Method::Post, "/api/v3/order")
+///     .error(429, -1003, "Too many requests.")
+///     .header("Retry-After", "1");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Mock {
+    pub(super) method: Method,
+    pub(super) path: String,
+    pub(super) params: Vec<(String, String)>,
+    pub(super) security: Security,
+    pub(super) response: MockResponse,
+    pub(super) latency: Duration,
+    pub(super) times: Option<usize>,
+}
+
+impl Mock {
+    /// Mock replying `{}` to the requests of `method` on `path`.
+    pub fn new(method: Method, path: &str) -> Self {
+        Self {
+            method,
+            path: path.to_owned(),
+            params: vec![],
+            security: Security::of(path),
+            response: MockResponse::new(200, "{}"),
+            latency: Duration::ZERO,
+            times: None,
+        }
+    }
+
+    /// Only match the requests with the parameter `key` set to `value`.
+    pub fn param(mut self, key: &str, value: &str) -> Self {
+        self.params.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Authentication required, defaults to [`Security::of`] the path.
+    pub fn security(mut self, security: Security) -> Self {
+        self.security = security;
+        self
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.response.status = status;
+        self
+    }
+
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.response
+            .headers
+            .push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.response.body = body.into();
+        self
+    }
+
+    /// Reply with the Binance error `code`.
+    ///
+    /// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/errors)
+    pub fn error(mut self, status: u16, code: i64, msg: &str) -> Self {
+        let headers = std::mem::take(&mut self.response.headers);
+        self.response = MockResponse::error(status, code, msg);
+        self.response.headers = headers;
+        self
+    }
+
+    /// Delay before replying, added to the latency of the server.
+    pub fn latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+
+    /// Number of requests replied before the mock is removed, unlimited by default.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = Some(times);
+        self
+    }
+
+    pub(super) fn matches(&self, method: &Method, path: &str, params: &[(String, String)]) -> bool {
+        self.method == *method
+            && self.path == path
+            && self.params.iter().all(|param| params.contains(param))
+    }
+}
+
+/// Request received by the mock server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceivedRequest {
+    pub method: Method,
+    pub path: String,
+    /// Query and body parameters, including `timestamp` and `signature`.
+    pub params: Vec<(String, String)>,
+    pub api_key: Option<String>,
+}
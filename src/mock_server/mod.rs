@@ -0,0 +1,636 @@
+//! In-process mock of the Binance servers, to test bots offline.
+//!
+//! [`MockServer`] serves the REST API and the websocket streams on local ports:
+//!
+//! * Requests are authenticated like on the Binance servers: API keys and
+//!   signatures are validated against the [`Credentials`] registered with
+//!   [`MockServer::credentials`], and signed requests must be sent within
+//!   their `recvWindow`.
+//! * Responses are scripted with [`Mock`]s, including errors and latency.
+//!   Without mock, `ping`, `time`, the listen key endpoints, `exchangeInfo`,
+//!   `depth`, `klines`, `avgPrice`, the tickers and `account` get default
+//!   responses describing a static `BTCUSDT`, `ETHUSDT` and `BNBUSDT` market;
+//!   other requests are rejected with a `404` error.
+//! * Websocket clients connect to `/ws/<streams>` or `/stream?streams=<streams>`
+//!   and (un)subscribe with control messages. Events are pushed to the
+//!   subscribed connections with [`MockServer::push`].
+//! * Orders are paper traded by a [`MatchingEngine`] installed with
+//!   [`MockServer::paper_trading`], fed with the market events pushed to the
+//!   server. Its user data events are pushed to the listen key connections.
+//!
+//! WebSocket API requests are not served.
+//!
+//! # Example
+//!
+//! ```
+//! use binance_spot_connector_rust::{
+//!     http::{Credentials, Method},
+//!     hyper::BinanceHttpClient,
+//!     market,
+//!     mock_server::{Mock, MockServer},
+//! };
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let server = MockServer::start().await.expect("Failed to start server");
+//!     server.credentials(Credentials::from_hmac("api-key", "api-secret"));
+//!     server.mock(
+//!         Mock::new(Method::Get, "/api/v3/avgPrice")
+//!             .param("symbol", "BNBUSDT")
+//!             .body(r#"{"mins":5,"price":"9.35751834","closeTime":1694061154503}"#),
+//!     );
+//!
+//!     let client = BinanceHttpClient::default().environment(server.environment());
+//!     let data = client
+//!         .send(market::avg_price("BNBUSDT"))
+//!         .await
+//!         .expect("Request failed")
+//!         .into_body_str()
+//!         .await
+//!         .expect("Failed to read response body");
+//!
+//!     assert!(data.contains("9.35751834"));
+//! }
+//! ```
+
+mod canned;
+mod engine;
+mod mock;
+mod rest;
+mod stream;
+
+pub use engine::{Balance, MatchingEngine, SymbolRules};
+pub use mock::*;
+
+use crate::environment::Environment;
+use crate::http::Credentials;
+use crate::market_stream::event::MarketEvent;
+use hyper::service::{make_service_fn, service_fn};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::convert::Infallible;
+use std::io;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use stream::StreamConnection;
+use tokio::sync::watch;
+
+#[derive(Default)]
+struct State {
+    credentials: Vec<Credentials>,
+    mocks: Vec<Mock>,
+    latency: Duration,
+    requests: Vec<ReceivedRequest>,
+    listen_keys: HashSet<String>,
+    connections: HashMap<u64, StreamConnection>,
+    next_connection: u64,
+    engine: Option<MatchingEngine>,
+}
+
+/// Lock the state, even if a handler panicked while holding it: the mock
+/// server must keep serving, and must not panic when dropped while unwinding.
+fn lock(state: &Mutex<State>) -> MutexGuard<'_, State> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl State {
+    fn push(&self, stream: &str, data: &serde_json::Value) -> usize {
+        self.connections
+            .values()
+            .filter_map(|connection| {
+                let message = connection.event(stream, data)?;
+                connection.sender.send(message).ok()
+            })
+            .count()
+    }
+
+    /// Push the user data events of the engine to the listen key connections.
+    fn push_engine_events(&mut self) {
+        let events = match self.engine.as_mut() {
+            Some(engine) => engine.take_events(),
+            None => return,
+        };
+        for listen_key in &self.listen_keys {
+            for event in &events {
+                self.push(listen_key, event);
+            }
+        }
+    }
+}
+
+/// Mock of the Binance REST API and websocket streams.
+///
+/// The server stops when dropped.
+pub struct MockServer {
+    state: Arc<Mutex<State>>,
+    rest_addr: SocketAddr,
+    stream_addr: SocketAddr,
+    shutdown: watch::Sender<bool>,
+}
+
+impl MockServer {
+    /// Start the server on the current Tokio runtime.
+    pub async fn start() -> io::Result<Self> {
+        let (server, rest, stream) = Self::bind()?;
+        tokio::spawn(serve(
+            rest,
+            stream,
+            server.state.clone(),
+            server.shutdown.subscribe(),
+        ));
+
+        Ok(server)
+    }
+
+    /// Start the server on a background thread, for blocking clients.
+    pub fn start_blocking() -> io::Result<Self> {
+        let (server, rest, stream) = Self::bind()?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let state = server.state.clone();
+        let shutdown = server.shutdown.subscribe();
+        std::thread::spawn(move || runtime.block_on(serve(rest, stream, state, shutdown)));
+
+        Ok(server)
+    }
+
+    fn bind() -> io::Result<(Self, TcpListener, TcpListener)> {
+        let rest = TcpListener::bind("127.0.0.1:0")?;
+        let stream = TcpListener::bind("127.0.0.1:0")?;
+        rest.set_nonblocking(true)?;
+        stream.set_nonblocking(true)?;
+
+        let server = Self {
+            state: Arc::new(Mutex::new(State::default())),
+            rest_addr: rest.local_addr()?,
+            stream_addr: stream.local_addr()?,
+            shutdown: watch::channel(false).0,
+        };
+
+        Ok((server, rest, stream))
+    }
+
+    /// Base url of the REST API, e.g. `http://127.0.0.1:49152`.
+    pub fn rest_url(&self) -> String {
+        format!("http://{}", self.rest_addr)
+    }
+
+    /// Base url of the websocket streams, e.g. `ws://127.0.0.1:49153`.
+    pub fn stream_url(&self) -> String {
+        format!("ws://{}", self.stream_addr)
+    }
+
+    /// [`Environment::Custom`] pointing the clients to the server.
+    pub fn environment(&self) -> Environment {
+        Environment::Custom {
+            rest_url: self.rest_url(),
+            stream_url: self.stream_url(),
+            ws_api_url: format!("{}/ws-api/v3", self.stream_url()),
+        }
+    }
+
+    /// Accept the API key of `credentials` and validate signatures with its secret.
+    pub fn credentials(&self, credentials: Credentials) {
+        lock(&self.state).credentials.push(credentials);
+    }
+
+    /// Reply to the matching requests with `mock`.
+    ///
+    /// The most recent mock takes precedence over the previous mocks
+    /// matching a request.
+    pub fn mock(&self, mock: Mock) {
+        lock(&self.state).mocks.push(mock);
+    }
+
+    /// Delay before replying to any request.
+    pub fn latency(&self, latency: Duration) {
+        lock(&self.state).latency = latency;
+    }
+
+    /// Requests received so far, in order.
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        lock(&self.state).requests.clone()
+    }
+
+    /// Push `data` to the connections subscribed to `stream`, wrapped as
+    /// `{"stream":<stream>,"data":<data>}` on combined connections.
+    ///
+    /// User data events are pushed with the listen key as `stream`.
+    ///
+    /// Returns the number of connections the event was pushed to.
+    ///
+    /// Market events are also fed to the paper trading engine.
+    pub fn push(&self, stream: &str, data: serde_json::Value) -> usize {
+        let mut state = lock(&self.state);
+        let pushed = state.push(stream, &data);
+        if let (Some(engine), Ok(Some(event))) =
+            (state.engine.as_mut(), MarketEvent::from_value(&data))
+        {
+            match &event {
+                // Partial depth events do not carry the symbol.
+                MarketEvent::PartialDepth(depth) => {
+                    let symbol = stream.split('@').next().unwrap_or_default();
+                    engine.feed_partial_depth(&symbol.to_uppercase(), depth);
+                }
+                event => engine.feed(event),
+            }
+            state.push_engine_events();
+        }
+
+        pushed
+    }
+
+    /// Paper trade the orders with `engine`, replacing the previous engine.
+    ///
+    /// The trading endpoints served by the engine are answered without mock,
+    /// a matching [`Mock`] still takes precedence.
+    pub fn paper_trading(&self, engine: MatchingEngine) {
+        lock(&self.state).engine = Some(engine);
+    }
+
+    /// Feed a market event to the paper trading engine without pushing it to
+    /// the websocket connections, e.g. an event of a live stream.
+    pub fn feed(&self, event: &MarketEvent) {
+        let mut state = lock(&self.state);
+        if let Some(engine) = state.engine.as_mut() {
+            engine.feed(event);
+            state.push_engine_events();
+        }
+    }
+
+    /// Balances of the paper trading account, empty without engine.
+    pub fn balances(&self) -> BTreeMap<String, Balance> {
+        let state = lock(&self.state);
+        state
+            .engine
+            .as_ref()
+            .map(|engine| engine.balances().clone())
+            .unwrap_or_default()
+    }
+
+    /// Number of connections subscribed to `stream`.
+    pub fn subscribers(&self, stream: &str) -> usize {
+        let state = lock(&self.state);
+        state
+            .connections
+            .values()
+            .filter(|connection| connection.streams.contains(stream))
+            .count()
+    }
+
+    /// Close all websocket connections.
+    pub fn disconnect_streams(&self) {
+        lock(&self.state).connections.clear();
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        let _ = self.shutdown.send(true);
+        self.disconnect_streams();
+    }
+}
+
+async fn serve(
+    rest: TcpListener,
+    stream: TcpListener,
+    state: Arc<Mutex<State>>,
+    shutdown: watch::Receiver<bool>,
+) {
+    let rest_state = state.clone();
+    let make_service = make_service_fn(move |_| {
+        let state = rest_state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                rest::handle(state.clone(), request)
+            }))
+        }
+    });
+    let rest = match hyper::Server::from_tcp(rest) {
+        Ok(server) => {
+            let mut shutdown = shutdown.clone();
+            server
+                .serve(make_service)
+                .with_graceful_shutdown(async move {
+                    let _ = shutdown.changed().await;
+                })
+        }
+        Err(err) => {
+            log::error!("Failed to start mock REST server: {}", err);
+            return;
+        }
+    };
+    let stream = match tokio::net::TcpListener::from_std(stream) {
+        Ok(listener) => stream::serve(listener, state, shutdown),
+        Err(err) => {
+            log::error!("Failed to start mock websocket server: {}", err);
+            return;
+        }
+    };
+
+    let (rest, _) = tokio::join!(rest, stream);
+    if let Err(err) = rest {
+        log::error!("Mock REST server failed: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MatchingEngine, Mock, MockServer, SymbolRules};
+    use crate::http::{error::ClientError, Credentials, Method};
+    use crate::hyper::{BinanceHttpClient, Error};
+    use crate::market::{self, klines::KlineInterval};
+    use crate::market_stream::trade::TradeStream;
+    use crate::tokio_tungstenite::BinanceWebSocketClient;
+    use crate::user_data_stream::event::UserDataEvent;
+    use crate::websocket::Event;
+    use crate::{stream, trade};
+    use futures_util::StreamExt;
+    use rust_decimal_macros::dec;
+    use std::time::{Duration, Instant};
+
+    #[tokio::test]
+    async fn mock_server_rest_test() {
+        let server = MockServer::start().await.unwrap();
+        server.credentials(Credentials::from_hmac("api-key", "api-secret"));
+        server.mock(
+            Mock::new(Method::Post, "/api/v3/order")
+                .body(r#"{"orderId":1}"#)
+                .latency(Duration::from_millis(50)),
+        );
+        server.mock(
+            Mock::new(Method::Post, "/api/v3/order")
+                .error(
+                    400,
+                    -1021,
+                    "Timestamp for this request is outside of the recvWindow.",
+                )
+                .times(1),
+        );
+
+        let client = BinanceHttpClient::default()
+            .environment(server.environment())
+            .credentials(Credentials::from_hmac("api-key", "api-secret"));
+        let new_order =
+            || trade::new_order("BNBUSDT", trade::order::Side::Buy, "MARKET").quantity(dec!(1));
+
+        match client
+            .send(new_order())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+        {
+            Err(Error::Client(ClientError::Structured(err))) => assert_eq!(err.data.code, -1021),
+            _ => panic!("Invalid response"),
+        }
+
+        let start = Instant::now();
+        let data = client
+            .send(new_order())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        assert_eq!(data, r#"{"orderId":1}"#);
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        let client = client.credentials(Credentials::from_hmac("api-key", "wrong-secret"));
+        match client
+            .send(new_order())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+        {
+            Err(Error::Client(ClientError::Structured(err))) => assert_eq!(err.data.code, -1022),
+            _ => panic!("Invalid response"),
+        }
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].path, "/api/v3/order");
+        assert_eq!(requests[0].api_key.as_deref(), Some("api-key"));
+    }
+
+    #[tokio::test]
+    async fn mock_server_canned_responses_test() {
+        let server = MockServer::start().await.unwrap();
+        server.credentials(Credentials::from_hmac("api-key", "api-secret"));
+        let client = BinanceHttpClient::default()
+            .environment(server.environment())
+            .credentials(Credentials::from_hmac("api-key", "api-secret"));
+        let get = |request: crate::http::request::Request| async {
+            let data = client.send(request).await?.into_body_str().await?;
+            Ok::<_, Error>(serde_json::from_str::<serde_json::Value>(&data).unwrap())
+        };
+
+        let info = get(market::exchange_info().symbol("BNBUSDT").into())
+            .await
+            .unwrap();
+        assert_eq!(info["symbols"][0]["symbol"], "BNBUSDT");
+        let depth = get(market::depth("BTCUSDT").limit(5).into()).await.unwrap();
+        assert_eq!(depth["bids"][0][0], "59999.99");
+        assert_eq!(depth["asks"].as_array().unwrap().len(), 5);
+        let klines = get(market::klines("ETHUSDT", KlineInterval::Minutes1)
+            .start_time(60_000)
+            .limit(3)
+            .into())
+        .await
+        .unwrap();
+        assert_eq!(klines[2][0], 180_000);
+        let tickers = get(market::ticker_price().into()).await.unwrap();
+        assert_eq!(tickers.as_array().unwrap().len(), 3);
+        let account = get(trade::account().into()).await.unwrap();
+        assert_eq!(account["balances"][3]["asset"], "USDT");
+
+        match get(market::depth("XYZUSDT").into()).await {
+            Err(Error::Client(ClientError::Structured(err))) => assert_eq!(err.data.code, -1121),
+            _ => panic!("Invalid response"),
+        }
+
+        // Mocks take precedence.
+        server.mock(Mock::new(Method::Get, "/api/v3/ticker/price").body("[]"));
+        let tickers = get(market::ticker_price().into()).await.unwrap();
+        assert!(tickers.as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn mock_server_listen_key_test() {
+        let server = MockServer::start().await.unwrap();
+        server.credentials(Credentials::from_hmac("api-key", "api-secret"));
+
+        let client = BinanceHttpClient::default().environment(server.environment());
+        match client
+            .send(stream::new_listen_key())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+        {
+            Err(Error::Client(ClientError::Structured(err))) => assert_eq!(err.data.code, -2014),
+            _ => panic!("Invalid response"),
+        }
+
+        let client = client.credentials(Credentials::from_hmac("api-key", "api-secret"));
+        let data = client
+            .send(stream::new_listen_key())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        let listen_key = data["listenKey"].as_str().unwrap();
+
+        assert!(client
+            .send(stream::renew_listen_key(listen_key))
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .is_ok());
+        assert!(client
+            .send(stream::close_listen_key(listen_key))
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .is_ok());
+        match client
+            .send(stream::renew_listen_key(listen_key))
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+        {
+            Err(Error::Client(ClientError::Structured(err))) => assert_eq!(err.data.code, -1125),
+            _ => panic!("Invalid response"),
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_server_stream_test() {
+        let server = MockServer::start().await.unwrap();
+        let url = format!("{}/stream", server.stream_url());
+        let (mut conn, _) = BinanceWebSocketClient::connect_async(&url).await.unwrap();
+
+        conn.subscribe(vec![&TradeStream::new("BNBUSDT").into()])
+            .await
+            .unwrap();
+        match conn.next().await {
+            Some(Ok(Event::Reply(reply))) => assert!(reply.result.is_ok()),
+            event => panic!("Invalid event {:?}", event),
+        }
+        assert_eq!(server.subscribers("bnbusdt@trade"), 1);
+
+        let data = serde_json::json!({"e":"trade","E":1,"s":"BNBUSDT","t":1,"p":"1","q":"1","T":1,"m":true,"M":true});
+        assert_eq!(server.push("bnbusdt@trade", data), 1);
+        match conn.next().await {
+            Some(Ok(Event::Market { stream, .. })) => {
+                assert_eq!(stream.as_deref(), Some("bnbusdt@trade"))
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        server.disconnect_streams();
+        assert!(conn.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn mock_server_paper_trading_test() {
+        let server = MockServer::start().await.unwrap();
+        server.credentials(Credentials::from_hmac("api-key", "api-secret"));
+        server.paper_trading(
+            MatchingEngine::new()
+                .symbol("BNBUSDT", SymbolRules::new("BNB", "USDT"))
+                .balance("USDT", dec!(1000)),
+        );
+
+        let client = BinanceHttpClient::default()
+            .environment(server.environment())
+            .credentials(Credentials::from_hmac("api-key", "api-secret"));
+        let data = client
+            .send(stream::new_listen_key())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        let listen_key = data["listenKey"].as_str().unwrap();
+        let url = format!("{}/ws/{}", server.stream_url(), listen_key);
+        let (mut conn, _) = BinanceWebSocketClient::connect_async(&url).await.unwrap();
+        while server.subscribers(listen_key) == 0 {
+            tokio::task::yield_now().await;
+        }
+
+        let data = serde_json::json!({"e":"trade","E":1,"s":"BNBUSDT","t":1,"p":"300","q":"1","T":1,"m":true,"M":true});
+        server.push("bnbusdt@trade", data);
+        let data = client
+            .send(trade::new_order("BNBUSDT", trade::order::Side::Buy, "MARKET").quantity(dec!(1)))
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        let data: serde_json::Value = serde_json::from_str(&data).unwrap();
+        assert_eq!(data["status"], "FILLED");
+
+        let mut statuses = vec![];
+        while statuses.len() < 2 {
+            match conn.next().await {
+                Some(Ok(Event::UserData {
+                    event: UserDataEvent::ExecutionReport(report),
+                    ..
+                })) => statuses.push(report.order_status),
+                Some(Ok(Event::UserData {
+                    event: UserDataEvent::OutboundAccountPosition(_),
+                    ..
+                })) => {}
+                event => panic!("Invalid event {:?}", event),
+            }
+        }
+        assert_eq!(statuses, vec!["NEW", "FILLED"]);
+        assert_eq!(server.balances()["BNB"].free, dec!(0.999));
+    }
+
+    #[cfg(feature = "enable-ureq")]
+    #[test]
+    fn mock_server_start_blocking_test() {
+        let server = MockServer::start_blocking().unwrap();
+        let client = crate::ureq::BinanceHttpClient::default().environment(server.environment());
+
+        let data = client
+            .send(crate::market::ping())
+            .unwrap()
+            .into_body_str()
+            .unwrap();
+        assert_eq!(data, "{}");
+    }
+
+    #[tokio::test]
+    async fn mock_server_poisoned_state_test() {
+        let server = MockServer::start().await.unwrap();
+        let state = server.state.clone();
+        std::thread::spawn(move || {
+            let _state = state.lock().unwrap();
+            panic!("Handler panicked");
+        })
+        .join()
+        .unwrap_err();
+
+        let client = BinanceHttpClient::default().environment(server.environment());
+        let data = client
+            .send(market::ping())
+            .await
+            .unwrap()
+            .into_body_str()
+            .await
+            .unwrap();
+        assert_eq!(data, "{}");
+        assert_eq!(server.requests().len(), 1);
+    }
+}
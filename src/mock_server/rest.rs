@@ -0,0 +1,311 @@
+use crate::http::Method;
+use crate::mock_server::{
+    canned, lock, mock::LISTEN_KEY_PATHS, MockResponse, ReceivedRequest, Security, State,
+};
+use hyper::{Body, Request, Response};
+use rand::{distributions::Alphanumeric, Rng};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub(super) async fn handle(
+    state: Arc<Mutex<State>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let method = match *request.method() {
+        hyper::Method::GET => Method::Get,
+        hyper::Method::POST => Method::Post,
+        hyper::Method::PUT => Method::Put,
+        hyper::Method::DELETE => Method::Delete,
+        _ => return Ok(into_response(MockResponse::new(405, ""))),
+    };
+    let path = request.uri().path().to_owned();
+    let query = request.uri().query().unwrap_or_default().to_owned();
+    let api_key = request
+        .headers()
+        .get("X-MBX-APIKEY")
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_owned);
+    let body = hyper::body::to_bytes(request.into_body())
+        .await
+        .unwrap_or_default();
+    let body = String::from_utf8_lossy(&body).into_owned();
+
+    let params: Vec<(String, String)> = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .chain(url::form_urlencoded::parse(body.as_bytes()).into_owned())
+        .collect();
+
+    let (response, latency) = {
+        let mut state = lock(&state);
+        state.requests.push(ReceivedRequest {
+            method: method.clone(),
+            path: path.clone(),
+            params: params.clone(),
+            api_key: api_key.clone(),
+        });
+
+        state.respond(&method, &path, &query, &body, &params, api_key.as_deref())
+    };
+
+    if !latency.is_zero() {
+        tokio::time::sleep(latency).await;
+    }
+
+    Ok(into_response(response))
+}
+
+fn into_response(response: MockResponse) -> Response<Body> {
+    let mut builder = Response::builder()
+        .status(response.status)
+        .header("Content-Type", "application/json;charset=UTF-8");
+    for (key, value) in response.headers {
+        builder = builder.header(key, value);
+    }
+
+    builder
+        .body(Body::from(response.body))
+        .unwrap_or_else(|_| Response::new(Body::empty()))
+}
+
+impl State {
+    fn respond(
+        &mut self,
+        method: &Method,
+        path: &str,
+        query: &str,
+        body: &str,
+        params: &[(String, String)],
+        api_key: Option<&str>,
+    ) -> (MockResponse, Duration) {
+        // The most recent mock takes precedence.
+        let index = self
+            .mocks
+            .iter()
+            .rposition(|mock| mock.matches(method, path, params));
+
+        let security = match index {
+            Some(index) => self.mocks[index].security,
+            None => Security::of(path),
+        };
+        if let Err(response) = self.authenticate(security, query, body, params, api_key) {
+            return (response, self.latency);
+        }
+
+        let index = match index {
+            Some(index) => index,
+            None => {
+                let engine_response = self
+                    .engine
+                    .as_mut()
+                    .and_then(|engine| engine.handle(method, path, params));
+                let response = match engine_response {
+                    Some(response) => {
+                        self.push_engine_events();
+                        response
+                    }
+                    None => self.builtin(method, path, params),
+                };
+                return (response, self.latency);
+            }
+        };
+
+        let mock = &mut self.mocks[index];
+        let response = mock.response.clone();
+        let latency = self.latency + mock.latency;
+        if let Some(times) = mock.times.as_mut() {
+            *times = times.saturating_sub(1);
+            if *times == 0 {
+                self.mocks.remove(index);
+            }
+        }
+
+        (response, latency)
+    }
+
+    fn authenticate(
+        &self,
+        security: Security,
+        query: &str,
+        body: &str,
+        params: &[(String, String)],
+        api_key: Option<&str>,
+    ) -> Result<(), MockResponse> {
+        if security == Security::None {
+            return Ok(());
+        }
+
+        let api_key =
+            api_key.ok_or_else(|| MockResponse::error(401, -2014, "API-key format invalid."))?;
+        let credentials = self
+            .credentials
+            .iter()
+            .find(|credentials| credentials.api_key == api_key)
+            .ok_or_else(|| {
+                MockResponse::error(
+                    401,
+                    -2015,
+                    "Invalid API-key, IP, or permissions for action.",
+                )
+            })?;
+
+        if security == Security::ApiKey {
+            return Ok(());
+        }
+
+        let timestamp: u64 = param(params, "timestamp")
+            .and_then(|timestamp| timestamp.parse().ok())
+            .ok_or_else(|| missing_param("timestamp"))?;
+        let (payload, signature) =
+            split_signature(query, body).ok_or_else(|| missing_param("signature"))?;
+
+        let invalid_signature =
+            || MockResponse::error(400, -1022, "Signature for this request is not valid.");
+        let expected = crate::utils::sign(&payload, &credentials.signature)
+            .map_err(|_| invalid_signature())?;
+        if expected != signature {
+            return Err(invalid_signature());
+        }
+
+        let recv_window: u64 = param(params, "recvWindow")
+            .and_then(|recv_window| recv_window.parse().ok())
+            .unwrap_or(5000);
+        let now = now();
+        if timestamp > now + 1000 || now.saturating_sub(timestamp) > recv_window {
+            return Err(MockResponse::error(
+                400,
+                -1021,
+                "Timestamp for this request is outside of the recvWindow.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Responses of the endpoints served without mock.
+    fn builtin(
+        &mut self,
+        method: &Method,
+        path: &str,
+        params: &[(String, String)],
+    ) -> MockResponse {
+        match (method, path) {
+            (Method::Get, "/api/v3/ping") => MockResponse::new(200, "{}"),
+            (Method::Get, "/api/v3/time") => {
+                MockResponse::new(200, format!(r#"{{"serverTime":{}}}"#, now()))
+            }
+            (Method::Post, path) if LISTEN_KEY_PATHS.contains(&path) => {
+                let listen_key = random_key();
+                self.listen_keys.insert(listen_key.clone());
+                MockResponse::new(200, format!(r#"{{"listenKey":"{}"}}"#, listen_key))
+            }
+            (Method::Put | Method::Delete, path) if LISTEN_KEY_PATHS.contains(&path) => {
+                let exists = match (method, param(params, "listenKey")) {
+                    (Method::Delete, Some(listen_key)) => self.listen_keys.remove(listen_key),
+                    (_, Some(listen_key)) => self.listen_keys.contains(listen_key),
+                    _ => false,
+                };
+                if exists {
+                    MockResponse::new(200, "{}")
+                } else {
+                    MockResponse::error(400, -1125, "This listenKey does not exist.")
+                }
+            }
+            (Method::Post, "/sapi/v1/userListenToken") => {
+                let validity: u64 = param(params, "validity")
+                    .and_then(|validity| validity.parse().ok())
+                    .unwrap_or(86_400_000);
+                MockResponse::new(
+                    200,
+                    format!(
+                        r#"{{"token":"{}","expirationTime":{}}}"#,
+                        random_key(),
+                        now() + validity
+                    ),
+                )
+            }
+            _ => canned::respond(method, path, params).unwrap_or_else(|| {
+                MockResponse::error(
+                    404,
+                    -1,
+                    &format!("No mock for {} {}", method.as_ref(), path),
+                )
+            }),
+        }
+    }
+}
+
+pub(super) fn param<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(k, _)| k == key)
+        .map(|(_, v)| v.as_str())
+}
+
+pub(super) fn missing_param(key: &str) -> MockResponse {
+    MockResponse::error(
+        400,
+        -1102,
+        &format!(
+            "Mandatory parameter '{}' was not sent, was empty/null, or malformed.",
+            key
+        ),
+    )
+}
+
+/// Signed payload, the query string concatenated with the body without the
+/// `signature` parameter, and the decoded signature.
+fn split_signature(query: &str, body: &str) -> Option<(String, String)> {
+    let mut signature = None;
+    let mut parts = [query, body].map(|part| {
+        part.split('&')
+            .filter(|pair| match pair.strip_prefix("signature=") {
+                Some(value) => {
+                    signature = url::form_urlencoded::parse(format!("s={}", value).as_bytes())
+                        .next()
+                        .map(|(_, value)| value.into_owned());
+                    false
+                }
+                None => true,
+            })
+            .collect::<Vec<&str>>()
+            .join("&")
+    });
+
+    let payload = std::mem::take(&mut parts[0]) + &parts[1];
+    Some((payload, signature?))
+}
+
+fn random_key() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(60)
+        .map(char::from)
+        .collect()
+}
+
+pub(super) fn now() -> u64 {
+    // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_signature;
+
+    #[test]
+    fn mock_server_split_signature_test() {
+        assert_eq!(
+            split_signature("symbol=BNBUSDT&timestamp=1&signature=a%2Bb%3D", ""),
+            Some(("symbol=BNBUSDT&timestamp=1".to_owned(), "a+b=".to_owned()))
+        );
+        assert_eq!(
+            split_signature("symbol=BNBUSDT", "timestamp=1&signature=abc"),
+            Some(("symbol=BNBUSDTtimestamp=1".to_owned(), "abc".to_owned()))
+        );
+        assert_eq!(split_signature("timestamp=1", ""), None);
+    }
+}
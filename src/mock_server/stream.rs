@@ -0,0 +1,230 @@
+use crate::mock_server::{lock, State};
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use std::collections::BTreeSet;
+use std::sync::{Arc, Mutex};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, watch};
+use tokio_tungstenite::tungstenite::{
+    handshake::server::{Request, Response},
+    http::Uri,
+    protocol::Message,
+};
+
+/// Websocket connection of a client.
+pub(super) struct StreamConnection {
+    pub(super) streams: BTreeSet<String>,
+    pub(super) combined: bool,
+    pub(super) sender: mpsc::UnboundedSender<Message>,
+}
+
+impl StreamConnection {
+    /// Message carrying `data` of `stream`, `None` if not subscribed.
+    pub(super) fn event(&self, stream: &str, data: &Value) -> Option<Message> {
+        if !self.streams.contains(stream) {
+            return None;
+        }
+
+        let message = match self.combined {
+            true => json!({ "stream": stream, "data": data }).to_string(),
+            false => data.to_string(),
+        };
+        Some(Message::Text(message))
+    }
+
+    /// Reply to a control message.
+    ///
+    /// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#live-subscribingunsubscribing-to-streams)
+    fn control(&mut self, text: &str) -> String {
+        let request: Value = match serde_json::from_str(text) {
+            Ok(request) => request,
+            Err(err) => return error(&Value::Null, 3, &format!("Invalid JSON: {}", err)),
+        };
+        let id = request.get("id").cloned().unwrap_or_default();
+        let params = match request.get("params") {
+            Some(Value::Array(params)) => params.as_slice(),
+            _ => &[],
+        };
+
+        let result = match request.get("method").and_then(Value::as_str) {
+            Some("SUBSCRIBE") => stream_names(params).map(|streams| {
+                self.streams.extend(streams);
+                Value::Null
+            }),
+            Some("UNSUBSCRIBE") => stream_names(params).map(|streams| {
+                for stream in streams {
+                    self.streams.remove(&stream);
+                }
+                Value::Null
+            }),
+            Some("LIST_SUBSCRIPTIONS") => Ok(self.streams.iter().cloned().collect()),
+            Some("SET_PROPERTY") => match params {
+                [Value::String(property), Value::Bool(combined)] if property == "combined" => {
+                    self.combined = *combined;
+                    Ok(Value::Null)
+                }
+                [Value::String(property), _] if property == "combined" => {
+                    Err((1, "Invalid value type: expected Boolean".to_owned()))
+                }
+                _ => Err((0, "Unknown property".to_owned())),
+            },
+            Some("GET_PROPERTY") => match params {
+                [Value::String(property)] if property == "combined" => Ok(self.combined.into()),
+                _ => Err((0, "Unknown property".to_owned())),
+            },
+            _ => Err((2, "Invalid request: unknown method".to_owned())),
+        };
+
+        match result {
+            Ok(result) => json!({ "result": result, "id": id }).to_string(),
+            Err((code, msg)) => error(&id, code, &msg),
+        }
+    }
+}
+
+fn stream_names(params: &[Value]) -> Result<Vec<String>, (i64, String)> {
+    params
+        .iter()
+        .map(|param| match param {
+            Value::String(stream) => Ok(stream.to_owned()),
+            _ => Err((
+                2,
+                "Invalid request: stream names must be strings".to_owned(),
+            )),
+        })
+        .collect()
+}
+
+fn error(id: &Value, code: i64, msg: &str) -> String {
+    json!({ "error": { "code": code, "msg": msg }, "id": id }).to_string()
+}
+
+/// Streams and format requested by the connection url, `/ws/<streams>` or
+/// `/stream?streams=<streams>`.
+fn parse_uri(uri: &Uri) -> (BTreeSet<String>, bool) {
+    let (streams, combined) = match uri.path().strip_prefix("/stream") {
+        Some(_) => {
+            let streams = url::form_urlencoded::parse(uri.query().unwrap_or_default().as_bytes())
+                .find(|(key, _)| key == "streams")
+                .map(|(_, streams)| streams.into_owned())
+                .unwrap_or_default();
+            (streams, true)
+        }
+        None => {
+            let streams = uri.path().strip_prefix("/ws").unwrap_or_default();
+            (streams.to_owned(), false)
+        }
+    };
+
+    let streams = streams
+        .split('/')
+        .filter(|stream| !stream.is_empty())
+        .map(str::to_owned)
+        .collect();
+    (streams, combined)
+}
+
+pub(super) async fn serve(
+    listener: TcpListener,
+    state: Arc<Mutex<State>>,
+    mut shutdown: watch::Receiver<bool>,
+) {
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((tcp, _)) => {
+                    tokio::spawn(connection(tcp, state.clone()));
+                }
+                Err(err) => log::warn!("Failed to accept websocket connection: {}", err),
+            },
+            _ = shutdown.changed() => break,
+        }
+    }
+}
+
+#[allow(clippy::result_large_err)]
+async fn connection(tcp: TcpStream, state: Arc<Mutex<State>>) {
+    let mut uri = Uri::default();
+    let callback = |request: &Request, response: Response| {
+        uri = request.uri().clone();
+        Ok(response)
+    };
+    let mut socket = match tokio_tungstenite::accept_hdr_async(tcp, callback).await {
+        Ok(socket) => socket,
+        Err(err) => {
+            log::warn!("Websocket handshake failed: {}", err);
+            return;
+        }
+    };
+
+    let (streams, combined) = parse_uri(&uri);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let id = {
+        let mut state = lock(&state);
+        let id = state.next_connection;
+        state.next_connection += 1;
+        state.connections.insert(
+            id,
+            StreamConnection {
+                streams,
+                combined,
+                sender,
+            },
+        );
+        id
+    };
+
+    loop {
+        tokio::select! {
+            message = socket.next() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    let reply = match lock(&state).connections.get_mut(&id) {
+                        Some(connection) => connection.control(&text),
+                        None => break,
+                    };
+                    if socket.send(Message::Text(reply)).await.is_err() {
+                        break;
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+            message = receiver.recv() => match message {
+                Some(message) => {
+                    if socket.send(message).await.is_err() {
+                        break;
+                    }
+                }
+                // Disconnected by the server.
+                None => {
+                    let _ = socket.close(None).await;
+                    break;
+                }
+            },
+        }
+    }
+
+    lock(&state).connections.remove(&id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_uri;
+
+    #[test]
+    fn mock_server_parse_uri_test() {
+        let (streams, combined) = parse_uri(&"/stream?streams=a@trade/b@trade".parse().unwrap());
+        assert_eq!(
+            streams.into_iter().collect::<Vec<_>>(),
+            vec!["a@trade", "b@trade"]
+        );
+        assert!(combined);
+
+        let (streams, combined) = parse_uri(&"/ws/a@trade".parse().unwrap());
+        assert_eq!(streams.into_iter().collect::<Vec<_>>(), vec!["a@trade"]);
+        assert!(!combined);
+
+        let (streams, _) = parse_uri(&"/ws".parse().unwrap());
+        assert!(streams.is_empty());
+    }
+}
@@ -0,0 +1,524 @@
+//! Local balances of an account.
+//!
+//! A [`Portfolio`] is seeded from an account snapshot of the REST API and kept
+//! current with the `outboundAccountPosition` and `balanceUpdate` events of the
+//! user data stream:
+//!
+//! * Spot: `GET /api/v3/account`, see [`crate::trade::account()`].
+//! * Cross margin: `GET /sapi/v1/margin/account`, see [`crate::margin::margin_account()`].
+//! * Isolated margin: `GET /sapi/v1/margin/isolated/account`, see
+//!   [`crate::margin::isolated_margin_account()`], one portfolio per symbol.
+//!
+//! Events may be missed while the stream reconnects, so a new snapshot should
+//! be requested periodically and passed to [`Portfolio::reconcile_spot`],
+//! [`Portfolio::reconcile_margin`] or [`Portfolio::reconcile_isolated_margin`],
+//! which report the balances that drifted.
+//!
+//! # Example
+//!
+//! ```
+//! use binance_spot_connector_rust::{
+//!     portfolio::{Portfolio, SpotAccountSnapshot, SymbolPrice},
+//!     user_data_stream::event::UserDataEvent,
+//! };
+//! use rust_decimal_macros::dec;
+//!
+//! // let body = client.send(trade::account())?.into_body_str()?;
+//! let body = r#"{"updateTime":1660000000000,"balances":[{"asset":"BNB","free":"2.0","locked":"0.0"},{"asset":"USDT","free":"100.0","locked":"50.0"}]}"#;
+//! let snapshot: SpotAccountSnapshot = serde_json::from_str(body).unwrap();
+//! let mut portfolio = Portfolio::from_spot_account(&snapshot);
+//!
+//! let event = r#"{"e":"balanceUpdate","E":1660000000100,"a":"BNB","d":"1.0","T":1660000000099}"#;
+//! let event: UserDataEvent = serde_json::from_str(event).unwrap();
+//! portfolio.handle_event(&event);
+//! assert_eq!(portfolio.balance("BNB").unwrap().free, dec!(3.0));
+//!
+//! // let body = client.send(market::ticker_price())?.into_body_str()?;
+//! let body = r#"[{"symbol":"BNBUSDT","price":"300.0"}]"#;
+//! let prices: Vec<SymbolPrice> = serde_json::from_str(body).unwrap();
+//! let valuation = portfolio.value_in("USDT", &prices);
+//! assert_eq!(valuation.total, dec!(1050.0));
+//! ```
+
+use crate::user_data_stream::event::{AccountBalance, UserDataEvent};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Response of `GET /api/v3/account`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotAccountSnapshot {
+    pub update_time: u64,
+    pub balances: Vec<SpotBalance>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SpotBalance {
+    pub asset: String,
+    pub free: Decimal,
+    pub locked: Decimal,
+}
+
+/// Response of `GET /sapi/v1/margin/account`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccountSnapshot {
+    pub user_assets: Vec<MarginAsset>,
+}
+
+/// Response of `GET /sapi/v1/margin/isolated/account`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct IsolatedMarginAccountSnapshot {
+    pub assets: Vec<IsolatedMarginPair>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    pub symbol: String,
+    pub base_asset: MarginAsset,
+    pub quote_asset: MarginAsset,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct MarginAsset {
+    pub asset: String,
+    pub free: Decimal,
+    pub locked: Decimal,
+    pub borrowed: Decimal,
+    pub interest: Decimal,
+}
+
+/// Latest price of a symbol returned by `GET /api/v3/ticker/price`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SymbolPrice {
+    pub symbol: String,
+    pub price: Decimal,
+}
+
+/// Balance of an asset.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Balance {
+    pub free: Decimal,
+    pub locked: Decimal,
+    /// Zero on spot accounts.
+    pub borrowed: Decimal,
+    /// Zero on spot accounts.
+    pub interest: Decimal,
+}
+
+impl Balance {
+    pub fn total(&self) -> Decimal {
+        self.free + self.locked
+    }
+
+    /// Total minus the borrowed amount and the interest.
+    pub fn net(&self) -> Decimal {
+        self.total() - self.borrowed - self.interest
+    }
+}
+
+impl From<&MarginAsset> for Balance {
+    fn from(asset: &MarginAsset) -> Balance {
+        Balance {
+            free: asset.free,
+            locked: asset.locked,
+            borrowed: asset.borrowed,
+            interest: asset.interest,
+        }
+    }
+}
+
+/// Difference between the local balance of an asset and the balance of a
+/// snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BalanceDrift {
+    pub asset: String,
+    pub local: Balance,
+    pub snapshot: Balance,
+}
+
+impl BalanceDrift {
+    /// Free amount to add to the local balance to match the snapshot.
+    pub fn free_delta(&self) -> Decimal {
+        self.snapshot.free - self.local.free
+    }
+
+    /// Locked amount to add to the local balance to match the snapshot.
+    pub fn locked_delta(&self) -> Decimal {
+        self.snapshot.locked - self.local.locked
+    }
+}
+
+/// Net balances valued in a quote asset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Valuation {
+    pub total: Decimal,
+    /// Value of each priced asset.
+    pub assets: BTreeMap<String, Decimal>,
+    /// Assets with a balance but no price against the quote asset, left out
+    /// of the total.
+    pub unpriced: Vec<String>,
+}
+
+/// Balances of an account, per asset.
+#[derive(Debug, Clone, Default)]
+pub struct Portfolio {
+    balances: BTreeMap<String, Balance>,
+    update_time: Option<u64>,
+    reconcile_interval: Option<Duration>,
+    reconciled_at: Option<u64>,
+}
+
+impl Portfolio {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_spot_account(snapshot: &SpotAccountSnapshot) -> Self {
+        let mut portfolio = Self::new();
+        portfolio.reset_spot(snapshot);
+        portfolio
+    }
+
+    pub fn from_margin_account(snapshot: &MarginAccountSnapshot) -> Self {
+        let mut portfolio = Self::new();
+        portfolio.reset(Self::margin_balances(snapshot), None);
+        portfolio
+    }
+
+    /// Portfolio of the isolated margin pair `symbol`, `None` if the pair is
+    /// not part of the snapshot.
+    pub fn from_isolated_margin_account(
+        snapshot: &IsolatedMarginAccountSnapshot,
+        symbol: &str,
+    ) -> Option<Self> {
+        let mut portfolio = Self::new();
+        portfolio.reset(Self::isolated_margin_balances(snapshot, symbol)?, None);
+        Some(portfolio)
+    }
+
+    /// Report a reconciliation as due once `interval` has elapsed since the
+    /// last one, see [`Portfolio::is_reconcile_due_at`].
+    pub fn reconcile_interval(mut self, interval: Duration) -> Self {
+        self.reconcile_interval = Some(interval);
+        self
+    }
+
+    pub fn balance(&self, asset: &str) -> Option<&Balance> {
+        self.balances.get(asset)
+    }
+
+    /// Balances by asset.
+    pub fn balances(&self) -> impl Iterator<Item = (&str, &Balance)> {
+        self.balances
+            .iter()
+            .map(|(asset, balance)| (asset.as_str(), balance))
+    }
+
+    /// Time of the latest account update applied, in milliseconds.
+    pub fn update_time(&self) -> Option<u64> {
+        self.update_time
+    }
+
+    /// Apply a user data event, returning whether a balance changed.
+    ///
+    /// Events older than the latest snapshot or `outboundAccountPosition`
+    /// applied are ignored.
+    pub fn handle_event(&mut self, event: &UserDataEvent) -> bool {
+        match event {
+            UserDataEvent::OutboundAccountPosition(position) => {
+                if self.is_stale(position.last_update_time) {
+                    return false;
+                }
+                for AccountBalance {
+                    asset,
+                    free,
+                    locked,
+                } in position.balances.iter()
+                {
+                    let balance = self.balances.entry(asset.clone()).or_default();
+                    balance.free = *free;
+                    balance.locked = *locked;
+                }
+                self.update_time = Some(position.last_update_time);
+
+                true
+            }
+            UserDataEvent::BalanceUpdate(update) => {
+                // The position of the account at that time was already applied.
+                if self
+                    .update_time
+                    .map_or(false, |time| update.clear_time <= time)
+                {
+                    return false;
+                }
+                self.balances.entry(update.asset.clone()).or_default().free += update.balance_delta;
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the reconciliation interval has elapsed at `timestamp`, in
+    /// milliseconds. Always `false` without a reconciliation interval.
+    pub fn is_reconcile_due_at(&self, timestamp: u64) -> bool {
+        match (self.reconcile_interval, self.reconciled_at) {
+            (Some(interval), Some(reconciled_at)) => {
+                timestamp >= reconciled_at + interval.as_millis() as u64
+            }
+            (Some(_), None) => true,
+            (None, _) => false,
+        }
+    }
+
+    /// Replace the balances with a new spot snapshot received at `timestamp`,
+    /// returning the balances which differed.
+    ///
+    /// A snapshot older than the latest account update applied is ignored,
+    /// since it would revert the update.
+    pub fn reconcile_spot(
+        &mut self,
+        snapshot: &SpotAccountSnapshot,
+        timestamp: u64,
+    ) -> Vec<BalanceDrift> {
+        self.reconciled_at = Some(timestamp);
+        if self.is_stale(snapshot.update_time) {
+            return vec![];
+        }
+
+        let drifts = self.drifts(&Self::spot_balances(snapshot));
+        self.reset_spot(snapshot);
+        drifts
+    }
+
+    /// Replace the balances with a new cross margin snapshot received at
+    /// `timestamp`, returning the balances which differed.
+    pub fn reconcile_margin(
+        &mut self,
+        snapshot: &MarginAccountSnapshot,
+        timestamp: u64,
+    ) -> Vec<BalanceDrift> {
+        let balances = Self::margin_balances(snapshot);
+        self.reconciled_at = Some(timestamp);
+
+        let drifts = self.drifts(&balances);
+        self.reset(balances, self.update_time);
+        drifts
+    }
+
+    /// Replace the balances with the isolated margin pair `symbol` of a new
+    /// snapshot received at `timestamp`, returning the balances which
+    /// differed. Nothing is replaced if the pair is not part of the snapshot.
+    pub fn reconcile_isolated_margin(
+        &mut self,
+        snapshot: &IsolatedMarginAccountSnapshot,
+        symbol: &str,
+        timestamp: u64,
+    ) -> Vec<BalanceDrift> {
+        self.reconciled_at = Some(timestamp);
+        let Some(balances) = Self::isolated_margin_balances(snapshot, symbol) else {
+            return vec![];
+        };
+
+        let drifts = self.drifts(&balances);
+        self.reset(balances, self.update_time);
+        drifts
+    }
+
+    /// Value the net balances in `quote`, with the prices of the
+    /// `<asset><quote>` or `<quote><asset>` symbols.
+    ///
+    /// Request all the prices with [`crate::market::ticker_price()`] without
+    /// symbol, since a request for a symbol which does not exist fails.
+    pub fn value_in(&self, quote: &str, prices: &[SymbolPrice]) -> Valuation {
+        let price = |symbol: &str| {
+            prices
+                .iter()
+                .find(|price| price.symbol == symbol && !price.price.is_zero())
+                .map(|price| price.price)
+        };
+
+        let mut valuation = Valuation::default();
+        for (asset, balance) in self.balances.iter() {
+            let amount = balance.net();
+            if amount.is_zero() {
+                continue;
+            }
+
+            let value = if asset == quote {
+                Some(amount)
+            } else if let Some(price) = price(&format!("{}{}", asset, quote)) {
+                Some(amount * price)
+            } else {
+                price(&format!("{}{}", quote, asset)).map(|price| amount / price)
+            };
+            match value {
+                Some(value) => {
+                    valuation.total += value;
+                    valuation.assets.insert(asset.clone(), value);
+                }
+                None => valuation.unpriced.push(asset.clone()),
+            }
+        }
+
+        valuation
+    }
+
+    fn is_stale(&self, time: u64) -> bool {
+        self.update_time
+            .map_or(false, |update_time| time < update_time)
+    }
+
+    fn drifts(&self, balances: &BTreeMap<String, Balance>) -> Vec<BalanceDrift> {
+        let mut assets: Vec<&String> = self.balances.keys().chain(balances.keys()).collect();
+        assets.sort();
+        assets.dedup();
+
+        assets
+            .into_iter()
+            .filter_map(|asset| {
+                let local = self.balances.get(asset).copied().unwrap_or_default();
+                let snapshot = balances.get(asset).copied().unwrap_or_default();
+                (local != snapshot).then(|| BalanceDrift {
+                    asset: asset.clone(),
+                    local,
+                    snapshot,
+                })
+            })
+            .collect()
+    }
+
+    fn reset_spot(&mut self, snapshot: &SpotAccountSnapshot) {
+        self.reset(Self::spot_balances(snapshot), Some(snapshot.update_time));
+    }
+
+    fn reset(&mut self, balances: BTreeMap<String, Balance>, update_time: Option<u64>) {
+        self.balances = balances;
+        self.update_time = update_time;
+    }
+
+    fn spot_balances(snapshot: &SpotAccountSnapshot) -> BTreeMap<String, Balance> {
+        snapshot
+            .balances
+            .iter()
+            .map(|balance| {
+                (
+                    balance.asset.clone(),
+                    Balance {
+                        free: balance.free,
+                        locked: balance.locked,
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn margin_balances(snapshot: &MarginAccountSnapshot) -> BTreeMap<String, Balance> {
+        snapshot
+            .user_assets
+            .iter()
+            .map(|asset| (asset.asset.clone(), Balance::from(asset)))
+            .collect()
+    }
+
+    fn isolated_margin_balances(
+        snapshot: &IsolatedMarginAccountSnapshot,
+        symbol: &str,
+    ) -> Option<BTreeMap<String, Balance>> {
+        let pair = snapshot.assets.iter().find(|pair| pair.symbol == symbol)?;
+
+        Some(
+            [&pair.base_asset, &pair.quote_asset]
+                .into_iter()
+                .map(|asset| (asset.asset.clone(), Balance::from(asset)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        IsolatedMarginAccountSnapshot, MarginAccountSnapshot, Portfolio, SpotAccountSnapshot,
+        SymbolPrice,
+    };
+    use crate::user_data_stream::event::UserDataEvent;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    #[test]
+    fn portfolio_spot_account_test() {
+        let snapshot: SpotAccountSnapshot = serde_json::from_str(r#"{"makerCommission":15,"updateTime":1000,"balances":[{"asset":"BTC","free":"1.0","locked":"0.5"},{"asset":"USDT","free":"1000.0","locked":"0.0"}]}"#).unwrap();
+        let mut portfolio =
+            Portfolio::from_spot_account(&snapshot).reconcile_interval(Duration::from_secs(60));
+        assert!(portfolio.is_reconcile_due_at(1000));
+
+        let events = [
+            // Already part of the snapshot.
+            r#"{"e":"balanceUpdate","E":900,"a":"USDT","d":"10.0","T":900}"#,
+            r#"{"e":"outboundAccountPosition","E":2000,"u":2000,"B":[{"a":"BTC","f":"0.5","l":"0.5"},{"a":"ETH","f":"2.0","l":"0.0"}]}"#,
+            r#"{"e":"outboundAccountPosition","E":1500,"u":1500,"B":[{"a":"BTC","f":"0.0","l":"0.0"}]}"#,
+            r#"{"e":"balanceUpdate","E":3000,"a":"USDT","d":"-100.0","T":3000}"#,
+        ];
+        let applied: Vec<bool> = events
+            .iter()
+            .map(|event| {
+                let event: UserDataEvent = serde_json::from_str(event).unwrap();
+                portfolio.handle_event(&event)
+            })
+            .collect();
+        assert_eq!(applied, vec![false, true, false, true]);
+        assert_eq!(portfolio.balance("BTC").unwrap().total(), dec!(1.0));
+        assert_eq!(portfolio.balance("USDT").unwrap().free, dec!(900.0));
+
+        let prices: Vec<SymbolPrice> = serde_json::from_str(
+            r#"[{"symbol":"BTCUSDT","price":"30000.0"},{"symbol":"USDTTRY","price":"30.0"}]"#,
+        )
+        .unwrap();
+        let valuation = portfolio.value_in("USDT", &prices);
+        assert_eq!(valuation.total, dec!(30900.0));
+        assert_eq!(valuation.unpriced, vec!["ETH".to_owned()]);
+
+        let snapshot: SpotAccountSnapshot = serde_json::from_str(r#"{"updateTime":4000,"balances":[{"asset":"BTC","free":"0.5","locked":"0.5"},{"asset":"USDT","free":"890.0","locked":"0.0"}]}"#).unwrap();
+        let drifts = portfolio.reconcile_spot(&snapshot, 5000);
+        assert_eq!(drifts.len(), 2);
+        assert_eq!(drifts[0].asset, "ETH");
+        assert_eq!(drifts[1].free_delta(), dec!(-10.0));
+        assert!(!portfolio.is_reconcile_due_at(60000));
+        assert!(portfolio.is_reconcile_due_at(65000));
+    }
+
+    #[test]
+    fn portfolio_margin_account_test() {
+        let asset = |asset: &str, free: &str, borrowed: &str| {
+            format!(
+                r#"{{"asset":"{}","borrowed":"{}","free":"{}","interest":"0","locked":"0","netAsset":"0"}}"#,
+                asset, borrowed, free
+            )
+        };
+
+        let snapshot: MarginAccountSnapshot = serde_json::from_str(&format!(
+            r#"{{"tradeEnabled":true,"userAssets":[{},{}]}}"#,
+            asset("BTC", "2.0", "1.0"),
+            asset("USDT", "0", "0")
+        ))
+        .unwrap();
+        let portfolio = Portfolio::from_margin_account(&snapshot);
+        assert_eq!(portfolio.balance("BTC").unwrap().net(), dec!(1.0));
+
+        let snapshot: IsolatedMarginAccountSnapshot = serde_json::from_str(&format!(
+            r#"{{"assets":[{{"symbol":"BTCUSDT","baseAsset":{},"quoteAsset":{}}}]}}"#,
+            asset("BTC", "1.0", "0"),
+            asset("USDT", "100.0", "50.0")
+        ))
+        .unwrap();
+        let portfolio = Portfolio::from_isolated_margin_account(&snapshot, "BTCUSDT").unwrap();
+        assert_eq!(portfolio.balances().count(), 2);
+        assert!(Portfolio::from_isolated_margin_account(&snapshot, "ETHUSDT").is_none());
+    }
+}
@@ -0,0 +1,463 @@
+//! Record and replay of websocket sessions.
+//!
+//! A [`Recorder`] set on a websocket client saves every received text frame
+//! with its receive time, and a [`Replay`] feeds the frames back through the
+//! same decoding path as the live connection, [`Event::from_text`], to debug
+//! or backtest deterministically.
+//!
+//! Two formats are supported:
+//!
+//! * [`Format::Jsonl`]: one `{"received_at":<ms>,"text":<frame>}` object per
+//!   line, readable and easy to edit.
+//! * [`Format::Binary`]: gzip compressed frames prefixed with their receive
+//!   time and length, several times smaller.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use binance_spot_connector_rust::{
+//!     market_stream::trade::TradeStream,
+//!     recording::{Format, Recorder, Replay, Speed},
+//!     tungstenite::BinanceWebSocketClient,
+//! };
+//!
+//! let recorder = Recorder::create("trades.bin", Format::Binary).expect("Failed to create file");
+//! let mut conn = BinanceWebSocketClient::connect()
+//!     .expect("Failed to connect")
+//!     .record(recorder);
+//! conn.subscribe(vec![&TradeStream::new("BTCUSDT").into()])
+//!     .expect("Failed to subscribe");
+//! for _ in 0..100 {
+//!     conn.read().expect("Failed to read");
+//! }
+//! conn.close().expect("Failed to close");
+//!
+//! // Replay 10 times faster than recorded.
+//! let replay = Replay::open("trades.bin")
+//!     .expect("Failed to open file")
+//!     .speed(Speed::Accelerated(10.0));
+//! for event in replay {
+//!     println!("{:?}", event.expect("Invalid frame"));
+//! }
+//! ```
+
+use crate::websocket::Event;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Header of the binary format, followed by the format version.
+const MAGIC: &[u8; 4] = b"BNWS";
+const VERSION: u8 = 1;
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// File format of a recording.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One JSON object per line.
+    Jsonl,
+    /// Gzip compressed, length prefixed frames.
+    Binary,
+}
+
+/// Text frame received on a websocket connection.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct RecordedFrame {
+    /// Receive time in milliseconds since the Unix epoch.
+    pub received_at: u64,
+    pub text: String,
+}
+
+/// Replay error.
+#[derive(Debug)]
+pub enum Error {
+    /// The recording could not be read or is truncated.
+    Io(io::Error),
+    /// A frame is not a valid event.
+    Deserialize(serde_json::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "failed to read the recording: {}", err),
+            Error::Deserialize(err) => write!(f, "invalid recorded frame: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            Error::Deserialize(err) => Some(err),
+        }
+    }
+}
+
+enum Writer {
+    Jsonl(BufWriter<Box<dyn Write + Send>>),
+    Binary(GzEncoder<BufWriter<Box<dyn Write + Send>>>),
+}
+
+/// Writer of the frames received by a websocket client.
+///
+/// Set on a client with `record`. Frames are buffered, the recording is
+/// complete once the recorder is [finished](Self::finish) or dropped.
+pub struct Recorder {
+    writer: Option<Writer>,
+}
+
+impl Recorder {
+    /// Record to the file at `path`, replacing an existing file.
+    pub fn create(path: impl AsRef<Path>, format: Format) -> io::Result<Self> {
+        Self::new(File::create(path)?, format)
+    }
+
+    /// Record to `writer`.
+    pub fn new(writer: impl Write + Send + 'static, format: Format) -> io::Result<Self> {
+        let writer: Box<dyn Write + Send> = Box::new(writer);
+        let writer = BufWriter::new(writer);
+        let writer = match format {
+            Format::Jsonl => Writer::Jsonl(writer),
+            Format::Binary => {
+                let mut writer = GzEncoder::new(writer, Compression::default());
+                writer.write_all(MAGIC)?;
+                writer.write_all(&[VERSION])?;
+                Writer::Binary(writer)
+            }
+        };
+
+        Ok(Self {
+            writer: Some(writer),
+        })
+    }
+
+    /// Record `text` as received now.
+    pub fn record(&mut self, text: &str) -> io::Result<()> {
+        self.record_frame(&RecordedFrame {
+            received_at: now(),
+            text: text.to_owned(),
+        })
+    }
+
+    pub fn record_frame(&mut self, frame: &RecordedFrame) -> io::Result<()> {
+        match &mut self.writer {
+            Some(Writer::Jsonl(writer)) => {
+                serde_json::to_writer(&mut *writer, frame)?;
+                writer.write_all(b"\n")
+            }
+            Some(Writer::Binary(writer)) => {
+                let length = u32::try_from(frame.text.len())
+                    .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "Frame too large"))?;
+                writer.write_all(&frame.received_at.to_le_bytes())?;
+                writer.write_all(&length.to_le_bytes())?;
+                writer.write_all(frame.text.as_bytes())
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Write the buffered frames, leaving the recording open.
+    pub fn flush(&mut self) -> io::Result<()> {
+        match &mut self.writer {
+            Some(Writer::Jsonl(writer)) => writer.flush(),
+            Some(Writer::Binary(writer)) => writer.flush(),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the buffered frames and the end of the compressed stream.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.close()
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        match self.writer.take() {
+            Some(Writer::Jsonl(mut writer)) => writer.flush(),
+            Some(Writer::Binary(writer)) => writer.finish()?.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        if let Err(err) = self.close() {
+            log::error!("Failed to finish recording: {}", err);
+        }
+    }
+}
+
+/// Longest wait between two replayed frames.
+const MAX_DELAY: Duration = Duration::from_secs(u32::MAX as u64);
+
+/// Replay speed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Speed {
+    /// Wait between frames as long as when recorded.
+    Original,
+    /// Wait between frames the recorded time divided by the factor.
+    Accelerated(f64),
+    /// Do not wait between frames.
+    Max,
+}
+
+/// Source of the events of a recording, at the recorded pace.
+///
+/// Iterates over the decoded events, waiting between frames according to
+/// the [`Speed`], [`Speed::Max`] by default. The async stream
+/// [`Replay::into_stream`] waits with Tokio timers.
+pub struct Replay {
+    reader: Box<dyn BufRead + Send>,
+    format: Format,
+    speed: Speed,
+    /// Receive time of the first frame and the instant it was replayed.
+    start: Option<(u64, Instant)>,
+    received_at: Option<u64>,
+}
+
+impl Replay {
+    /// Replay the file at `path`, the format is detected from its content.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut reader = BufReader::new(File::open(path)?);
+        let format = match reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            true => Format::Binary,
+            false => Format::Jsonl,
+        };
+
+        Self::new(reader, format)
+    }
+
+    /// Replay the recording read from `reader`.
+    pub fn new(reader: impl Read + Send + 'static, format: Format) -> io::Result<Self> {
+        let reader: Box<dyn BufRead + Send> = match format {
+            Format::Jsonl => Box::new(BufReader::new(reader)),
+            Format::Binary => {
+                let mut reader = BufReader::new(GzDecoder::new(reader));
+                let mut header = [0; 5];
+                reader.read_exact(&mut header)?;
+                if header[..4] != MAGIC[..] || header[4] != VERSION {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "Not a websocket recording",
+                    ));
+                }
+                Box::new(reader)
+            }
+        };
+
+        Ok(Self {
+            reader,
+            format,
+            speed: Speed::Max,
+            start: None,
+            received_at: None,
+        })
+    }
+
+    pub fn speed(mut self, speed: Speed) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Receive time of the last replayed frame.
+    pub fn received_at(&self) -> Option<u64> {
+        self.received_at
+    }
+
+    /// Read the next frame without waiting, `None` at the end of the recording.
+    pub fn next_frame(&mut self) -> Option<io::Result<RecordedFrame>> {
+        let frame = match self.format {
+            Format::Jsonl => self.read_jsonl(),
+            Format::Binary => self.read_binary(),
+        };
+        if let Some(Ok(frame)) = &frame {
+            self.received_at = Some(frame.received_at);
+        }
+
+        frame
+    }
+
+    fn read_jsonl(&mut self) -> Option<io::Result<RecordedFrame>> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) if line.trim().is_empty() => continue,
+                Ok(_) => return Some(serde_json::from_str(&line).map_err(io::Error::from)),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+
+    fn read_binary(&mut self) -> Option<io::Result<RecordedFrame>> {
+        match self.reader.fill_buf() {
+            Ok([]) => return None,
+            Ok(_) => {}
+            Err(err) => return Some(Err(err)),
+        }
+
+        let mut read_frame = || {
+            let mut received_at = [0; 8];
+            let mut length = [0; 4];
+            self.reader.read_exact(&mut received_at)?;
+            self.reader.read_exact(&mut length)?;
+            let mut text = vec![0; u32::from_le_bytes(length) as usize];
+            self.reader.read_exact(&mut text)?;
+
+            Ok(RecordedFrame {
+                received_at: u64::from_le_bytes(received_at),
+                text: String::from_utf8(text)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            })
+        };
+
+        Some(read_frame())
+    }
+
+    /// Time to wait before replaying a frame received at `received_at`.
+    fn delay(&mut self, received_at: u64) -> Duration {
+        let factor = match self.speed {
+            Speed::Original => 1.0,
+            Speed::Accelerated(factor) if factor > 0.0 => factor,
+            Speed::Accelerated(_) | Speed::Max => return Duration::ZERO,
+        };
+        let (first_received_at, started) = *self.start.get_or_insert((received_at, Instant::now()));
+
+        let elapsed = Duration::from_millis(received_at.saturating_sub(first_received_at));
+        // Tiny factors would overflow the delay.
+        let delay =
+            Duration::from_secs_f64((elapsed.as_secs_f64() / factor).min(MAX_DELAY.as_secs_f64()));
+        started.checked_add(delay).map_or(MAX_DELAY, |due| {
+            due.saturating_duration_since(Instant::now())
+        })
+    }
+
+    fn decode(frame: io::Result<RecordedFrame>) -> Result<Event, Error> {
+        let frame = frame.map_err(Error::Io)?;
+        Event::from_text(&frame.text).map_err(Error::Deserialize)
+    }
+
+    /// Async stream of the events, waiting between frames with Tokio timers.
+    #[cfg(feature = "enable-tokio-tungstenite")]
+    pub fn into_stream(self) -> impl futures_util::Stream<Item = Result<Event, Error>> {
+        futures_util::stream::unfold(self, |mut replay| async move {
+            let frame = replay.next_frame()?;
+            if let Ok(frame) = &frame {
+                let delay = replay.delay(frame.received_at);
+                if !delay.is_zero() {
+                    tokio::time::sleep(delay).await;
+                }
+            }
+
+            Some((Self::decode(frame), replay))
+        })
+    }
+}
+
+impl Iterator for Replay {
+    type Item = Result<Event, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.next_frame()?;
+        if let Ok(frame) = &frame {
+            let delay = self.delay(frame.received_at);
+            if !delay.is_zero() {
+                std::thread::sleep(delay);
+            }
+        }
+
+        Some(Self::decode(frame))
+    }
+}
+
+fn now() -> u64 {
+    // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Format, RecordedFrame, Recorder, Replay, Speed, MAX_DELAY};
+    use crate::market_stream::event::MarketEvent;
+    use crate::websocket::Event;
+    use std::io::{self, Write};
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    /// Writer shared with the test once the recorder is dropped.
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn recording_round_trip_test() {
+        let frames = [
+            RecordedFrame {
+                received_at: 1_000,
+                text: r#"{"stream":"btcusdt@trade","data":{"e":"trade","E":1,"s":"BTCUSDT","t":1,"p":"1","q":"1","T":1,"m":true,"M":true}}"#.to_owned(),
+            },
+            RecordedFrame {
+                received_at: 1_100,
+                text: r#"{"result":null,"id":1}"#.to_owned(),
+            },
+        ];
+
+        for format in [Format::Jsonl, Format::Binary] {
+            let buffer = Buffer::default();
+            let mut recorder = Recorder::new(buffer.clone(), format).unwrap();
+            for frame in &frames {
+                recorder.record_frame(frame).unwrap();
+            }
+            recorder.finish().unwrap();
+
+            let data = buffer.0.lock().unwrap().clone();
+            let mut replay = Replay::new(io::Cursor::new(data.clone()), format).unwrap();
+            assert_eq!(replay.next_frame().unwrap().unwrap(), frames[0]);
+            assert_eq!(replay.next_frame().unwrap().unwrap(), frames[1]);
+            assert!(replay.next_frame().is_none());
+            assert_eq!(replay.received_at(), Some(1_100));
+
+            let start = Instant::now();
+            let events: Vec<Event> = Replay::new(io::Cursor::new(data), format)
+                .unwrap()
+                .speed(Speed::Accelerated(2.0))
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert!(start.elapsed() >= Duration::from_millis(50));
+            assert!(matches!(
+                &events[0],
+                Event::Market {
+                    event: MarketEvent::Trade(_),
+                    ..
+                }
+            ));
+        }
+
+        assert!(Replay::new(io::Cursor::new(b"{}".to_vec()), Format::Binary).is_err());
+
+        let mut replay = Replay::new(io::Cursor::new(vec![]), Format::Jsonl)
+            .unwrap()
+            .speed(Speed::Accelerated(f64::MIN_POSITIVE));
+        assert_eq!(replay.delay(1_000), Duration::ZERO);
+        assert!(replay.delay(1_100) > MAX_DELAY - Duration::from_secs(1));
+    }
+}
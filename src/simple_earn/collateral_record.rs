@@ -0,0 +1,162 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/simple-earn/flexible/history/collateralRecord`
+///
+/// Get records of flexible positions pledged as collateral for loans
+///
+/// * The time between `startTime` and `endTime` cannot be longer than 30 days.
+/// * If `startTime` and `endTime` are both not sent, then the last 30 days' data will be returned.
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+///
+/// let request = simple_earn::collateral_record().product_id("BNB001");
+/// ```
+pub struct CollateralRecord {
+    product_id: Option<String>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    current: Option<u64>,
+    size: Option<u64>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl CollateralRecord {
+    pub fn new() -> Self {
+        Self {
+            product_id: None,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn product_id(mut self, product_id: &str) -> Self {
+        self.product_id = Some(product_id.to_owned());
+        self
+    }
+
+    pub fn start_time(mut self, start_time: u64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn end_time(mut self, end_time: u64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    pub fn current(mut self, current: u64) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<CollateralRecord> for Request {
+    fn from(request: CollateralRecord) -> Request {
+        let mut params = vec![];
+
+        if let Some(product_id) = request.product_id {
+            params.push(("productId".to_owned(), product_id));
+        }
+
+        if let Some(start_time) = request.start_time {
+            params.push(("startTime".to_owned(), start_time.to_string()));
+        }
+
+        if let Some(end_time) = request.end_time {
+            params.push(("endTime".to_owned(), end_time.to_string()));
+        }
+
+        if let Some(current) = request.current {
+            params.push(("current".to_owned(), current.to_string()));
+        }
+
+        if let Some(size) = request.size {
+            params.push(("size".to_owned(), size.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/flexible/history/collateralRecord".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for CollateralRecord {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CollateralRecord;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_collateral_record_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = CollateralRecord::new()
+            .product_id("BNB001")
+            .start_time(1640995200000)
+            .end_time(1640995200000)
+            .current(1)
+            .size(10)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/flexible/history/collateralRecord".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("productId".to_owned(), "BNB001".to_string()),
+                    ("startTime".to_owned(), "1640995200000".to_string()),
+                    ("endTime".to_owned(), "1640995200000".to_string()),
+                    ("current".to_owned(), "1".to_string()),
+                    ("size".to_owned(), "10".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
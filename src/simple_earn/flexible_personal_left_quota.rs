@@ -0,0 +1,91 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/simple-earn/flexible/personalLeftQuota`
+///
+/// Get the amount the account may still subscribe to a flexible product
+///
+/// Weight(IP): 150
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+///
+/// let request = simple_earn::flexible_personal_left_quota("BNB001");
+/// ```
+pub struct FlexiblePersonalLeftQuota {
+    product_id: String,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl FlexiblePersonalLeftQuota {
+    pub fn new(product_id: &str) -> Self {
+        Self {
+            product_id: product_id.to_owned(),
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<FlexiblePersonalLeftQuota> for Request {
+    fn from(request: FlexiblePersonalLeftQuota) -> Request {
+        let mut params = vec![("productId".to_owned(), request.product_id)];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/flexible/personalLeftQuota".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlexiblePersonalLeftQuota;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_flexible_personal_left_quota_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = FlexiblePersonalLeftQuota::new("BNB001")
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/flexible/personalLeftQuota".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("productId".to_owned(), "BNB001".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
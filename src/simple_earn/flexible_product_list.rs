@@ -0,0 +1,133 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `GET /sapi/v1/simple-earn/flexible/list`
+///
+/// Get available Simple Earn flexible product list
+///
+/// Weight(IP): 150
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+///
+/// let request = simple_earn::flexible_product_list().asset("BNB");
+/// ```
+pub struct FlexibleProductList {
+    asset: Option<String>,
+    current: Option<u64>,
+    size: Option<u64>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl FlexibleProductList {
+    pub fn new() -> Self {
+        Self {
+            asset: None,
+            current: None,
+            size: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn asset(mut self, asset: &str) -> Self {
+        self.asset = Some(asset.to_owned());
+        self
+    }
+
+    pub fn current(mut self, current: u64) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    pub fn size(mut self, size: u64) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<FlexibleProductList> for Request {
+    fn from(request: FlexibleProductList) -> Request {
+        let mut params = vec![];
+
+        if let Some(asset) = request.asset {
+            params.push(("asset".to_owned(), asset));
+        }
+
+        if let Some(current) = request.current {
+            params.push(("current".to_owned(), current.to_string()));
+        }
+
+        if let Some(size) = request.size {
+            params.push(("size".to_owned(), size.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/flexible/list".to_owned(),
+            method: Method::Get,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+impl Default for FlexibleProductList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlexibleProductList;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_flexible_product_list_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = FlexibleProductList::new()
+            .asset("BNB")
+            .current(1)
+            .size(10)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/flexible/list".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Get,
+                params: vec![
+                    ("asset".to_owned(), "BNB".to_string()),
+                    ("current".to_owned(), "1".to_string()),
+                    ("size".to_owned(), "10".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use crate::http::{request::Request, Credentials, Method};
+use crate::simple_earn::product::DestAccount;
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/simple-earn/flexible/redeem`
+///
+/// Redeem a Simple Earn flexible product
+///
+/// * You need to open `Enable Spot & Margin Trading` permission for the API Key which requests this endpoint.
+/// * `amount` is mandatory unless `redeemAll` is `true`.
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+/// use rust_decimal_macros::dec;
+///
+/// let request = simple_earn::flexible_redeem("BNB001").amount(dec!(1.01));
+/// ```
+pub struct FlexibleRedeem {
+    product_id: String,
+    redeem_all: Option<bool>,
+    amount: Option<Decimal>,
+    dest_account: Option<DestAccount>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl FlexibleRedeem {
+    pub fn new(product_id: &str) -> Self {
+        Self {
+            product_id: product_id.to_owned(),
+            redeem_all: None,
+            amount: None,
+            dest_account: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn redeem_all(mut self, redeem_all: bool) -> Self {
+        self.redeem_all = Some(redeem_all);
+        self
+    }
+
+    pub fn amount(mut self, amount: Decimal) -> Self {
+        self.amount = Some(amount);
+        self
+    }
+
+    pub fn dest_account(mut self, dest_account: DestAccount) -> Self {
+        self.dest_account = Some(dest_account);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<FlexibleRedeem> for Request {
+    fn from(request: FlexibleRedeem) -> Request {
+        let mut params = vec![("productId".to_owned(), request.product_id)];
+
+        if let Some(redeem_all) = request.redeem_all {
+            params.push(("redeemAll".to_owned(), redeem_all.to_string()));
+        }
+
+        if let Some(amount) = request.amount {
+            params.push(("amount".to_owned(), amount.to_string()));
+        }
+
+        if let Some(dest_account) = request.dest_account {
+            params.push(("destAccount".to_owned(), dest_account.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/flexible/redeem".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DestAccount, FlexibleRedeem};
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_flexible_redeem_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = FlexibleRedeem::new("BNB001")
+            .redeem_all(true)
+            .amount(dec!(1.01))
+            .dest_account(DestAccount::Spot)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/flexible/redeem".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("productId".to_owned(), "BNB001".to_string()),
+                    ("redeemAll".to_owned(), "true".to_string()),
+                    ("amount".to_owned(), "1.01".to_string()),
+                    ("destAccount".to_owned(), "SPOT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::http::{request::Request, Credentials, Method};
+use crate::simple_earn::product::SourceAccount;
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/simple-earn/flexible/subscribe`
+///
+/// Subscribe to a Simple Earn flexible product
+///
+/// * You need to open `Enable Spot & Margin Trading` permission for the API Key which requests this endpoint.
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+/// use rust_decimal_macros::dec;
+///
+/// let request = simple_earn::flexible_subscribe("BNB001", dec!(1.01));
+/// ```
+pub struct FlexibleSubscribe {
+    product_id: String,
+    amount: Decimal,
+    auto_subscribe: Option<bool>,
+    source_account: Option<SourceAccount>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl FlexibleSubscribe {
+    pub fn new(product_id: &str, amount: Decimal) -> Self {
+        Self {
+            product_id: product_id.to_owned(),
+            amount,
+            auto_subscribe: None,
+            source_account: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn auto_subscribe(mut self, auto_subscribe: bool) -> Self {
+        self.auto_subscribe = Some(auto_subscribe);
+        self
+    }
+
+    pub fn source_account(mut self, source_account: SourceAccount) -> Self {
+        self.source_account = Some(source_account);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<FlexibleSubscribe> for Request {
+    fn from(request: FlexibleSubscribe) -> Request {
+        let mut params = vec![
+            ("productId".to_owned(), request.product_id),
+            ("amount".to_owned(), request.amount.to_string()),
+        ];
+
+        if let Some(auto_subscribe) = request.auto_subscribe {
+            params.push(("autoSubscribe".to_owned(), auto_subscribe.to_string()));
+        }
+
+        if let Some(source_account) = request.source_account {
+            params.push(("sourceAccount".to_owned(), source_account.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/flexible/subscribe".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FlexibleSubscribe, SourceAccount};
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_flexible_subscribe_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = FlexibleSubscribe::new("BNB001", dec!(1.01))
+            .auto_subscribe(true)
+            .source_account(SourceAccount::Spot)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/flexible/subscribe".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("productId".to_owned(), "BNB001".to_string()),
+                    ("amount".to_owned(), "1.01".to_string()),
+                    ("autoSubscribe".to_owned(), "true".to_string()),
+                    ("sourceAccount".to_owned(), "SPOT".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::http::{request::Request, Credentials, Method};
+
+/// `POST /sapi/v1/simple-earn/locked/redeem`
+///
+/// Redeem a Simple Earn locked position before maturity
+///
+/// * You need to open `Enable Spot & Margin Trading` permission for the API Key which requests this endpoint.
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+///
+/// let request = simple_earn::locked_redeem(1);
+/// ```
+pub struct LockedRedeem {
+    position_id: u64,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl LockedRedeem {
+    pub fn new(position_id: u64) -> Self {
+        Self {
+            position_id,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<LockedRedeem> for Request {
+    fn from(request: LockedRedeem) -> Request {
+        let mut params = vec![("positionId".to_owned(), request.position_id.to_string())];
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/locked/redeem".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LockedRedeem;
+    use crate::http::{request::Request, Credentials, Method};
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_locked_redeem_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = LockedRedeem::new(1)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/locked/redeem".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("positionId".to_owned(), "1".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
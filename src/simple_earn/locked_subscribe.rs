@@ -0,0 +1,143 @@
+use crate::http::{request::Request, Credentials, Method};
+use crate::simple_earn::product::{RedeemTo, SourceAccount};
+use rust_decimal::Decimal;
+
+/// `POST /sapi/v1/simple-earn/locked/subscribe`
+///
+/// Subscribe to a Simple Earn locked product
+///
+/// * You need to open `Enable Spot & Margin Trading` permission for the API Key which requests this endpoint.
+/// * `redeemTo` only applies to products with auto subscription disabled, defaults to `SPOT`.
+///
+/// Weight(IP): 1
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::simple_earn;
+/// use rust_decimal_macros::dec;
+///
+/// let request = simple_earn::locked_subscribe("BNB*90", dec!(1.01));
+/// ```
+pub struct LockedSubscribe {
+    project_id: String,
+    amount: Decimal,
+    auto_subscribe: Option<bool>,
+    source_account: Option<SourceAccount>,
+    redeem_to: Option<RedeemTo>,
+    recv_window: Option<u64>,
+    credentials: Option<Credentials>,
+}
+
+impl LockedSubscribe {
+    pub fn new(project_id: &str, amount: Decimal) -> Self {
+        Self {
+            project_id: project_id.to_owned(),
+            amount,
+            auto_subscribe: None,
+            source_account: None,
+            redeem_to: None,
+            recv_window: None,
+            credentials: None,
+        }
+    }
+
+    pub fn auto_subscribe(mut self, auto_subscribe: bool) -> Self {
+        self.auto_subscribe = Some(auto_subscribe);
+        self
+    }
+
+    pub fn source_account(mut self, source_account: SourceAccount) -> Self {
+        self.source_account = Some(source_account);
+        self
+    }
+
+    pub fn redeem_to(mut self, redeem_to: RedeemTo) -> Self {
+        self.redeem_to = Some(redeem_to);
+        self
+    }
+
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.credentials = Some(credentials.clone());
+        self
+    }
+}
+
+impl From<LockedSubscribe> for Request {
+    fn from(request: LockedSubscribe) -> Request {
+        let mut params = vec![
+            ("projectId".to_owned(), request.project_id),
+            ("amount".to_owned(), request.amount.to_string()),
+        ];
+
+        if let Some(auto_subscribe) = request.auto_subscribe {
+            params.push(("autoSubscribe".to_owned(), auto_subscribe.to_string()));
+        }
+
+        if let Some(source_account) = request.source_account {
+            params.push(("sourceAccount".to_owned(), source_account.to_string()));
+        }
+
+        if let Some(redeem_to) = request.redeem_to {
+            params.push(("redeemTo".to_owned(), redeem_to.to_string()));
+        }
+
+        if let Some(recv_window) = request.recv_window {
+            params.push(("recvWindow".to_owned(), recv_window.to_string()));
+        }
+
+        Request {
+            path: "/sapi/v1/simple-earn/locked/subscribe".to_owned(),
+            method: Method::Post,
+            params,
+            credentials: request.credentials,
+            sign: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LockedSubscribe, RedeemTo, SourceAccount};
+    use crate::http::{request::Request, Credentials, Method};
+    use rust_decimal_macros::dec;
+
+    static API_KEY: &str = "api-key";
+    static API_SECRET: &str = "api-secret";
+
+    #[test]
+    fn simple_earn_locked_subscribe_convert_to_request_test() {
+        let credentials = Credentials::from_hmac(API_KEY.to_owned(), API_SECRET.to_owned());
+
+        let request: Request = LockedSubscribe::new("BNB*90", dec!(1.01))
+            .auto_subscribe(true)
+            .source_account(SourceAccount::Spot)
+            .redeem_to(RedeemTo::Flexible)
+            .recv_window(5000)
+            .credentials(&credentials)
+            .into();
+
+        assert_eq!(
+            request,
+            Request {
+                path: "/sapi/v1/simple-earn/locked/subscribe".to_owned(),
+                credentials: Some(credentials),
+                method: Method::Post,
+                params: vec![
+                    ("projectId".to_owned(), "BNB*90".to_string()),
+                    ("amount".to_owned(), "1.01".to_string()),
+                    ("autoSubscribe".to_owned(), "true".to_string()),
+                    ("sourceAccount".to_owned(), "SPOT".to_string()),
+                    ("redeemTo".to_owned(), "FLEXIBLE".to_string()),
+                    ("recvWindow".to_owned(), "5000".to_string()),
+                ],
+                sign: true
+            }
+        );
+    }
+}
@@ -0,0 +1,115 @@
+//! Simple Earn
+
+pub mod collateral_record;
+pub mod flexible_personal_left_quota;
+pub mod flexible_product_list;
+pub mod flexible_product_position;
+pub mod flexible_redeem;
+pub mod flexible_redemption_record;
+pub mod flexible_rewards_history;
+pub mod flexible_subscribe;
+pub mod flexible_subscription_record;
+pub mod locked_personal_left_quota;
+pub mod locked_product_list;
+pub mod locked_product_position;
+pub mod locked_redeem;
+pub mod locked_redemption_record;
+pub mod locked_rewards_history;
+pub mod locked_subscribe;
+pub mod locked_subscription_record;
+pub mod product;
+pub mod simple_account;
+
+use rust_decimal::Decimal;
+
+use collateral_record::CollateralRecord;
+use flexible_personal_left_quota::FlexiblePersonalLeftQuota;
+use flexible_product_list::FlexibleProductList;
+use flexible_product_position::FlexibleProductPosition;
+use flexible_redeem::FlexibleRedeem;
+use flexible_redemption_record::FlexibleRedemptionRecord;
+use flexible_rewards_history::FlexibleRewardsHistory;
+use flexible_subscribe::FlexibleSubscribe;
+use flexible_subscription_record::FlexibleSubscriptionRecord;
+use locked_personal_left_quota::LockedPersonalLeftQuota;
+use locked_product_list::LockedProductList;
+use locked_product_position::LockedProductPosition;
+use locked_redeem::LockedRedeem;
+use locked_redemption_record::LockedRedemptionRecord;
+use locked_rewards_history::LockedRewardsHistory;
+use locked_subscribe::LockedSubscribe;
+use locked_subscription_record::LockedSubscriptionRecord;
+use product::RewardsType;
+use simple_account::SimpleAccount;
+
+pub fn flexible_product_list() -> FlexibleProductList {
+    FlexibleProductList::new()
+}
+
+pub fn locked_product_list() -> LockedProductList {
+    LockedProductList::new()
+}
+
+pub fn flexible_subscribe(product_id: &str, amount: Decimal) -> FlexibleSubscribe {
+    FlexibleSubscribe::new(product_id, amount)
+}
+
+pub fn locked_subscribe(project_id: &str, amount: Decimal) -> LockedSubscribe {
+    LockedSubscribe::new(project_id, amount)
+}
+
+pub fn flexible_redeem(product_id: &str) -> FlexibleRedeem {
+    FlexibleRedeem::new(product_id)
+}
+
+pub fn locked_redeem(position_id: u64) -> LockedRedeem {
+    LockedRedeem::new(position_id)
+}
+
+pub fn flexible_product_position() -> FlexibleProductPosition {
+    FlexibleProductPosition::new()
+}
+
+pub fn locked_product_position() -> LockedProductPosition {
+    LockedProductPosition::new()
+}
+
+pub fn simple_account() -> SimpleAccount {
+    SimpleAccount::new()
+}
+
+pub fn flexible_personal_left_quota(product_id: &str) -> FlexiblePersonalLeftQuota {
+    FlexiblePersonalLeftQuota::new(product_id)
+}
+
+pub fn locked_personal_left_quota(project_id: &str) -> LockedPersonalLeftQuota {
+    LockedPersonalLeftQuota::new(project_id)
+}
+
+pub fn flexible_subscription_record() -> FlexibleSubscriptionRecord {
+    FlexibleSubscriptionRecord::new()
+}
+
+pub fn locked_subscription_record() -> LockedSubscriptionRecord {
+    LockedSubscriptionRecord::new()
+}
+
+pub fn flexible_redemption_record() -> FlexibleRedemptionRecord {
+    FlexibleRedemptionRecord::new()
+}
+
+pub fn locked_redemption_record() -> LockedRedemptionRecord {
+    LockedRedemptionRecord::new()
+}
+
+pub fn flexible_rewards_history(r#type: RewardsType) -> FlexibleRewardsHistory {
+    FlexibleRewardsHistory::new(r#type)
+}
+
+pub fn locked_rewards_history() -> LockedRewardsHistory {
+    LockedRewardsHistory::new()
+}
+
+pub fn collateral_record() -> CollateralRecord {
+    CollateralRecord::new()
+}
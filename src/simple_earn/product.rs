@@ -0,0 +1,34 @@
+use strum::Display;
+
+/// Account the subscription amount is deducted from.
+#[derive(Copy, Clone, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum SourceAccount {
+    Spot,
+    Fund,
+    All,
+}
+
+/// Account redeemed flexible product funds are credited to.
+#[derive(Copy, Clone, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum DestAccount {
+    Spot,
+    Fund,
+}
+
+/// Where locked product funds go at the end of the subscription.
+#[derive(Copy, Clone, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum RedeemTo {
+    Spot,
+    Flexible,
+}
+
+#[derive(Copy, Clone, Display)]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum RewardsType {
+    Bonus,
+    Realtime,
+    Rewards,
+}
@@ -0,0 +1,640 @@
+mod error;
+pub mod multiplexer;
+
+pub use error::*;
+
+use crate::environment::Environment;
+use crate::http::Credentials;
+use crate::recording::Recorder;
+use crate::websocket::{
+    control_message, ApiRequest, ControlMethod, Event, PendingRequests, Reply, Stream,
+};
+use futures_util::{ready, Sink, SinkExt, StreamExt};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+use tokio_tungstenite::{
+    connect_async,
+    tungstenite::{handshake::client::Response, protocol::Message, Error as TungsteniteError},
+    MaybeTlsStream, WebSocketStream,
+};
+
+/// Binance websocket client using Tungstenite.
+pub struct BinanceWebSocketClient;
+
+impl BinanceWebSocketClient {
+    /// Connect to `url`, e.g. built with [`crate::websocket::StreamUrl`] to
+    /// receive streams without subscribing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     market_stream::trade::TradeStream, tokio_tungstenite::BinanceWebSocketClient,
+    ///     websocket::StreamUrl,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let url = StreamUrl::new(&[TradeStream::new("BTCUSDT").into()])
+    ///         .url()
+    ///         .expect("Too many streams");
+    ///
+    ///     let (conn, _) = BinanceWebSocketClient::connect_async(&url)
+    ///         .await
+    ///         .expect("Failed to connect");
+    /// }
+    /// ```
+    pub async fn connect_async(
+        url: &str,
+    ) -> Result<(WebSocketState<MaybeTlsStream<TcpStream>>, Response), Error> {
+        let (socket, response) = connect_async(url).await?;
+
+        log::info!("Connected to {}", url);
+        log::debug!("Response HTTP code: {}", response.status());
+        log::debug!("Response headers:");
+        for (ref header, _value) in response.headers() {
+            log::debug!("* {}", header);
+        }
+
+        let mut conn = WebSocketState::new(socket);
+        conn.environment = Environment::from_url(url);
+
+        Ok((conn, response))
+    }
+
+    pub async fn connect_async_default(
+    ) -> Result<(WebSocketState<MaybeTlsStream<TcpStream>>, Response), Error> {
+        BinanceWebSocketClient::connect_async_with_environment(&Environment::Production).await
+    }
+
+    /// Connect to the combined streams of `environment`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     environment::Environment, tokio_tungstenite::BinanceWebSocketClient,
+    /// };
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (conn, _) = BinanceWebSocketClient::connect_async_with_environment(&Environment::Testnet)
+    ///         .await
+    ///         .expect("Failed to connect");
+    /// }
+    /// ```
+    pub async fn connect_async_with_environment(
+        environment: &Environment,
+    ) -> Result<(WebSocketState<MaybeTlsStream<TcpStream>>, Response), Error> {
+        let url = format!("{}/stream", environment.stream_url());
+        let (conn, response) = BinanceWebSocketClient::connect_async(&url).await?;
+
+        Ok((conn.environment(environment.clone()), response))
+    }
+
+    /// Connect to the WebSocket API of `environment`.
+    pub async fn connect_async_ws_api(
+        environment: &Environment,
+    ) -> Result<(WebSocketState<MaybeTlsStream<TcpStream>>, Response), Error> {
+        let (conn, response) =
+            BinanceWebSocketClient::connect_async(environment.ws_api_url()).await?;
+
+        Ok((conn.environment(environment.clone()), response))
+    }
+}
+
+/// Connection to the websocket server.
+///
+/// The connection is a [`futures_util::Stream`] of decoded [`Event`]s:
+/// ping, pong and close frames are handled without being yielded, and the
+/// stream ends once the connection is closed. It is also a [`Sink`] of raw
+/// messages.
+///
+/// # Example
+///
+/// ```no_run
+/// use binance_spot_connector_rust::{
+///     market_stream::trade::TradeStream, tokio_tungstenite::BinanceWebSocketClient,
+///     websocket::Event,
+/// };
+/// use futures_util::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (mut conn, _) = BinanceWebSocketClient::connect_async_default()
+///         .await
+///         .expect("Failed to connect");
+///
+///     conn.subscribe(vec![&TradeStream::new("BTCUSDT").into()])
+///         .await
+///         .expect("Failed to subscribe");
+///
+///     let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+///     loop {
+///         tokio::select! {
+///             event = conn.next() => match event {
+///                 Some(Ok(Event::Market { event, .. })) => println!("{:?}", event),
+///                 Some(Ok(event)) => println!("{:?}", event),
+///                 Some(Err(err)) => println!("{:?}", err),
+///                 None => break,
+///             },
+///             _ = interval.tick() => {
+///                 conn.subscriptions().await.expect("Failed to list subscriptions");
+///             }
+///         }
+///     }
+/// }
+/// ```
+pub struct WebSocketState<T> {
+    socket: WebSocketStream<T>,
+    id: u64,
+    environment: Option<Environment>,
+    pending: PendingRequests,
+    waiters: HashMap<u64, oneshot::Sender<Reply>>,
+    recorder: Option<Recorder>,
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> WebSocketState<T> {
+    pub fn new(socket: WebSocketStream<T>) -> Self {
+        Self {
+            socket,
+            id: 0,
+            environment: None,
+            pending: PendingRequests::default(),
+            waiters: HashMap::new(),
+            recorder: None,
+        }
+    }
+
+    /// Environment of the server, set when connecting to a known url.
+    ///
+    /// Signing with testnet credentials is refused by production environments.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Record the text frames received by the [`futures_util::Stream`], see
+    /// [`crate::recording`].
+    ///
+    /// Recording stops on the first write error.
+    pub fn record(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn check_credentials(&self, credentials: &Credentials) -> Result<(), Error> {
+        match &self.environment {
+            Some(environment) if !environment.accepts(credentials) => {
+                Err(Error::TestnetCredentials)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn send_control(
+        &mut self,
+        method: ControlMethod,
+        params: Vec<serde_json::Value>,
+    ) -> Result<u64, Error> {
+        let id = self.id;
+        self.id += 1;
+
+        let message = Message::Text(control_message(method, params, id));
+        log::debug!("Sent {}", message);
+
+        self.socket.send(message).await?;
+        self.pending.insert(id, method);
+
+        Ok(id)
+    }
+
+    async fn send_api(&mut self, request: ApiRequest) -> Result<u64, Error> {
+        let id = self.id;
+        self.id += 1;
+
+        let message = Message::Text(request.to_message(id));
+        log::debug!("Sent {}", message);
+
+        self.socket.send(message).await?;
+        self.pending.insert(id, ControlMethod::Api);
+
+        Ok(id)
+    }
+
+    /// Sends `SUBSCRIBE` message for the given `streams`.
+    ///
+    /// `streams` are not validated. Invalid streams will be
+    /// accepted by the server, but no data will be sent.
+    /// Requests to subscribe an existing stream will be ignored
+    /// by the server.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "method": "SUBSCRIBE", "params": [ <streams> ], "id": <id> }
+    /// ```
+    pub async fn subscribe(
+        &mut self,
+        streams: impl IntoIterator<Item = &Stream>,
+    ) -> Result<u64, Error> {
+        self.send_control(
+            ControlMethod::Subscribe,
+            streams.into_iter().map(|s| s.as_str().into()).collect(),
+        )
+        .await
+    }
+
+    /// Sends `UNSUBSCRIBE` message for the given `streams`.
+    ///
+    /// `streams` are not validated. Non-existing streams will be
+    /// ignored by the server.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "method": "UNSUBSCRIBE", "params": [ <streams> ], "id": <id> }
+    /// ```
+    pub async fn unsubscribe(
+        &mut self,
+        streams: impl IntoIterator<Item = &Stream>,
+    ) -> Result<u64, Error> {
+        self.send_control(
+            ControlMethod::Unsubscribe,
+            streams.into_iter().map(|s| s.as_str().into()).collect(),
+        )
+        .await
+    }
+
+    /// Sends `LIST_SUBSCRIPTIONS` message.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "result": [ <streams> ], "id": <id> }
+    /// ```
+    pub async fn subscriptions(&mut self) -> Result<u64, Error> {
+        self.send_control(ControlMethod::ListSubscriptions, vec![])
+            .await
+    }
+
+    /// Sends `SET_PROPERTY` message for the `combined` property.
+    ///
+    /// When `combined` is `true`, stream events are wrapped as
+    /// `{"stream":"<streamName>","data":<rawPayload>}`.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "method": "SET_PROPERTY", "params": [ "combined", <combined> ], "id": <id> }
+    /// ```
+    pub async fn set_combined_property(&mut self, combined: bool) -> Result<u64, Error> {
+        self.send_control(
+            ControlMethod::SetProperty,
+            vec!["combined".into(), combined.into()],
+        )
+        .await
+    }
+
+    /// Sends `GET_PROPERTY` message for the `combined` property.
+    ///
+    /// Returns the message `id`. The value is replied as a [`crate::websocket::ControlResponse::Property`].
+    /// ```json
+    /// { "method": "GET_PROPERTY", "params": [ "combined" ], "id": <id> }
+    /// ```
+    pub async fn combined_property(&mut self) -> Result<u64, Error> {
+        self.send_control(ControlMethod::GetProperty, vec!["combined".into()])
+            .await
+    }
+
+    /// Sends `session.logon` WebSocket API request.
+    ///
+    /// Authenticates the connection with `credentials`, so that following
+    /// requests such as [`Self::user_data_stream_subscribe`] don't need to
+    /// be signed. Only Ed25519 keys are accepted by the server.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "session.logon", "params": { "apiKey": <key>, "signature": <signature>, "timestamp": <timestamp> } }
+    /// ```
+    pub async fn session_logon(&mut self, credentials: &Credentials) -> Result<u64, Error> {
+        self.check_credentials(credentials)?;
+        let request = ApiRequest::new("session.logon")
+            .sign(credentials)
+            .map_err(|_| Error::InvalidApiSecret)?;
+
+        self.send_api(request).await
+    }
+
+    /// Sends `userDataStream.subscribe` WebSocket API request.
+    ///
+    /// Requires a session authenticated with [`Self::session_logon`].
+    /// User data events are then pushed on this connection.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe" }
+    /// ```
+    pub async fn user_data_stream_subscribe(&mut self) -> Result<u64, Error> {
+        self.send_api(ApiRequest::new("userDataStream.subscribe"))
+            .await
+    }
+
+    /// Sends `userDataStream.subscribe.signature` WebSocket API request.
+    ///
+    /// Subscribes to the user data of the `credentials` account without
+    /// authenticating the session. Several accounts can be subscribed on
+    /// the same connection.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe.signature", "params": { "apiKey": <key>, "timestamp": <timestamp>, "signature": <signature> } }
+    /// ```
+    pub async fn user_data_stream_subscribe_signature(
+        &mut self,
+        credentials: &Credentials,
+    ) -> Result<u64, Error> {
+        self.check_credentials(credentials)?;
+        let request = ApiRequest::new("userDataStream.subscribe.signature")
+            .sign(credentials)
+            .map_err(|_| Error::InvalidApiSecret)?;
+
+        self.send_api(request).await
+    }
+
+    /// Sends `userDataStream.subscribe.listenToken` WebSocket API request.
+    ///
+    /// Subscribes to the margin user data with a `listen_token` issued by
+    /// [`crate::margin_stream::new_listen_token`].
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe.listenToken", "params": { "listenToken": <listen_token> } }
+    /// ```
+    pub async fn user_data_stream_subscribe_listen_token(
+        &mut self,
+        listen_token: &str,
+    ) -> Result<u64, Error> {
+        let request = ApiRequest::new("userDataStream.subscribe.listenToken")
+            .param("listenToken", listen_token);
+
+        self.send_api(request).await
+    }
+
+    /// Sends `userDataStream.unsubscribe` WebSocket API request.
+    ///
+    /// Stops the subscription `subscription_id`, or all subscriptions of
+    /// the connection when `None`.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.unsubscribe", "params": { "subscriptionId": <subscription_id> } }
+    /// ```
+    pub async fn user_data_stream_unsubscribe(
+        &mut self,
+        subscription_id: Option<u64>,
+    ) -> Result<u64, Error> {
+        let mut request = ApiRequest::new("userDataStream.unsubscribe");
+        if let Some(subscription_id) = subscription_id {
+            request = request.param("subscriptionId", subscription_id);
+        }
+
+        self.send_api(request).await
+    }
+
+    /// Match a received `message` with the control message or WebSocket
+    /// API request it replies to, resolving the futures returned by
+    /// [`Self::reply`].
+    ///
+    /// Returns `None` for stream events and for messages not replying to
+    /// a pending request.
+    pub fn handle_reply(&mut self, message: &Message) -> Option<Reply> {
+        let reply = match message {
+            Message::Text(text) => self.pending.handle(text)?,
+            _ => return None,
+        };
+
+        if let Some(waiter) = self.waiters.remove(&reply.id) {
+            // The future may have been dropped.
+            let _ = waiter.send(reply.clone());
+        }
+
+        Some(reply)
+    }
+
+    /// Whether the message `id` was sent and not replied yet.
+    pub fn is_pending(&self, id: u64) -> bool {
+        self.pending.contains(id)
+    }
+
+    /// Future resolved with the reply to the message `id` once it is read
+    /// and passed to [`Self::handle_reply`], or with `None` if `id` is not
+    /// pending.
+    ///
+    /// The future doesn't borrow the connection, so it can be awaited while
+    /// reading messages.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     market_stream::trade::TradeStream, tokio_tungstenite::BinanceWebSocketClient,
+    /// };
+    /// use futures_util::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (mut conn, _) = BinanceWebSocketClient::connect_async_default()
+    ///         .await
+    ///         .expect("Failed to connect");
+    ///
+    ///     let id = conn
+    ///         .subscribe(vec![&TradeStream::new("BTCUSDT").into()])
+    ///         .await
+    ///         .expect("Failed to subscribe");
+    ///     let reply = conn.reply(id);
+    ///     tokio::spawn(async move {
+    ///         if let Some(reply) = reply.await {
+    ///             println!("Subscribed: {:?}", reply.result);
+    ///         }
+    ///     });
+    ///
+    ///     while let Some(Ok(event)) = conn.next().await {
+    ///         println!("{:?}", event);
+    ///     }
+    /// }
+    /// ```
+    pub fn reply(&mut self, id: u64) -> impl Future<Output = Option<Reply>> {
+        let (sender, receiver) = oneshot::channel();
+        if self.pending.contains(id) {
+            self.waiters.insert(id, sender);
+        }
+
+        async move { receiver.await.ok() }
+    }
+
+    pub async fn close(mut self) -> Result<(), Error> {
+        self.socket.close(None).await.map_err(Error::Socket)
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> futures_util::Stream for WebSocketState<T> {
+    type Item = Result<Event, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match ready!(self.socket.poll_next_unpin(cx)) {
+                Some(Ok(message)) => message,
+                None
+                | Some(Err(TungsteniteError::ConnectionClosed | TungsteniteError::AlreadyClosed)) => {
+                    return Poll::Ready(None)
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(Error::Socket(err)))),
+            };
+
+            if let (Some(recorder), Message::Text(text)) = (self.recorder.as_mut(), &message) {
+                if let Err(err) = recorder.record(text) {
+                    log::error!("Recording stopped: {}", err);
+                    self.recorder = None;
+                }
+            }
+
+            if let Some(reply) = self.handle_reply(&message) {
+                return Poll::Ready(Some(Ok(Event::Reply(reply))));
+            }
+
+            match message {
+                Message::Text(text) => {
+                    return Poll::Ready(Some(Event::from_text(&text).map_err(Error::Deserialize)))
+                }
+                // Pong replies are queued by Tungstenite and sent on the next read.
+                Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+                // The stream ends on the next read.
+                Message::Close(frame) => log::info!("Connection closed: {:?}", frame),
+                Message::Binary(data) => log::debug!("Ignored binary message: {:?}", data),
+            }
+        }
+    }
+}
+
+impl<T: AsyncRead + AsyncWrite + Unpin> Sink<Message> for WebSocketState<T> {
+    type Error = Error;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.socket.poll_ready_unpin(cx).map_err(Error::Socket)
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, message: Message) -> Result<(), Error> {
+        self.socket.start_send_unpin(message).map_err(Error::Socket)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.socket.poll_flush_unpin(cx).map_err(Error::Socket)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Error>> {
+        self.socket.poll_close_unpin(cx).map_err(Error::Socket)
+    }
+}
+
+impl<T> From<WebSocketState<T>> for WebSocketStream<T> {
+    fn from(conn: WebSocketState<T>) -> WebSocketStream<T> {
+        conn.socket
+    }
+}
+
+impl<T> AsMut<WebSocketStream<T>> for WebSocketState<T> {
+    fn as_mut(&mut self) -> &mut WebSocketStream<T> {
+        &mut self.socket
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BinanceWebSocketClient;
+    use crate::market_stream::event::MarketEvent;
+    use crate::recording::{Format, Recorder, Replay};
+    use crate::websocket::{ControlResponse, Event, Stream};
+    use futures_util::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::protocol::Message;
+
+    #[tokio::test]
+    async fn websocket_state_stream_test() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+
+            // Reply to the subscription, then push an event between pings.
+            socket.next().await.unwrap().unwrap();
+            socket
+                .send(Message::Text(r#"{"result":null,"id":0}"#.to_owned()))
+                .await
+                .unwrap();
+            socket.send(Message::Ping(vec![1])).await.unwrap();
+            socket
+                .send(Message::Text(r#"{"stream":"btcusdt@bookTicker","data":{"u":400900217,"s":"BTCUSDT","b":"25.35","B":"31.21","a":"25.36","A":"40.66"}}"#.to_owned()))
+                .await
+                .unwrap();
+            assert_eq!(
+                socket.next().await.unwrap().unwrap(),
+                Message::Pong(vec![1])
+            );
+            socket.close(None).await.unwrap();
+        });
+
+        let path =
+            std::env::temp_dir().join(format!("websocket_state_{}.jsonl", std::process::id()));
+        let recorder = Recorder::create(&path, Format::Jsonl).unwrap();
+        let (conn, _) = BinanceWebSocketClient::connect_async(&url).await.unwrap();
+        let mut conn = conn.record(recorder);
+        let id = conn
+            .subscribe(vec![&Stream::new("btcusdt@bookTicker")])
+            .await
+            .unwrap();
+
+        match conn.next().await {
+            Some(Ok(Event::Reply(reply))) => {
+                assert_eq!(reply.id, id);
+                assert_eq!(reply.result, Ok(ControlResponse::Done));
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+        match conn.next().await {
+            Some(Ok(Event::Market {
+                stream,
+                event: MarketEvent::BookTicker(ticker),
+            })) => {
+                assert_eq!(stream.as_deref(), Some("btcusdt@bookTicker"));
+                assert_eq!(ticker.symbol, "BTCUSDT");
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+        assert!(conn.next().await.is_none());
+
+        drop(conn);
+        let replay = Replay::open(&path).unwrap();
+        assert_eq!(replay.count(), 2);
+        std::fs::remove_file(path).unwrap();
+    }
+}
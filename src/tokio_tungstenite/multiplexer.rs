@@ -0,0 +1,730 @@
+use crate::tokio_tungstenite::{BinanceWebSocketClient, Error, WebSocketState};
+use crate::websocket::{Stream, StreamUrl, MAX_STREAMS_PER_CONNECTION};
+use futures_util::StreamExt;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::{Instant, MissedTickBehavior};
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream};
+
+/// Maximum number of streams per `SUBSCRIBE` message or connection url.
+const STREAMS_PER_MESSAGE: usize = 200;
+
+/// Market streams spread across a pool of connections.
+///
+/// * Streams are added to the connections with spare capacity, new
+///   connections are opened once all connections hold
+///   `max_streams_per_connection` streams.
+/// * Connections are opened at most once per `connection_interval`, below
+///   the limit of 300 connection attempts per 5 minutes per IP.
+/// * `SUBSCRIBE` and `UNSUBSCRIBE` messages are sent at most once per
+///   `message_interval` on each connection, below the limit of 5 incoming
+///   messages per second which also counts `PONG` frames.
+/// * Events of all connections are merged into a single channel.
+/// * When a connection drops, its streams are redistributed to the other
+///   connections or to new connections after `reconnect_delay`. Streams
+///   rejected by the server in reply to a `SUBSCRIBE` message are
+///   subscribed again after `reconnect_delay` as well.
+///
+/// # Example
+///
+/// ```no_run
+/// use binance_spot_connector_rust::{
+///     market_stream::{self, ticker::TickerStream},
+///     tokio_tungstenite::multiplexer::StreamMultiplexer,
+/// };
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (multiplexer, mut messages) = StreamMultiplexer::new("wss://stream.binance.com:9443")
+///         .max_streams_per_connection(200)
+///         .start();
+///
+///     let streams: Vec<_> = ["BTCUSDT", "ETHUSDT", "BNBUSDT"]
+///         .iter()
+///         .map(|symbol| market_stream::diff_depth_100ms(symbol).into())
+///         .chain(std::iter::once(TickerStream::all_symbols().into()))
+///         .collect();
+///     multiplexer.subscribe(&streams).expect("Multiplexer stopped");
+///
+///     while let Some(message) = messages.recv().await {
+///         println!("{} on connection {}: {}", message.stream, message.connection, message.data);
+///     }
+/// }
+/// ```
+pub struct StreamMultiplexer {
+    base_url: String,
+    max_streams: usize,
+    message_interval: Duration,
+    connection_interval: Duration,
+    reconnect_delay: Duration,
+}
+
+impl StreamMultiplexer {
+    /// Multiplexer connecting to `base_url`, e.g. `wss://stream.binance.com:9443`.
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_owned(),
+            max_streams: MAX_STREAMS_PER_CONNECTION,
+            message_interval: Duration::from_millis(250),
+            connection_interval: Duration::from_secs(1),
+            reconnect_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Streams per connection, capped to 1024.
+    pub fn max_streams_per_connection(mut self, max_streams: usize) -> Self {
+        self.max_streams = max_streams.clamp(1, MAX_STREAMS_PER_CONNECTION);
+        self
+    }
+
+    /// Minimum delay between two control messages on a connection, 250ms by default.
+    pub fn message_interval(mut self, message_interval: Duration) -> Self {
+        self.message_interval = message_interval;
+        self
+    }
+
+    /// Minimum delay between two connection attempts, 1s by default.
+    pub fn connection_interval(mut self, connection_interval: Duration) -> Self {
+        self.connection_interval = connection_interval;
+        self
+    }
+
+    /// Delay before redistributing the streams of a dropped connection, or
+    /// subscribing again the streams of a failed `SUBSCRIBE` message, 1s by
+    /// default.
+    pub fn reconnect_delay(mut self, reconnect_delay: Duration) -> Self {
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    /// Spawn the multiplexer on the current tokio runtime.
+    ///
+    /// Returns the handle to update the streams and the receiver of the
+    /// merged events. The connections are closed once the handle is dropped.
+    pub fn start(
+        self,
+    ) -> (
+        StreamMultiplexerHandle,
+        mpsc::UnboundedReceiver<StreamMessage>,
+    ) {
+        let (commands, commands_receiver) = mpsc::unbounded_channel();
+        let (events, events_receiver) = mpsc::unbounded_channel();
+
+        tokio::spawn(
+            Supervisor {
+                config: self,
+                connections: BTreeMap::new(),
+                next_id: 0,
+                next_connection: Instant::now(),
+                events,
+            }
+            .run(commands_receiver),
+        );
+
+        (StreamMultiplexerHandle { commands }, events_receiver)
+    }
+}
+
+/// Handle of a running [`StreamMultiplexer`].
+pub struct StreamMultiplexerHandle {
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+impl StreamMultiplexerHandle {
+    /// Subscribe `streams`, ignoring the streams already subscribed.
+    pub fn subscribe<'a>(
+        &self,
+        streams: impl IntoIterator<Item = &'a Stream>,
+    ) -> Result<(), MultiplexerStopped> {
+        self.send(Command::Subscribe(stream_names(streams)))
+    }
+
+    /// Unsubscribe `streams`, closing the connections left without streams.
+    pub fn unsubscribe<'a>(
+        &self,
+        streams: impl IntoIterator<Item = &'a Stream>,
+    ) -> Result<(), MultiplexerStopped> {
+        self.send(Command::Unsubscribe(stream_names(streams)))
+    }
+
+    fn send(&self, command: Command) -> Result<(), MultiplexerStopped> {
+        self.commands.send(command).map_err(|_| MultiplexerStopped)
+    }
+}
+
+/// The multiplexer task is no longer running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiplexerStopped;
+
+impl fmt::Display for MultiplexerStopped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Stream multiplexer stopped")
+    }
+}
+
+impl std::error::Error for MultiplexerStopped {}
+
+/// Event received on one of the connections of a [`StreamMultiplexer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamMessage {
+    /// Connection which received the event.
+    pub connection: usize,
+    pub stream: String,
+    pub data: serde_json::Value,
+}
+
+/// Combined stream envelope.
+#[derive(Deserialize)]
+struct Envelope {
+    stream: String,
+    data: serde_json::Value,
+}
+
+enum Command {
+    Subscribe(Vec<String>),
+    Unsubscribe(Vec<String>),
+}
+
+impl Command {
+    /// Split into messages of at most [`STREAMS_PER_MESSAGE`] streams.
+    fn chunks(self) -> Vec<Command> {
+        match self {
+            Command::Subscribe(streams) => streams
+                .chunks(STREAMS_PER_MESSAGE)
+                .map(|chunk| Command::Subscribe(chunk.to_vec()))
+                .collect(),
+            Command::Unsubscribe(streams) => streams
+                .chunks(STREAMS_PER_MESSAGE)
+                .map(|chunk| Command::Unsubscribe(chunk.to_vec()))
+                .collect(),
+        }
+    }
+}
+
+enum SupervisorEvent {
+    Command(Option<Command>),
+    Dropped(usize),
+    /// Streams of a `SUBSCRIBE` message rejected by the server.
+    SubscribeFailed(usize, Vec<String>),
+}
+
+struct Connection {
+    streams: HashSet<String>,
+    commands: mpsc::UnboundedSender<Command>,
+}
+
+struct Supervisor {
+    config: StreamMultiplexer,
+    connections: BTreeMap<usize, Connection>,
+    next_id: usize,
+    /// Earliest time to open the next connection.
+    next_connection: Instant,
+    events: mpsc::UnboundedSender<StreamMessage>,
+}
+
+impl Supervisor {
+    async fn run(mut self, mut commands: mpsc::UnboundedReceiver<Command>) {
+        let (connection_events, mut connection_events_receiver) = mpsc::unbounded_channel();
+
+        loop {
+            let event = tokio::select! {
+                command = commands.recv() => SupervisorEvent::Command(command),
+                Some(event) = connection_events_receiver.recv() => event,
+            };
+
+            match event {
+                SupervisorEvent::Command(Some(Command::Subscribe(streams))) => {
+                    self.subscribe(streams, &connection_events)
+                }
+                SupervisorEvent::Command(Some(Command::Unsubscribe(streams))) => {
+                    self.unsubscribe(streams)
+                }
+                SupervisorEvent::Command(None) => break,
+                SupervisorEvent::Dropped(id) => {
+                    if let Some(connection) = self.connections.remove(&id) {
+                        log::warn!(
+                            "Connection {} dropped, redistributing {} streams",
+                            id,
+                            connection.streams.len()
+                        );
+                        self.subscribe(
+                            connection.streams.into_iter().collect(),
+                            &connection_events,
+                        );
+                    }
+                }
+                SupervisorEvent::SubscribeFailed(id, streams) => {
+                    // Streams unsubscribed or redistributed since then are
+                    // no longer held by the connection.
+                    let streams = match self.connections.get_mut(&id) {
+                        Some(connection) => streams
+                            .into_iter()
+                            .filter(|stream| connection.streams.remove(stream))
+                            .collect(),
+                        None => vec![],
+                    };
+                    if !streams.is_empty() {
+                        log::warn!(
+                            "Connection {} failed to subscribe {} streams, subscribing again",
+                            id,
+                            streams.len()
+                        );
+                        self.subscribe(streams, &connection_events);
+                    }
+                }
+            }
+        }
+
+        log::debug!("Stream multiplexer stopped");
+    }
+
+    fn subscribe(
+        &mut self,
+        streams: Vec<String>,
+        connection_events: &mpsc::UnboundedSender<SupervisorEvent>,
+    ) {
+        let mut known: HashSet<&String> = self
+            .connections
+            .values()
+            .flat_map(|connection| connection.streams.iter())
+            .collect();
+        let mut streams_to_add = vec![];
+        for stream in &streams {
+            if known.insert(stream) {
+                streams_to_add.push(stream.clone());
+            }
+        }
+
+        let loads: Vec<(usize, usize)> = self
+            .connections
+            .iter()
+            .map(|(id, connection)| (*id, connection.streams.len()))
+            .collect();
+        let plan = plan_subscriptions(&loads, streams_to_add, self.config.max_streams);
+
+        for (id, streams) in plan.assigned {
+            if let Some(connection) = self.connections.get_mut(&id) {
+                connection.streams.extend(streams.iter().cloned());
+                // A closed connection is reported as dropped with its streams.
+                let _ = connection.commands.send(Command::Subscribe(streams));
+            }
+        }
+
+        for streams in plan.new_connections {
+            self.spawn_connection(streams, connection_events);
+        }
+    }
+
+    fn unsubscribe(&mut self, streams: Vec<String>) {
+        let streams: HashSet<String> = streams.into_iter().collect();
+
+        let mut empty = vec![];
+        for (id, connection) in self.connections.iter_mut() {
+            let removed: Vec<String> = connection.streams.intersection(&streams).cloned().collect();
+            if removed.is_empty() {
+                continue;
+            }
+
+            for stream in &removed {
+                connection.streams.remove(stream);
+            }
+            if connection.streams.is_empty() {
+                empty.push(*id);
+            } else {
+                let _ = connection.commands.send(Command::Unsubscribe(removed));
+            }
+        }
+
+        // Dropping the command sender closes the connection.
+        for id in empty {
+            self.connections.remove(&id);
+        }
+    }
+
+    fn spawn_connection(
+        &mut self,
+        streams: Vec<String>,
+        connection_events: &mpsc::UnboundedSender<SupervisorEvent>,
+    ) {
+        let id = self.next_id;
+        self.next_id += 1;
+        let open_at = self.next_connection.max(Instant::now());
+        self.next_connection = open_at + self.config.connection_interval;
+
+        let (commands, commands_receiver) = mpsc::unbounded_channel();
+        self.connections.insert(
+            id,
+            Connection {
+                streams: streams.iter().cloned().collect(),
+                commands,
+            },
+        );
+
+        let task = ConnectionTask {
+            id,
+            base_url: self.config.base_url.clone(),
+            open_at,
+            message_interval: self.config.message_interval,
+            reconnect_delay: self.config.reconnect_delay,
+            events: self.events.clone(),
+            supervisor: connection_events.clone(),
+        };
+        tokio::spawn(task.run(streams, commands_receiver));
+    }
+}
+
+struct ConnectionTask {
+    id: usize,
+    base_url: String,
+    open_at: Instant,
+    message_interval: Duration,
+    reconnect_delay: Duration,
+    events: mpsc::UnboundedSender<StreamMessage>,
+    supervisor: mpsc::UnboundedSender<SupervisorEvent>,
+}
+
+impl ConnectionTask {
+    async fn run(self, streams: Vec<String>, mut commands: mpsc::UnboundedReceiver<Command>) {
+        let mut queue: VecDeque<Command> = Command::Subscribe(streams).chunks().into();
+        let first = match queue.pop_front() {
+            Some(Command::Subscribe(streams)) => to_streams(&streams),
+            _ => vec![],
+        };
+
+        let url = StreamUrl::new(&first)
+            .base_url(&self.base_url)
+            .url()
+            .expect("Streams per message are below the limit per connection");
+
+        tokio::time::sleep_until(self.open_at).await;
+        let mut conn = match BinanceWebSocketClient::connect_async(&url).await {
+            Ok((conn, _)) => conn,
+            Err(err) => {
+                log::warn!("Connection {} failed: {:?}", self.id, err);
+                self.drop_connection().await;
+                return;
+            }
+        };
+
+        // Streams of the `SUBSCRIBE` messages waiting for a reply, by id.
+        let mut subscribing: HashMap<u64, Vec<String>> = HashMap::new();
+        let mut ticker = tokio::time::interval(self.message_interval);
+        // Ticks missed while no message is queued must not be sent in a burst.
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                message = conn.as_mut().next() => match message {
+                    Some(Ok(message)) => {
+                        if let Some(reply) = conn.handle_reply(&message) {
+                            let streams = subscribing.remove(&reply.id);
+                            if let Err(err) = reply.result {
+                                log::error!(
+                                    "Connection {}: {} request {} failed: {}",
+                                    self.id,
+                                    reply.method.as_str(),
+                                    reply.id,
+                                    err
+                                );
+                                if let Some(streams) = streams {
+                                    self.subscribe_failed(streams);
+                                }
+                            }
+                        } else if let Message::Text(text) = message {
+                            match serde_json::from_str::<Envelope>(&text) {
+                                Ok(envelope) => {
+                                    let message = StreamMessage {
+                                        connection: self.id,
+                                        stream: envelope.stream,
+                                        data: envelope.data,
+                                    };
+                                    if self.events.send(message).is_err() {
+                                        break;
+                                    }
+                                }
+                                Err(_) => log::debug!("Connection {}: ignored {}", self.id, text),
+                            }
+                        }
+                    }
+                    Some(Err(err)) => {
+                        log::warn!("Connection {} failed: {:?}", self.id, err);
+                        self.drop_connection().await;
+                        return;
+                    }
+                    None => {
+                        self.drop_connection().await;
+                        return;
+                    }
+                },
+                command = commands.recv() => match command {
+                    Some(command) => queue.extend(command.chunks()),
+                    None => break,
+                },
+                _ = ticker.tick(), if !queue.is_empty() => {
+                    if let Some(command) = queue.pop_front() {
+                        let streams = match &command {
+                            Command::Subscribe(streams) => Some(streams.clone()),
+                            Command::Unsubscribe(_) => None,
+                        };
+                        match send_command(&mut conn, command).await {
+                            Ok(id) => {
+                                if let Some(streams) = streams {
+                                    subscribing.insert(id, streams);
+                                }
+                            }
+                            Err(err) => {
+                                log::warn!("Connection {} failed: {:?}", self.id, err);
+                                self.drop_connection().await;
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Err(err) = conn.close().await {
+            log::debug!("Connection {} closed with error: {:?}", self.id, err);
+        }
+    }
+
+    async fn drop_connection(&self) {
+        tokio::time::sleep(self.reconnect_delay).await;
+        // The multiplexer may have been stopped in the meantime.
+        let _ = self.supervisor.send(SupervisorEvent::Dropped(self.id));
+    }
+
+    /// Hand the streams of a rejected `SUBSCRIBE` message back to the
+    /// supervisor after `reconnect_delay`, without blocking the connection.
+    fn subscribe_failed(&self, streams: Vec<String>) {
+        let supervisor = self.supervisor.clone();
+        let event = SupervisorEvent::SubscribeFailed(self.id, streams);
+        let delay = self.reconnect_delay;
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let _ = supervisor.send(event);
+        });
+    }
+}
+
+async fn send_command(
+    conn: &mut WebSocketState<MaybeTlsStream<TcpStream>>,
+    command: Command,
+) -> Result<u64, Error> {
+    match command {
+        Command::Subscribe(streams) => conn.subscribe(&to_streams(&streams)).await,
+        Command::Unsubscribe(streams) => conn.unsubscribe(&to_streams(&streams)).await,
+    }
+}
+
+fn to_streams(names: &[String]) -> Vec<Stream> {
+    names.iter().map(|name| Stream::new(name)).collect()
+}
+
+fn stream_names<'a>(streams: impl IntoIterator<Item = &'a Stream>) -> Vec<String> {
+    streams
+        .into_iter()
+        .map(|stream| stream.as_str().to_owned())
+        .collect()
+}
+
+/// Streams added to existing connections and to new connections.
+#[derive(Debug, PartialEq, Eq)]
+struct SubscriptionPlan {
+    assigned: Vec<(usize, Vec<String>)>,
+    new_connections: Vec<Vec<String>>,
+}
+
+/// Split `streams` between the connections with spare capacity, given as
+/// `(id, number of streams)`, and new connections.
+fn plan_subscriptions(
+    loads: &[(usize, usize)],
+    streams: Vec<String>,
+    max_streams: usize,
+) -> SubscriptionPlan {
+    let mut streams = streams.into_iter().peekable();
+
+    let mut assigned = vec![];
+    for (id, load) in loads {
+        let capacity = max_streams.saturating_sub(*load);
+        let batch: Vec<String> = streams.by_ref().take(capacity).collect();
+        if !batch.is_empty() {
+            assigned.push((*id, batch));
+        }
+        if streams.peek().is_none() {
+            break;
+        }
+    }
+
+    let remaining: Vec<String> = streams.collect();
+    let new_connections = remaining
+        .chunks(max_streams)
+        .map(|chunk| chunk.to_vec())
+        .collect();
+
+    SubscriptionPlan {
+        assigned,
+        new_connections,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{plan_subscriptions, StreamMultiplexer};
+    use crate::websocket::Stream;
+    use futures_util::{SinkExt, StreamExt};
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
+    use std::time::{Duration, Instant};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::{
+        handshake::server::{Request, Response},
+        Message,
+    };
+
+    fn streams(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("s{}@trade", i)).collect()
+    }
+
+    #[test]
+    fn multiplexer_plan_subscriptions_test() {
+        let plan = plan_subscriptions(&[(0, 3), (1, 5), (2, 4)], streams(6), 5);
+
+        assert_eq!(
+            plan.assigned,
+            vec![
+                (0, vec!["s0@trade".to_owned(), "s1@trade".to_owned()]),
+                (2, vec!["s2@trade".to_owned()]),
+            ]
+        );
+        assert_eq!(plan.new_connections.len(), 1);
+        assert_eq!(plan.new_connections[0].len(), 3);
+
+        let plan = plan_subscriptions(&[], streams(11), 5);
+        assert!(plan.assigned.is_empty());
+        assert_eq!(
+            plan.new_connections
+                .iter()
+                .map(Vec::len)
+                .collect::<Vec<_>>(),
+            vec![5, 5, 1]
+        );
+    }
+
+    #[allow(clippy::result_large_err)]
+    #[tokio::test]
+    async fn multiplexer_shards_streams_test() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Replies on each connection with the streams of its url.
+        let dropped = Arc::new(AtomicBool::new(false));
+        tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                let dropped = dropped.clone();
+                tokio::spawn(async move {
+                    let mut query = String::new();
+                    let callback = |request: &Request, response: Response| {
+                        query = request.uri().query().unwrap_or_default().to_owned();
+                        Ok(response)
+                    };
+                    let mut socket = tokio_tungstenite::accept_hdr_async(tcp, callback)
+                        .await
+                        .unwrap();
+
+                    let streams = query.trim_start_matches("streams=").to_owned();
+                    let message = format!(r#"{{"stream":"{}","data":{{}}}}"#, streams);
+                    socket.send(Message::Text(message)).await.unwrap();
+
+                    // Drop the first connection of `c@trade`.
+                    if streams == "c@trade" && !dropped.swap(true, Ordering::SeqCst) {
+                        return;
+                    }
+                    while let Some(Ok(_)) = socket.next().await {}
+                });
+            }
+        });
+
+        let started = Instant::now();
+        let (multiplexer, mut messages) = StreamMultiplexer::new(&base_url)
+            .max_streams_per_connection(2)
+            .connection_interval(Duration::from_millis(100))
+            .reconnect_delay(Duration::from_millis(10))
+            .start();
+        let streams: Vec<Stream> = ["a@trade", "b@trade", "c@trade"]
+            .iter()
+            .map(|name| Stream::new(name))
+            .collect();
+        multiplexer.subscribe(&streams).unwrap();
+
+        let mut received = [
+            messages.recv().await.unwrap(),
+            messages.recv().await.unwrap(),
+        ];
+        received.sort_by_key(|message| message.connection);
+
+        assert_eq!(received[0].connection, 0);
+        assert_eq!(received[0].stream, "a@trade/b@trade");
+        assert_eq!(received[1].connection, 1);
+        assert_eq!(received[1].stream, "c@trade");
+
+        let message = messages.recv().await.unwrap();
+        assert_eq!(message.connection, 2);
+        assert_eq!(message.stream, "c@trade");
+        // The third connection waits for two connection intervals.
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn multiplexer_subscribe_failed_test() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Rejects the first `SUBSCRIBE` message, then pushes an event of the
+        // subscribed streams.
+        let rejected = Arc::new(AtomicBool::new(false));
+        tokio::spawn(async move {
+            while let Ok((tcp, _)) = listener.accept().await {
+                let rejected = rejected.clone();
+                tokio::spawn(async move {
+                    let mut socket = tokio_tungstenite::accept_async(tcp).await.unwrap();
+                    while let Some(Ok(Message::Text(text))) = socket.next().await {
+                        let request: serde_json::Value = serde_json::from_str(&text).unwrap();
+                        let id = &request["id"];
+                        if !rejected.swap(true, Ordering::SeqCst) {
+                            let reply = format!(
+                                r#"{{"error":{{"code":2,"msg":"Invalid request"}},"id":{}}}"#,
+                                id
+                            );
+                            socket.send(Message::Text(reply)).await.unwrap();
+                            continue;
+                        }
+
+                        let reply = format!(r#"{{"result":null,"id":{}}}"#, id);
+                        socket.send(Message::Text(reply)).await.unwrap();
+                        let message =
+                            format!(r#"{{"stream":{},"data":{{}}}}"#, request["params"][0]);
+                        socket.send(Message::Text(message)).await.unwrap();
+                    }
+                });
+            }
+        });
+
+        let (multiplexer, mut messages) = StreamMultiplexer::new(&base_url)
+            .max_streams_per_connection(2)
+            .message_interval(Duration::from_millis(10))
+            .reconnect_delay(Duration::from_millis(10))
+            .start();
+        multiplexer.subscribe(&[Stream::new("a@trade")]).unwrap();
+        multiplexer.subscribe(&[Stream::new("b@trade")]).unwrap();
+
+        let message = messages.recv().await.unwrap();
+        assert_eq!(message.connection, 0);
+        assert_eq!(message.stream, "b@trade");
+    }
+}
@@ -0,0 +1,458 @@
+use crate::http::request::Request;
+use crate::trade::cancel_open_orders::CancelOpenOrders;
+use crate::trade::cancel_order::CancelOrder;
+use serde::Deserialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+/// Requests sent at once when the batch does not set a maximum.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+/// Orders sent concurrently within the order count limits of the account.
+///
+/// Spot has no batch order endpoint: the orders are sent one request each,
+/// up to `max_concurrency` at a time, with `ureq::BinanceHttpClient::send_batch`
+/// (blocking) or `hyper::BinanceHttpClient::send_batch` (async), which return
+/// one result per request in the order of the batch.
+///
+/// Before the first order, the current usage is requested with
+/// `GET /api/v3/rateLimit/order`, then updated from the `X-MBX-ORDER-COUNT-*`
+/// headers of the responses. Once a limit is reached, the next orders wait
+/// for the next interval.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::trade::{self, batch::OrderBatch, order::{Side, TimeInForce}};
+/// use rust_decimal_macros::dec;
+///
+/// let ladder = (1..=50).map(|level| {
+///     trade::new_order("BNBUSDT", Side::Buy, "LIMIT")
+///         .time_in_force(TimeInForce::Gtc)
+///         .price(dec!(300) - rust_decimal::Decimal::from(level))
+///         .quantity(dec!(0.1))
+/// });
+/// let batch = OrderBatch::new(ladder).max_concurrency(5);
+/// ```
+pub struct OrderBatch {
+    requests: Vec<Request>,
+    max_concurrency: usize,
+    check_usage: bool,
+    limiter: OrderRateLimiter,
+}
+
+impl OrderBatch {
+    pub fn new<R: Into<Request>>(requests: impl IntoIterator<Item = R>) -> Self {
+        Self {
+            requests: requests.into_iter().map(Into::into).collect(),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            check_usage: true,
+            limiter: OrderRateLimiter::default(),
+        }
+    }
+
+    pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Whether to request the current order count usage before the first
+    /// order, `true` by default. Otherwise, the limits of `rate_limiter`
+    /// start from their counts.
+    pub fn check_usage(mut self, check_usage: bool) -> Self {
+        self.check_usage = check_usage;
+        self
+    }
+
+    /// Limits to respect, [`OrderRateLimiter::default`] otherwise.
+    pub fn rate_limiter(mut self, limiter: OrderRateLimiter) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    pub fn requests(&self) -> &[Request] {
+        &self.requests
+    }
+
+    /// Split the batch for sending.
+    #[cfg(any(feature = "enable-ureq", feature = "enable-hyper"))]
+    pub(crate) fn into_parts(self) -> (Vec<Request>, usize, bool, OrderRateLimiter) {
+        (
+            self.requests,
+            self.max_concurrency,
+            self.check_usage,
+            self.limiter,
+        )
+    }
+}
+
+/// Cancel of many orders.
+///
+/// Orders of a symbol are canceled with a single `DELETE /api/v3/openOrders`
+/// when their order ids are exactly the [open order ids](BulkCancel::open_order_ids)
+/// of the symbol, one `DELETE /api/v3/order` per order otherwise.
+///
+/// The open order ids are a snapshot: an order placed on the symbol after it
+/// was taken, e.g. by another process, is canceled as well by
+/// `DELETE /api/v3/openOrders`.
+///
+/// Cancels do not count towards the order count limits, the batch is not
+/// throttled.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::trade::batch::{BulkCancel, OrderBatch};
+///
+/// let cancel = BulkCancel::new()
+///     .order_id("BNBUSDT", 12)
+///     .order_id("BNBUSDT", 13)
+///     .open_order_ids("BNBUSDT", [12, 13])
+///     .orig_client_order_id("BTCUSDT", "grid:lz3kq1")
+///     .symbol("ETHUSDT");
+///
+/// // One `DELETE /api/v3/openOrders` for BNBUSDT and for ETHUSDT, one `DELETE /api/v3/order` for BTCUSDT.
+/// assert_eq!(OrderBatch::from(cancel).requests().len(), 3);
+/// ```
+#[derive(Default)]
+pub struct BulkCancel {
+    symbols: Vec<String>,
+    orders: BTreeMap<String, Vec<CancelTarget>>,
+    open_order_ids: BTreeMap<String, BTreeSet<u64>>,
+}
+
+#[derive(PartialEq, Eq)]
+enum CancelTarget {
+    OrderId(u64),
+    OrigClientOrderId(String),
+}
+
+impl BulkCancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all the open orders of `symbol`, order lists included.
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbols.push(symbol.to_owned());
+        self
+    }
+
+    pub fn order_id(self, symbol: &str, order_id: u64) -> Self {
+        self.target(symbol, CancelTarget::OrderId(order_id))
+    }
+
+    pub fn orig_client_order_id(self, symbol: &str, orig_client_order_id: &str) -> Self {
+        self.target(
+            symbol,
+            CancelTarget::OrigClientOrderId(orig_client_order_id.to_owned()),
+        )
+    }
+
+    /// Order ids of the open orders of `symbol`, e.g. from
+    /// `GET /api/v3/openOrders`: when exactly these orders are canceled, a
+    /// single `DELETE /api/v3/openOrders` is sent instead.
+    pub fn open_order_ids(
+        mut self,
+        symbol: &str,
+        order_ids: impl IntoIterator<Item = u64>,
+    ) -> Self {
+        self.open_order_ids
+            .insert(symbol.to_owned(), order_ids.into_iter().collect());
+        self
+    }
+
+    fn target(mut self, symbol: &str, target: CancelTarget) -> Self {
+        let targets = self.orders.entry(symbol.to_owned()).or_default();
+        if !targets.contains(&target) {
+            targets.push(target);
+        }
+        self
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        let mut requests: Vec<Request> = self
+            .symbols
+            .iter()
+            .map(|symbol| CancelOpenOrders::new(symbol).into())
+            .collect();
+
+        for (symbol, targets) in self.orders.iter() {
+            if self.symbols.contains(symbol) {
+                continue;
+            }
+            let order_ids: BTreeSet<u64> = targets
+                .iter()
+                .filter_map(|target| match target {
+                    CancelTarget::OrderId(order_id) => Some(*order_id),
+                    CancelTarget::OrigClientOrderId(_) => None,
+                })
+                .collect();
+            // Orders known by client order id can't be matched with the open orders.
+            if order_ids.len() == targets.len()
+                && self.open_order_ids.get(symbol) == Some(&order_ids)
+            {
+                requests.push(CancelOpenOrders::new(symbol).into());
+                continue;
+            }
+
+            requests.extend(targets.iter().map(|target| {
+                match target {
+                    CancelTarget::OrderId(order_id) => CancelOrder::new(symbol).order_id(*order_id),
+                    CancelTarget::OrigClientOrderId(client_order_id) => {
+                        CancelOrder::new(symbol).orig_client_order_id(client_order_id)
+                    }
+                }
+                .into()
+            }));
+        }
+
+        requests
+    }
+}
+
+impl From<BulkCancel> for OrderBatch {
+    fn from(cancel: BulkCancel) -> OrderBatch {
+        OrderBatch::new(cancel.requests())
+            .check_usage(false)
+            .rate_limiter(OrderRateLimiter::new(vec![]))
+    }
+}
+
+/// Order count usage returned by `GET /api/v3/rateLimit/order`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitUsage {
+    pub rate_limit_type: String,
+    /// `SECOND`, `MINUTE`, `HOUR` or `DAY`.
+    pub interval: String,
+    pub interval_num: u64,
+    pub limit: u32,
+    pub count: u32,
+}
+
+/// Order count limit over a wall clock interval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderRateLimit {
+    /// Interval length, in milliseconds.
+    pub interval: u64,
+    pub limit: u32,
+    /// Orders counted in the current interval.
+    pub count: u32,
+    /// Response header holding the count, e.g. `x-mbx-order-count-10s`.
+    pub header: String,
+    /// Index of the interval of `count`, `None` if unknown.
+    window: Option<u64>,
+}
+
+impl OrderRateLimit {
+    pub fn new(interval_num: u64, interval: &str, limit: u32) -> Option<Self> {
+        let (unit, letter) = match interval {
+            "SECOND" => (1_000, 's'),
+            "MINUTE" => (60_000, 'm'),
+            "HOUR" => (3_600_000, 'h'),
+            "DAY" => (86_400_000, 'd'),
+            _ => return None,
+        };
+
+        Some(Self {
+            interval: interval_num * unit,
+            limit,
+            count: 0,
+            header: format!("x-mbx-order-count-{}{}", interval_num, letter),
+            window: None,
+        })
+    }
+
+    fn available(&self, timestamp: u64) -> u32 {
+        match self.window == Some(timestamp / self.interval) {
+            true => self.limit.saturating_sub(self.count),
+            false => self.limit,
+        }
+    }
+
+    fn add(&mut self, count: u32, timestamp: u64) {
+        let window = timestamp / self.interval;
+        if self.window != Some(window) {
+            self.window = Some(window);
+            self.count = 0;
+        }
+        self.count += count;
+    }
+}
+
+/// Order count limits of an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderRateLimiter {
+    limits: Vec<OrderRateLimit>,
+}
+
+impl OrderRateLimiter {
+    pub fn new(limits: Vec<OrderRateLimit>) -> Self {
+        Self { limits }
+    }
+
+    /// Limits of the `ORDERS` usage, with their counts at `timestamp`.
+    pub fn from_usage(usage: &[RateLimitUsage], timestamp: u64) -> Self {
+        let limits = usage
+            .iter()
+            .filter(|usage| usage.rate_limit_type == "ORDERS")
+            .filter_map(|usage| {
+                let mut limit =
+                    OrderRateLimit::new(usage.interval_num, &usage.interval, usage.limit)?;
+                limit.add(usage.count, timestamp);
+                Some(limit)
+            })
+            .collect();
+
+        Self { limits }
+    }
+
+    pub fn limits(&self) -> &[OrderRateLimit] {
+        &self.limits
+    }
+
+    /// Orders which can be sent at `timestamp` without exceeding a limit.
+    pub fn available(&self, timestamp: u64) -> u32 {
+        self.limits
+            .iter()
+            .map(|limit| limit.available(timestamp))
+            .min()
+            .unwrap_or(u32::MAX)
+    }
+
+    /// Time to wait at `timestamp` before `count` orders can be sent, `None`
+    /// if they can be sent now.
+    pub fn delay(&self, count: u32, timestamp: u64) -> Option<Duration> {
+        self.limits
+            .iter()
+            .filter(|limit| limit.available(timestamp) < count.min(limit.limit))
+            .map(|limit| Duration::from_millis(limit.interval - timestamp % limit.interval))
+            .max()
+    }
+
+    /// Count `count` orders sent at `timestamp`.
+    pub fn record(&mut self, count: u32, timestamp: u64) {
+        for limit in self.limits.iter_mut() {
+            limit.add(count, timestamp);
+        }
+    }
+
+    /// Update the counts with the headers of a response received at `timestamp`.
+    ///
+    /// Responses of concurrent requests arrive in any order, the highest
+    /// count of an interval is kept.
+    pub fn update<'a>(&mut self, header: impl Fn(&str) -> Option<&'a str>, timestamp: u64) {
+        for limit in self.limits.iter_mut() {
+            let count = header(&limit.header).and_then(|count| count.parse::<u32>().ok());
+            if let Some(count) = count {
+                let window = timestamp / limit.interval;
+                if limit.window != Some(window) {
+                    limit.window = Some(window);
+                    limit.count = count;
+                } else {
+                    limit.count = limit.count.max(count);
+                }
+            }
+        }
+    }
+}
+
+impl Default for OrderRateLimiter {
+    /// 100 orders every 10 seconds and 200,000 orders a day, the limits of
+    /// the spot API at the time of writing.
+    fn default() -> Self {
+        Self::new(vec![
+            OrderRateLimit::new(10, "SECOND", 100).unwrap(),
+            OrderRateLimit::new(1, "DAY", 200_000).unwrap(),
+        ])
+    }
+}
+
+/// Number of orders created by a request, counted by the order count limits.
+#[cfg(any(feature = "enable-ureq", feature = "enable-hyper"))]
+pub(crate) fn order_count(request: &Request) -> u32 {
+    match request.path.as_str() {
+        "/api/v3/orderList/otoco" | "/sapi/v1/margin/order/otoco" => 3,
+        "/api/v3/orderList/oco"
+        | "/api/v3/orderList/oto"
+        | "/sapi/v1/margin/order/oco"
+        | "/sapi/v1/margin/order/oto" => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BulkCancel;
+
+    #[test]
+    #[cfg(any(feature = "enable-ureq", feature = "enable-hyper"))]
+    fn trade_batch_rate_limiter_test() {
+        use super::{order_count, OrderBatch, OrderRateLimiter, RateLimitUsage};
+        use crate::trade::{self, order::Side};
+        use rust_decimal_macros::dec;
+        use std::time::Duration;
+
+        let usage: Vec<RateLimitUsage> = serde_json::from_str(r#"[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50,"count":48},{"rateLimitType":"ORDERS","interval":"DAY","intervalNum":1,"limit":160000,"count":1000}]"#).unwrap();
+        let mut limiter = OrderRateLimiter::from_usage(&usage, 1_000_001_000);
+        assert_eq!(limiter.limits()[0].header, "x-mbx-order-count-10s");
+        assert_eq!(limiter.available(1_000_001_000), 2);
+
+        let batch = OrderBatch::new(vec![
+            trade::new_order("BNBUSDT", Side::Buy, "MARKET").quantity(dec!(1)),
+            trade::new_order("BNBUSDT", Side::Sell, "MARKET").quantity(dec!(1)),
+        ]);
+        let count = batch.requests().iter().map(order_count).sum();
+        limiter.record(count, 1_000_002_000);
+        assert_eq!(limiter.available(1_000_002_000), 0);
+        assert_eq!(
+            limiter.delay(1, 1_000_002_000),
+            Some(Duration::from_millis(8_000))
+        );
+
+        // A new interval starts.
+        assert_eq!(limiter.available(1_000_010_000), 50);
+        limiter.update(
+            |header| match header {
+                "x-mbx-order-count-10s" => Some("10"),
+                _ => None,
+            },
+            1_000_010_000,
+        );
+        assert_eq!(limiter.available(1_000_010_000), 40);
+        assert_eq!(limiter.delay(1, 1_000_010_000), None);
+    }
+
+    #[test]
+    fn trade_batch_bulk_cancel_test() {
+        let cancel = BulkCancel::new()
+            .order_id("BNBUSDT", 12)
+            .orig_client_order_id("BNBUSDT", "grid:1")
+            .open_order_ids("BNBUSDT", [12, 13])
+            .order_id("BTCUSDT", 1)
+            .order_id("BTCUSDT", 2)
+            .open_order_ids("BTCUSDT", [2, 1])
+            .order_id("BCHUSDT", 12)
+            .order_id("BCHUSDT", 12)
+            .open_order_ids("BCHUSDT", [12, 13])
+            .symbol("ETHUSDT")
+            .order_id("ETHUSDT", 5);
+
+        let paths: Vec<String> = cancel
+            .requests()
+            .into_iter()
+            .map(|request| format!("{} {}", request.params[0].1, request.path))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                "ETHUSDT /api/v3/openOrders",
+                "BCHUSDT /api/v3/order",
+                "BNBUSDT /api/v3/order",
+                "BNBUSDT /api/v3/order",
+                "BTCUSDT /api/v3/openOrders",
+            ]
+        );
+    }
+}
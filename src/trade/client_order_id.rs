@@ -0,0 +1,334 @@
+use crate::http::{request::Request, Method};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Maximum length of a client order id.
+pub const MAX_CLIENT_ORDER_ID_LEN: usize = 36;
+
+/// Separator between the strategy tag and the counter of the ids generated
+/// by [`StrategyIds`].
+pub const STRATEGY_TAG_SEPARATOR: char = ':';
+
+/// Longest counter of [`MonotonicIds`] and [`StrategyIds`], a `u64` in base 36.
+const MAX_COUNTER_LEN: usize = 13;
+
+/// Whether `client_order_id` matches `^[\.A-Z\:/a-z0-9_-]{1,36}$`, the format
+/// accepted by the server.
+pub fn is_valid(client_order_id: &str) -> bool {
+    (1..=MAX_CLIENT_ORDER_ID_LEN).contains(&client_order_id.len())
+        && client_order_id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | ':' | '/' | '_' | '-'))
+}
+
+/// Strategy tag of an id generated by [`StrategyIds`], `None` for other ids.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::trade::client_order_id::{strategy_tag, ClientOrderIdGenerator, StrategyIds};
+///
+/// let ids = StrategyIds::new("grid.btc").unwrap();
+///
+/// assert_eq!(strategy_tag(&ids.next_id()), Some("grid.btc"));
+/// assert_eq!(strategy_tag("web_4a8f0c"), None);
+/// ```
+pub fn strategy_tag(client_order_id: &str) -> Option<&str> {
+    let (tag, counter) = client_order_id.split_once(STRATEGY_TAG_SEPARATOR)?;
+    let is_counter = (1..=MAX_COUNTER_LEN).contains(&counter.len())
+        && counter
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase())
+        && u64::from_str_radix(counter, 36).is_ok();
+
+    if !tag.is_empty() && is_counter && is_valid(client_order_id) {
+        Some(tag)
+    } else {
+        None
+    }
+}
+
+/// Source of the client order ids set by the clients and the `OrderManager`
+/// on the orders sent without one.
+///
+/// Ids must be unique among the open orders of the account and [valid](is_valid).
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::trade::{
+///     client_order_id::StrategyIds, order_manager::OrderManager,
+/// };
+///
+/// let manager = OrderManager::new()
+///     .client_order_id_generator(StrategyIds::new("grid").expect("Invalid tag"));
+/// ```
+pub trait ClientOrderIdGenerator: Send + Sync {
+    fn next_id(&self) -> String;
+}
+
+/// Ids made of a prefix and a counter, starting at the current time in
+/// milliseconds so that they are not reused after a restart.
+///
+/// Instances sending orders on the same account at the same time must use
+/// different prefixes.
+pub struct MonotonicIds {
+    prefix: String,
+    counter: AtomicU64,
+}
+
+impl MonotonicIds {
+    /// The prefix is at most 23 characters long.
+    pub fn new(prefix: &str) -> Result<Self, InvalidClientOrderId> {
+        if !prefix.is_empty() && !is_valid(prefix)
+            || prefix.len() > MAX_CLIENT_ORDER_ID_LEN - MAX_COUNTER_LEN
+        {
+            return Err(InvalidClientOrderId {
+                param: "prefix".to_owned(),
+                client_order_id: prefix.to_owned(),
+            });
+        }
+
+        Ok(Self {
+            prefix: prefix.to_owned(),
+            counter: AtomicU64::new(now()),
+        })
+    }
+}
+
+impl ClientOrderIdGenerator for MonotonicIds {
+    fn next_id(&self) -> String {
+        let counter = self.counter.fetch_add(1, Ordering::Relaxed);
+        format!("{}{}", self.prefix, base36(counter))
+    }
+}
+
+/// Random UUID v4 ids, e.g. `"3f2b8c1e-7d4a-4e0b-9c1f-5a6d7e8f9012"`.
+#[derive(Default)]
+pub struct UuidIds;
+
+impl UuidIds {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ClientOrderIdGenerator for UuidIds {
+    fn next_id(&self) -> String {
+        let mut bytes: [u8; 16] = rand::random();
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    }
+}
+
+/// Ids tagged with the strategy placing the orders, `<tag>:<counter>`.
+///
+/// The tag is decoded back from the ids of the user data events with
+/// [`strategy_tag`] or `ExecutionReport::strategy_tag`.
+pub struct StrategyIds {
+    ids: MonotonicIds,
+}
+
+impl StrategyIds {
+    /// The tag is at most 22 characters long and does not contain `:`.
+    pub fn new(tag: &str) -> Result<Self, InvalidClientOrderId> {
+        if tag.is_empty() || tag.contains(STRATEGY_TAG_SEPARATOR) {
+            return Err(InvalidClientOrderId {
+                param: "tag".to_owned(),
+                client_order_id: tag.to_owned(),
+            });
+        }
+
+        let ids =
+            MonotonicIds::new(&format!("{}{}", tag, STRATEGY_TAG_SEPARATOR)).map_err(|_| {
+                InvalidClientOrderId {
+                    param: "tag".to_owned(),
+                    client_order_id: tag.to_owned(),
+                }
+            })?;
+
+        Ok(Self { ids })
+    }
+}
+
+impl ClientOrderIdGenerator for StrategyIds {
+    fn next_id(&self) -> String {
+        self.ids.next_id()
+    }
+}
+
+/// Client order id not matching `^[\.A-Z\:/a-z0-9_-]{1,36}$`, or already
+/// used by an open order of an `OrderManager`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidClientOrderId {
+    /// Parameter or generator setting holding the id.
+    pub param: String,
+    pub client_order_id: String,
+}
+
+/// Parameters holding the client order ids of the orders created by a request.
+pub(crate) fn client_order_id_params(method: &Method, path: &str) -> &'static [&'static str] {
+    if *method != Method::Post {
+        return &[];
+    }
+
+    match path {
+        "/api/v3/order"
+        | "/api/v3/order/test"
+        | "/api/v3/order/cancelReplace"
+        | "/sapi/v1/margin/order" => &["newClientOrderId"],
+        "/api/v3/orderList/oco" => &[
+            "listClientOrderId",
+            "aboveClientOrderId",
+            "belowClientOrderId",
+        ],
+        "/sapi/v1/margin/order/oco" => &[
+            "listClientOrderId",
+            "limitClientOrderId",
+            "stopClientOrderId",
+        ],
+        "/api/v3/orderList/oto" | "/sapi/v1/margin/order/oto" => &[
+            "listClientOrderId",
+            "workingClientOrderId",
+            "pendingClientOrderId",
+        ],
+        "/api/v3/orderList/otoco" | "/sapi/v1/margin/order/otoco" => &[
+            "listClientOrderId",
+            "workingClientOrderId",
+            "pendingAboveClientOrderId",
+            "pendingBelowClientOrderId",
+        ],
+        _ => &[],
+    }
+}
+
+/// Check the client order ids of an order creating request, generating the
+/// missing ones with `generator`.
+pub(crate) fn assign_client_order_ids(
+    request: &mut Request,
+    generator: Option<&dyn ClientOrderIdGenerator>,
+) -> Result<(), InvalidClientOrderId> {
+    for param in client_order_id_params(&request.method, &request.path) {
+        match request.params.iter().find(|(key, _)| key == param) {
+            Some((_, client_order_id)) if !is_valid(client_order_id) => {
+                return Err(InvalidClientOrderId {
+                    param: param.to_string(),
+                    client_order_id: client_order_id.clone(),
+                });
+            }
+            Some(_) => {}
+            None => {
+                if let Some(generator) = generator {
+                    let client_order_id = generator.next_id();
+                    if !is_valid(&client_order_id) {
+                        return Err(InvalidClientOrderId {
+                            param: param.to_string(),
+                            client_order_id,
+                        });
+                    }
+                    request.params.push((param.to_string(), client_order_id));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+fn base36(mut value: u64) -> String {
+    let mut digits = vec![];
+    loop {
+        digits.push(std::char::from_digit((value % 36) as u32, 36).unwrap());
+        value /= 36;
+        if value == 0 {
+            break;
+        }
+    }
+
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        assign_client_order_ids, is_valid, strategy_tag, ClientOrderIdGenerator, MonotonicIds,
+        StrategyIds, UuidIds,
+    };
+    use crate::http::request::Request;
+    use crate::trade::{self, order::Side};
+
+    #[test]
+    fn trade_client_order_id_test() {
+        assert!(is_valid("x-A1.b:c/d_e-f"));
+        assert!(!is_valid(""));
+        assert!(!is_valid("order id"));
+        assert!(!is_valid(&"a".repeat(37)));
+
+        let monotonic = MonotonicIds::new("bot-").unwrap();
+        let (first, second) = (monotonic.next_id(), monotonic.next_id());
+        assert!(first.starts_with("bot-") && first != second);
+        assert!(MonotonicIds::new(&"a".repeat(24)).is_err());
+        assert!(MonotonicIds::new("bot#").is_err());
+
+        let uuid = UuidIds::new().next_id();
+        assert_eq!(uuid.len(), 36);
+        assert!(is_valid(&uuid) && uuid != UuidIds::new().next_id());
+
+        let strategy = StrategyIds::new(&"s".repeat(22)).unwrap();
+        let id = strategy.next_id();
+        assert!(is_valid(&id));
+        assert_eq!(strategy_tag(&id), Some("s".repeat(22).as_str()));
+        assert_eq!(strategy_tag("grid:1a2b"), Some("grid"));
+        assert_eq!(strategy_tag("grid:"), None);
+        assert_eq!(strategy_tag(":1a2b"), None);
+        assert_eq!(strategy_tag("grid:1A2B"), None);
+        assert_eq!(strategy_tag("grid:1a-2b"), None);
+        assert_eq!(strategy_tag("a:b:1a2b"), None);
+        assert_eq!(strategy_tag("grid:zzzzzzzzzzzzz"), None);
+        assert_eq!(strategy_tag("grid#:1a2b"), None);
+        assert!(StrategyIds::new("a:b").is_err());
+        assert!(StrategyIds::new(&"s".repeat(23)).is_err());
+
+        let mut request: Request = trade::new_oco_order(
+            "BNBUSDT",
+            Side::Sell,
+            rust_decimal::Decimal::ONE,
+            "LIMIT_MAKER",
+            "STOP_LOSS",
+        )
+        .above_client_order_id("above")
+        .into();
+        assign_client_order_ids(&mut request, Some(&strategy)).unwrap();
+        let ids: Vec<&str> = request
+            .params
+            .iter()
+            .filter(|(key, _)| key.ends_with("ClientOrderId"))
+            .map(|(_, value)| value.as_str())
+            .collect();
+        assert_eq!(ids.len(), 3);
+        assert_eq!(ids[0], "above");
+
+        let mut request: Request = trade::new_order("BNBUSDT", Side::Buy, "MARKET")
+            .new_client_order_id("not valid")
+            .into();
+        let err = assign_client_order_ids(&mut request, None).unwrap_err();
+        assert_eq!(err.param, "newClientOrderId");
+    }
+}
@@ -3,10 +3,12 @@
 //! [API Documentation]()
 pub mod account;
 pub mod all_orders;
+pub mod batch;
 pub mod cancel_an_existing_order_and_send_a_new_order;
 pub mod cancel_oco_order;
 pub mod cancel_open_orders;
 pub mod cancel_order;
+pub mod client_order_id;
 pub mod get_allocations;
 pub mod get_commission_rates;
 pub mod get_oco_order;
@@ -14,6 +16,7 @@ pub mod get_oco_orders;
 pub mod get_open_oco_orders;
 pub mod get_order;
 pub mod get_prevented_matches;
+pub mod my_trade_history;
 pub mod my_trades;
 pub mod new_oco_order;
 pub mod new_order;
@@ -23,6 +26,7 @@ pub mod new_otoco_order;
 pub mod open_orders;
 pub mod order;
 pub mod order_limit_usage;
+pub mod order_manager;
 
 use rust_decimal::Decimal;
 
@@ -39,6 +43,7 @@ use get_oco_orders::GetOCOOrders;
 use get_open_oco_orders::GetOpenOCOOrders;
 use get_order::GetOrder;
 use get_prevented_matches::GetPreventedMatches;
+use my_trade_history::MyTradeHistory;
 use my_trades::MyTrades;
 use new_oco_order::NewOCOOrder;
 use new_order::NewOrder;
@@ -164,6 +169,10 @@ pub fn my_trades(symbol: &str) -> MyTrades {
     MyTrades::new(symbol)
 }
 
+pub fn my_trade_history(symbol: &str) -> MyTradeHistory {
+    MyTradeHistory::new(symbol)
+}
+
 pub fn order_limit_usage() -> OrderLimitUsage {
     OrderLimitUsage::new()
 }
@@ -1,5 +1,6 @@
 use rust_decimal::Decimal;
-use strum::Display;
+use serde::Deserialize;
+use strum::{Display, EnumString};
 
 #[derive(Copy, Clone, Display)]
 #[strum(serialize_all = "UPPERCASE")]
@@ -24,6 +25,32 @@ pub enum NewOrderResponseType {
     Full,
 }
 
+/// Status of an order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Display, EnumString, Deserialize)]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    New,
+    PendingNew,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    PendingCancel,
+    Rejected,
+    Expired,
+    ExpiredInMatch,
+}
+
+impl OrderStatus {
+    /// Whether the order may still be filled or canceled.
+    pub fn is_open(&self) -> bool {
+        matches!(
+            self,
+            Self::New | Self::PendingNew | Self::PartiallyFilled | Self::PendingCancel
+        )
+    }
+}
+
 #[derive(Copy, Clone, Display)]
 pub enum CancelReplaceMode {
     #[strum(serialize = "STOP_ON_FAILURE")]
@@ -54,3 +81,54 @@ impl WorkingMandatoryParams {
         }
     }
 }
+
+/// Order returned by `POST /api/v3/order`, `GET /api/v3/order`,
+/// `DELETE /api/v3/order` and `GET /api/v3/openOrders`.
+///
+/// Fields missing from `ACK` responses are `None`, `fills` are only returned
+/// by `FULL` responses.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponse {
+    pub symbol: String,
+    pub order_id: u64,
+    #[serde(default)]
+    pub order_list_id: Option<i64>,
+    /// Client order id of the request, the cancel request on `DELETE /api/v3/order`.
+    pub client_order_id: String,
+    /// Client order id of the canceled order on `DELETE /api/v3/order`.
+    #[serde(default)]
+    pub orig_client_order_id: Option<String>,
+    #[serde(default)]
+    pub transact_time: Option<u64>,
+    #[serde(default)]
+    pub update_time: Option<u64>,
+    #[serde(default)]
+    pub price: Option<Decimal>,
+    #[serde(default)]
+    pub orig_qty: Option<Decimal>,
+    #[serde(default)]
+    pub executed_qty: Option<Decimal>,
+    #[serde(default)]
+    pub cummulative_quote_qty: Option<Decimal>,
+    #[serde(default)]
+    pub status: Option<OrderStatus>,
+    #[serde(default)]
+    pub time_in_force: Option<String>,
+    #[serde(rename = "type", default)]
+    pub order_type: Option<String>,
+    #[serde(default)]
+    pub side: Option<String>,
+    #[serde(default)]
+    pub fills: Vec<OrderResponseFill>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderResponseFill {
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: String,
+    pub trade_id: u64,
+}
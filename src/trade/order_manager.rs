@@ -0,0 +1,648 @@
+use crate::http::request::Request;
+use crate::trade::client_order_id::{
+    assign_client_order_ids, ClientOrderIdGenerator, InvalidClientOrderId, MonotonicIds,
+};
+use crate::trade::get_order::GetOrder;
+use crate::trade::new_order::NewOrder;
+use crate::trade::order::{OrderResponse, OrderStatus};
+use crate::user_data_stream::event::{ExecutionReport, UserDataEvent};
+use rust_decimal::Decimal;
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+/// Local state of the orders of an account.
+///
+/// Orders sent with [`OrderManager::submit`] are given a client order id and
+/// tracked from submission. Their state is then moved forward by the
+/// responses of the order endpoints and by the `executionReport` events of the
+/// user data stream, whichever arrives first. Late or replayed updates never
+/// move an order back, and fills are recorded once per trade id.
+///
+/// Orders not submitted through the manager are tracked from the first
+/// response or event received about them.
+///
+/// A client order id may be reused once its order is closed. The closed order
+/// is then kept apart, still updated by the events carrying its order id,
+/// until it is returned by [`OrderManager::remove_closed`].
+///
+/// After a user data stream reconnection, pass the `GET /api/v3/openOrders`
+/// response to [`OrderManager::reconcile`] and query the returned orders with
+/// `GET /api/v3/order` to catch up with the events missed in between.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::trade::{
+///     self,
+///     order::{OrderResponse, OrderStatus, Side},
+///     order_manager::OrderManager,
+/// };
+/// use binance_spot_connector_rust::user_data_stream::event::UserDataEvent;
+/// use rust_decimal_macros::dec;
+///
+/// let mut manager = OrderManager::new();
+///
+/// let request = manager
+///     .submit(
+///         trade::new_order("BNBUSDT", Side::Buy, "LIMIT")
+///             .time_in_force(trade::order::TimeInForce::Gtc)
+///             .price(dec!(300))
+///             .quantity(dec!(0.1))
+///             .new_client_order_id("grid-1"),
+///     )
+///     .expect("Invalid client order id");
+/// // let body = client.send(request)?.into_body_str()?;
+/// let body = r#"{"symbol":"BNBUSDT","orderId":28,"orderListId":-1,"clientOrderId":"grid-1","transactTime":1507725176595}"#;
+/// let response: OrderResponse = serde_json::from_str(body).unwrap();
+/// let order = manager.handle_response(&response).unwrap();
+///
+/// assert_eq!(order.order_id, Some(28));
+/// assert_eq!(order.status, OrderStatus::New);
+/// ```
+pub struct OrderManager {
+    client_order_ids: Box<dyn ClientOrderIdGenerator>,
+    /// Orders by symbol and client order id, unique among the open orders of a symbol.
+    orders: BTreeMap<(String, String), TrackedOrder>,
+    order_ids: HashMap<(String, u64), String>,
+    /// Closed orders whose client order id was reused, by symbol and order id.
+    replaced: HashMap<(String, u64), TrackedOrder>,
+}
+
+/// Order tracked by an [`OrderManager`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrackedOrder {
+    pub symbol: String,
+    pub client_order_id: String,
+    /// `None` until the order is acknowledged by the server.
+    pub order_id: Option<u64>,
+    pub side: String,
+    pub order_type: String,
+    pub price: Option<Decimal>,
+    pub quantity: Option<Decimal>,
+    /// `PENDING_NEW` until the order is acknowledged by the server.
+    pub status: OrderStatus,
+    pub executed_qty: Decimal,
+    pub cumulative_quote_qty: Decimal,
+    /// Fills received from `FULL` responses and `executionReport` events.
+    pub fills: Vec<OrderFill>,
+    pub update_time: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderFill {
+    pub trade_id: u64,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub commission: Decimal,
+    pub commission_asset: Option<String>,
+    /// `None` for fills of an order response, which carry no time.
+    pub time: Option<u64>,
+    /// `None` for fills of an order response.
+    pub is_maker: Option<bool>,
+}
+
+impl TrackedOrder {
+    /// Average price of the executed quantity, `None` until a first fill.
+    pub fn average_price(&self) -> Option<Decimal> {
+        if self.executed_qty.is_zero() {
+            None
+        } else {
+            Some(self.cumulative_quote_qty / self.executed_qty)
+        }
+    }
+
+    /// Quantity left to fill, `None` for orders placed with a quote quantity.
+    pub fn remaining_qty(&self) -> Option<Decimal> {
+        self.quantity.map(|quantity| quantity - self.executed_qty)
+    }
+
+    fn update(
+        &mut self,
+        status: Option<OrderStatus>,
+        executed_qty: Option<Decimal>,
+        cumulative_quote_qty: Option<Decimal>,
+        time: Option<u64>,
+    ) {
+        // Updates are applied in fill order: a closed order stays closed and
+        // the executed quantity never decreases.
+        let is_stale = executed_qty.map_or(false, |executed_qty| executed_qty < self.executed_qty)
+            || (!self.status.is_open() && status.map_or(true, |status| status.is_open()));
+        if is_stale {
+            return;
+        }
+
+        if let Some(status) = status {
+            self.status = status;
+        } else if self.status == OrderStatus::PendingNew {
+            self.status = OrderStatus::New;
+        }
+        if let Some(executed_qty) = executed_qty {
+            self.executed_qty = executed_qty;
+        }
+        if let Some(cumulative_quote_qty) = cumulative_quote_qty {
+            self.cumulative_quote_qty = cumulative_quote_qty;
+        }
+        if time.is_some() {
+            self.update_time = time;
+        }
+    }
+
+    fn add_fill(&mut self, fill: OrderFill) {
+        match self
+            .fills
+            .iter_mut()
+            .find(|known| known.trade_id == fill.trade_id)
+        {
+            // A fill of an order response completed by its event.
+            Some(known) => {
+                known.time = known.time.or(fill.time);
+                known.is_maker = known.is_maker.or(fill.is_maker);
+            }
+            None => self.fills.push(fill),
+        }
+    }
+}
+
+impl OrderManager {
+    /// Client order ids are generated by `MonotonicIds` prefixed with `"om-"`
+    /// by default.
+    pub fn new() -> Self {
+        Self {
+            client_order_ids: Box::new(MonotonicIds::new("om-").unwrap()),
+            orders: BTreeMap::new(),
+            order_ids: HashMap::new(),
+            replaced: HashMap::new(),
+        }
+    }
+
+    pub fn client_order_id_generator(
+        mut self,
+        generator: impl ClientOrderIdGenerator + 'static,
+    ) -> Self {
+        self.client_order_ids = Box::new(generator);
+        self
+    }
+
+    /// Track `order` and build its request, with a client order id unless
+    /// one was set on the order.
+    ///
+    /// Fails if the client order id is invalid or is the id of an open
+    /// tracked order of the symbol.
+    pub fn submit(&mut self, order: NewOrder) -> Result<Request, InvalidClientOrderId> {
+        let mut request: Request = order.into();
+        assign_client_order_ids(&mut request, Some(self.client_order_ids.as_ref()))?;
+        let param = |name: &str| {
+            request
+                .params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.to_owned())
+        };
+        let decimal = |name: &str| param(name).and_then(|value| Decimal::from_str(&value).ok());
+
+        let order = TrackedOrder {
+            symbol: param("symbol").unwrap_or_default(),
+            client_order_id: param("newClientOrderId").unwrap_or_default(),
+            order_id: None,
+            side: param("side").unwrap_or_default(),
+            order_type: param("type").unwrap_or_default(),
+            price: decimal("price"),
+            quantity: decimal("quantity"),
+            status: OrderStatus::PendingNew,
+            executed_qty: Decimal::ZERO,
+            cumulative_quote_qty: Decimal::ZERO,
+            fills: vec![],
+            update_time: None,
+        };
+        let key = (order.symbol.clone(), order.client_order_id.clone());
+        if let Some(previous) = self.orders.get(&key) {
+            if previous.status.is_open() {
+                return Err(InvalidClientOrderId {
+                    param: "newClientOrderId".to_owned(),
+                    client_order_id: order.client_order_id,
+                });
+            }
+            // Late events of the previous order must not update this one.
+            if let Some(previous) = self.remove(&key.0, &key.1) {
+                if let Some(order_id) = previous.order_id {
+                    self.replaced
+                        .insert((previous.symbol.clone(), order_id), previous);
+                }
+            }
+        }
+        self.orders.insert(key, order);
+
+        Ok(request)
+    }
+
+    /// Apply the response of an order endpoint, returning the updated order.
+    ///
+    /// `None` if an `ACK` response is received for an order which is not
+    /// tracked, since it does not describe the order, or if the client order
+    /// id is tracked for another order.
+    pub fn handle_response(&mut self, response: &OrderResponse) -> Option<&TrackedOrder> {
+        let client_order_id = response
+            .orig_client_order_id
+            .as_ref()
+            .unwrap_or(&response.client_order_id);
+        let id = (response.symbol.clone(), response.order_id);
+        let key = self.key(&response.symbol, response.order_id, client_order_id);
+        let is_replaced = self.replaced.contains_key(&id);
+        if key.is_none()
+            && !is_replaced
+            && self
+                .orders
+                .contains_key(&(response.symbol.clone(), client_order_id.clone()))
+        {
+            // The client order id belongs to another order.
+            return None;
+        }
+
+        let order = match (self.replaced.get_mut(&id), key) {
+            (Some(order), _) => order,
+            (None, Some(key)) => self.orders.get_mut(&key)?,
+            (None, None) => {
+                let order = TrackedOrder {
+                    symbol: response.symbol.clone(),
+                    client_order_id: client_order_id.clone(),
+                    order_id: Some(response.order_id),
+                    side: response.side.clone()?,
+                    order_type: response.order_type.clone()?,
+                    price: response.price,
+                    quantity: response.orig_qty,
+                    status: response.status?,
+                    executed_qty: Decimal::ZERO,
+                    cumulative_quote_qty: Decimal::ZERO,
+                    fills: vec![],
+                    update_time: None,
+                };
+                self.orders
+                    .entry((response.symbol.clone(), client_order_id.clone()))
+                    .or_insert(order)
+            }
+        };
+
+        order.order_id = Some(response.order_id);
+        order.update(
+            response.status,
+            response.executed_qty,
+            response.cummulative_quote_qty,
+            response.update_time.or(response.transact_time),
+        );
+        for fill in response.fills.iter() {
+            order.add_fill(OrderFill {
+                trade_id: fill.trade_id,
+                price: fill.price,
+                qty: fill.qty,
+                commission: fill.commission,
+                commission_asset: Some(fill.commission_asset.clone()),
+                time: None,
+                is_maker: None,
+            });
+        }
+
+        if is_replaced {
+            return self.replaced.get(&id);
+        }
+        let key = (order.symbol.clone(), order.client_order_id.clone());
+        self.order_ids.insert(id, key.1.clone());
+        self.orders.get(&key)
+    }
+
+    /// Apply a user data event, returning the order updated by an
+    /// `executionReport`.
+    pub fn handle_event(&mut self, event: &UserDataEvent) -> Option<&TrackedOrder> {
+        match event {
+            UserDataEvent::ExecutionReport(report) => self.handle_execution_report(report),
+            _ => None,
+        }
+    }
+
+    pub fn handle_execution_report(&mut self, report: &ExecutionReport) -> Option<&TrackedOrder> {
+        // `C` holds the id of the canceled order when `c` is the id of the cancel request.
+        let client_order_id = match report.original_client_order_id.is_empty() {
+            true => &report.client_order_id,
+            false => &report.original_client_order_id,
+        };
+        let id = (report.symbol.clone(), report.order_id);
+        let is_replaced = self.replaced.contains_key(&id);
+        let key = match self.key(&report.symbol, report.order_id, client_order_id) {
+            Some(key) => key,
+            None => {
+                let key = (report.symbol.clone(), client_order_id.clone());
+                if !is_replaced && self.orders.contains_key(&key) {
+                    // The client order id belongs to another order.
+                    return None;
+                }
+                key
+            }
+        };
+
+        let order = match self.replaced.get_mut(&id) {
+            Some(order) => order,
+            None => self.orders.entry(key).or_insert_with(|| TrackedOrder {
+                symbol: report.symbol.clone(),
+                client_order_id: client_order_id.clone(),
+                order_id: Some(report.order_id),
+                side: report.side.clone(),
+                order_type: report.order_type.clone(),
+                price: Some(report.price).filter(|price| !price.is_zero()),
+                quantity: Some(report.quantity).filter(|quantity| !quantity.is_zero()),
+                status: OrderStatus::PendingNew,
+                executed_qty: Decimal::ZERO,
+                cumulative_quote_qty: Decimal::ZERO,
+                fills: vec![],
+                update_time: None,
+            }),
+        };
+
+        order.order_id = Some(report.order_id);
+        order.update(
+            OrderStatus::from_str(&report.order_status).ok(),
+            Some(report.cumulative_filled_quantity),
+            Some(report.cumulative_quote_quantity),
+            Some(report.transaction_time),
+        );
+        if report.execution_type == "TRADE" && report.trade_id >= 0 {
+            order.add_fill(OrderFill {
+                trade_id: report.trade_id as u64,
+                price: report.last_executed_price,
+                qty: report.last_executed_quantity,
+                commission: report.commission_amount,
+                commission_asset: report.commission_asset.clone(),
+                time: Some(report.transaction_time),
+                is_maker: Some(report.is_maker),
+            });
+        }
+
+        if is_replaced {
+            return self.replaced.get(&id);
+        }
+        let key = (order.symbol.clone(), order.client_order_id.clone());
+        self.order_ids.insert(id, key.1.clone());
+        self.orders.get(&key)
+    }
+
+    /// Apply the `GET /api/v3/openOrders` response, returning the requests to
+    /// query the tracked orders which are no longer open.
+    ///
+    /// Pass the symbol the open orders were requested for, `None` for all
+    /// symbols. Orders never acknowledged are queried by client order id: the
+    /// server answers `-2013` if their submission failed, in which case they
+    /// may be dropped with [`OrderManager::remove`].
+    pub fn reconcile(
+        &mut self,
+        symbol: Option<&str>,
+        open_orders: &[OrderResponse],
+    ) -> Vec<GetOrder> {
+        for response in open_orders {
+            self.handle_response(response);
+        }
+
+        self.orders
+            .values()
+            .filter(|order| order.status.is_open())
+            .filter(|order| symbol.map_or(true, |symbol| symbol == order.symbol))
+            .filter(|order| {
+                !open_orders.iter().any(|response| {
+                    response.symbol == order.symbol && Some(response.order_id) == order.order_id
+                })
+            })
+            .map(|order| match order.order_id {
+                Some(order_id) => GetOrder::new(&order.symbol).order_id(order_id),
+                None => GetOrder::new(&order.symbol).orig_client_order_id(&order.client_order_id),
+            })
+            .collect()
+    }
+
+    pub fn order(&self, symbol: &str, client_order_id: &str) -> Option<&TrackedOrder> {
+        self.orders
+            .get(&(symbol.to_owned(), client_order_id.to_owned()))
+    }
+
+    /// Tracked order with the order id, including closed orders whose
+    /// client order id was reused.
+    pub fn order_by_id(&self, symbol: &str, order_id: u64) -> Option<&TrackedOrder> {
+        let id = (symbol.to_owned(), order_id);
+        match self.order_ids.get(&id) {
+            Some(client_order_id) => self.order(symbol, client_order_id),
+            None => self.replaced.get(&id),
+        }
+    }
+
+    /// Tracked orders, by symbol and client order id.
+    pub fn orders(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders.values()
+    }
+
+    pub fn open_orders(&self) -> impl Iterator<Item = &TrackedOrder> {
+        self.orders.values().filter(|order| order.status.is_open())
+    }
+
+    /// Stop tracking an order.
+    pub fn remove(&mut self, symbol: &str, client_order_id: &str) -> Option<TrackedOrder> {
+        let order = self
+            .orders
+            .remove(&(symbol.to_owned(), client_order_id.to_owned()))?;
+        if let Some(order_id) = order.order_id {
+            self.order_ids.remove(&(order.symbol.clone(), order_id));
+        }
+
+        Some(order)
+    }
+
+    /// Stop tracking the closed orders, returning them with the closed orders
+    /// whose client order id was reused.
+    pub fn remove_closed(&mut self) -> Vec<TrackedOrder> {
+        let keys: Vec<(String, String)> = self
+            .orders
+            .values()
+            .filter(|order| !order.status.is_open())
+            .map(|order| (order.symbol.clone(), order.client_order_id.clone()))
+            .collect();
+
+        let mut closed: Vec<TrackedOrder> = self.replaced.drain().map(|(_, order)| order).collect();
+        closed.extend(
+            keys.iter()
+                .filter_map(|(symbol, client_order_id)| self.remove(symbol, client_order_id)),
+        );
+        closed
+    }
+
+    /// Key of an order known by its order id or client order id, `None` if
+    /// the tracked order with the client order id has another order id.
+    fn key(&self, symbol: &str, order_id: u64, client_order_id: &str) -> Option<(String, String)> {
+        let is_order = |key: &(String, String)| {
+            self.orders.get(key).map_or(false, |order| {
+                order.order_id.map_or(true, |known| known == order_id)
+            })
+        };
+
+        if let Some(known) = self.order_ids.get(&(symbol.to_owned(), order_id)) {
+            let key = (symbol.to_owned(), known.to_owned());
+            if is_order(&key) {
+                return Some(key);
+            }
+        }
+        let key = (symbol.to_owned(), client_order_id.to_owned());
+
+        is_order(&key).then_some(key)
+    }
+}
+
+impl Default for OrderManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OrderManager;
+    use crate::trade::{
+        self,
+        client_order_id::{strategy_tag, StrategyIds},
+        order::{OrderResponse, OrderStatus, Side},
+    };
+    use crate::user_data_stream::event::UserDataEvent;
+    use rust_decimal_macros::dec;
+
+    fn execution_report(
+        status: &str,
+        trade_id: i64,
+        last_qty: &str,
+        cumulative_qty: &str,
+    ) -> UserDataEvent {
+        let data = format!(
+            r#"{{"e":"executionReport","E":1499405658658,"s":"BNBUSDT","c":"web_1","S":"BUY","o":"LIMIT","f":"GTC","q":"1.0","p":"300.0","P":"0","F":"0","g":-1,"C":"","x":"{}","X":"{}","r":"NONE","i":28,"l":"{}","z":"{}","L":"300.0","n":"0.001","N":"BNB","T":1499405658657,"t":{},"w":true,"m":true,"M":true,"O":1499405658657,"Z":"{}","Y":"0","Q":"0"}}"#,
+            if trade_id < 0 { "NEW" } else { "TRADE" },
+            status,
+            last_qty,
+            cumulative_qty,
+            trade_id,
+            dec!(300) * cumulative_qty.parse::<rust_decimal::Decimal>().unwrap(),
+        );
+
+        serde_json::from_str(&data).unwrap()
+    }
+
+    #[test]
+    fn trade_order_manager_test() {
+        let mut manager =
+            OrderManager::new().client_order_id_generator(StrategyIds::new("test").unwrap());
+        let request = manager
+            .submit(
+                trade::new_order("BNBUSDT", Side::Buy, "LIMIT")
+                    .price(dec!(300))
+                    .quantity(dec!(1)),
+            )
+            .unwrap();
+        let client_order_id = request
+            .params
+            .iter()
+            .find(|(key, _)| key == "newClientOrderId")
+            .map(|(_, value)| value.clone())
+            .unwrap();
+        assert_eq!(strategy_tag(&client_order_id), Some("test"));
+        assert_eq!(
+            manager.order("BNBUSDT", &client_order_id).unwrap().status,
+            OrderStatus::PendingNew
+        );
+
+        // The response arrives after the first fill event.
+        let data = format!(
+            r#"{{"symbol":"BNBUSDT","orderId":28,"orderListId":-1,"clientOrderId":"{}","transactTime":1507725176595,"price":"300.0","origQty":"1.0","executedQty":"0.0","cummulativeQuoteQty":"0.0","status":"NEW","timeInForce":"GTC","type":"LIMIT","side":"BUY","fills":[]}}"#,
+            client_order_id
+        );
+        let response: OrderResponse = serde_json::from_str(&data).unwrap();
+        let mut report = execution_report("PARTIALLY_FILLED", 7, "0.4", "0.4");
+        if let UserDataEvent::ExecutionReport(report) = &mut report {
+            report.client_order_id = client_order_id.clone();
+        }
+        manager.handle_event(&report);
+        let order = manager.handle_response(&response).unwrap();
+        assert_eq!(order.status, OrderStatus::PartiallyFilled);
+        assert_eq!(order.executed_qty, dec!(0.4));
+
+        // Replayed events are ignored.
+        manager.handle_event(&report);
+        manager.handle_event(&execution_report("FILLED", 8, "0.6", "1.0"));
+        manager.handle_event(&report);
+        let order = manager.order_by_id("BNBUSDT", 28).unwrap();
+        assert_eq!(order.status, OrderStatus::Filled);
+        assert_eq!(order.fills.len(), 2);
+        assert_eq!(order.average_price(), Some(dec!(300)));
+        assert_eq!(order.remaining_qty(), Some(dec!(0)));
+
+        // Orders missing from the open orders are queried.
+        manager
+            .submit(trade::new_order("BNBUSDT", Side::Sell, "MARKET").quantity(dec!(1)))
+            .unwrap();
+        assert_eq!(manager.reconcile(Some("BNBUSDT"), &[]).len(), 1);
+        assert_eq!(manager.reconcile(Some("ETHUSDT"), &[]).len(), 0);
+        assert_eq!(manager.remove_closed().len(), 1);
+        assert_eq!(manager.open_orders().count(), 1);
+
+        // Client order ids are only unique per symbol.
+        let mut manager = OrderManager::new();
+        for symbol in ["BNBUSDT", "ETHUSDT"] {
+            manager
+                .submit(
+                    trade::new_order(symbol, Side::Buy, "MARKET")
+                        .quantity(dec!(1))
+                        .new_client_order_id("web_1"),
+                )
+                .unwrap();
+        }
+        manager.handle_event(&execution_report("FILLED", 9, "1.0", "1.0"));
+        assert_eq!(
+            manager.order("BNBUSDT", "web_1").unwrap().status,
+            OrderStatus::Filled
+        );
+        assert_eq!(
+            manager.order("ETHUSDT", "web_1").unwrap().status,
+            OrderStatus::PendingNew
+        );
+        assert_eq!(manager.open_orders().count(), 1);
+
+        // A client order id reused after a fill tracks a new order.
+        let mut manager = OrderManager::new();
+        let grid_order = || {
+            trade::new_order("BNBUSDT", Side::Buy, "LIMIT")
+                .price(dec!(300))
+                .quantity(dec!(1))
+                .new_client_order_id("grid-1")
+        };
+        let mut filled = execution_report("FILLED", 10, "1.0", "1.0");
+        if let UserDataEvent::ExecutionReport(report) = &mut filled {
+            report.client_order_id = "grid-1".to_owned();
+        }
+        manager.submit(grid_order()).unwrap();
+        manager.handle_event(&filled);
+        assert!(manager.submit(grid_order()).is_ok());
+        assert!(manager.submit(grid_order()).is_err());
+
+        // The late event of the filled order leaves the new order alone.
+        manager.handle_event(&filled);
+        let order = manager.order("BNBUSDT", "grid-1").unwrap();
+        assert_eq!(order.status, OrderStatus::PendingNew);
+        assert_eq!(order.order_id, None);
+        assert_eq!(
+            manager.order_by_id("BNBUSDT", 28).unwrap().status,
+            OrderStatus::Filled
+        );
+
+        let data = r#"{"symbol":"BNBUSDT","orderId":29,"orderListId":-1,"clientOrderId":"grid-1","transactTime":1507725176595}"#;
+        let response: OrderResponse = serde_json::from_str(data).unwrap();
+        manager.handle_response(&response);
+        manager.handle_event(&filled);
+        let order = manager.order("BNBUSDT", "grid-1").unwrap();
+        assert_eq!((order.order_id, order.status), (Some(29), OrderStatus::New));
+        assert_eq!(manager.remove_closed().len(), 1);
+        assert!(manager.order_by_id("BNBUSDT", 28).is_none());
+        assert_eq!(
+            manager.order_by_id("BNBUSDT", 29),
+            manager.order("BNBUSDT", "grid-1")
+        );
+    }
+}
@@ -0,0 +1,18 @@
+use tungstenite::Error as TungsteniteError;
+
+/// Communication error with the server.
+#[derive(Debug)]
+pub enum Error {
+    /// The format of the API secret is invalid.
+    InvalidApiSecret,
+    /// Testnet credentials used against a production environment.
+    TestnetCredentials,
+    /// The connection failed or is closed.
+    Socket(TungsteniteError),
+}
+
+impl From<TungsteniteError> for Error {
+    fn from(err: TungsteniteError) -> Error {
+        Error::Socket(err)
+    }
+}
@@ -0,0 +1,436 @@
+mod error;
+
+pub use error::*;
+
+use crate::environment::Environment;
+use crate::http::Credentials;
+use crate::recording::Recorder;
+use crate::websocket::{
+    control_message, ApiRequest, ControlMethod, PendingRequests, Reply, Stream,
+};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use tungstenite::{connect, stream::MaybeTlsStream, Message, WebSocket};
+
+/// Binance websocket client using Tungstenite.
+pub struct BinanceWebSocketClient;
+
+#[allow(clippy::result_large_err)]
+impl BinanceWebSocketClient {
+    /// Connect to `url`, e.g. built with [`crate::websocket::StreamUrl`] to
+    /// receive streams without subscribing.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     market_stream::trade::TradeStream, tungstenite::BinanceWebSocketClient,
+    ///     websocket::StreamUrl,
+    /// };
+    ///
+    /// let url = StreamUrl::new(&[TradeStream::new("BTCUSDT").into()])
+    ///     .combined(false)
+    ///     .url()
+    ///     .expect("Too many streams");
+    ///
+    /// let conn = BinanceWebSocketClient::connect_with_url(&url).expect("Failed to connect");
+    /// ```
+    pub fn connect_with_url(url: &str) -> Result<WebSocketState<MaybeTlsStream<TcpStream>>, Error> {
+        let (socket, response) = connect(url)?;
+
+        log::info!("Connected to {}", url);
+        log::debug!("Response HTTP code: {}", response.status());
+        log::debug!("Response headers:");
+        for (ref header, _value) in response.headers() {
+            log::debug!("* {}", header);
+        }
+
+        let mut conn = WebSocketState::new(socket);
+        conn.environment = Environment::from_url(url);
+
+        Ok(conn)
+    }
+
+    pub fn connect() -> Result<WebSocketState<MaybeTlsStream<TcpStream>>, Error> {
+        BinanceWebSocketClient::connect_with_environment(&Environment::Production)
+    }
+
+    /// Connect to the combined streams of `environment`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     environment::Environment, tungstenite::BinanceWebSocketClient,
+    /// };
+    ///
+    /// let conn = BinanceWebSocketClient::connect_with_environment(&Environment::Testnet)
+    ///     .expect("Failed to connect");
+    /// ```
+    pub fn connect_with_environment(
+        environment: &Environment,
+    ) -> Result<WebSocketState<MaybeTlsStream<TcpStream>>, Error> {
+        let url = format!("{}/stream", environment.stream_url());
+        let conn = BinanceWebSocketClient::connect_with_url(&url)?;
+
+        Ok(conn.environment(environment.clone()))
+    }
+
+    /// Connect to the WebSocket API of `environment`.
+    pub fn connect_ws_api(
+        environment: &Environment,
+    ) -> Result<WebSocketState<MaybeTlsStream<TcpStream>>, Error> {
+        let conn = BinanceWebSocketClient::connect_with_url(environment.ws_api_url())?;
+
+        Ok(conn.environment(environment.clone()))
+    }
+}
+
+pub struct WebSocketState<T> {
+    socket: WebSocket<T>,
+    id: u64,
+    environment: Option<Environment>,
+    pending: PendingRequests,
+    recorder: Option<Recorder>,
+}
+
+#[allow(clippy::result_large_err)]
+impl<T: Read + Write> WebSocketState<T> {
+    pub fn new(socket: WebSocket<T>) -> Self {
+        Self {
+            socket,
+            id: 0,
+            environment: None,
+            pending: PendingRequests::default(),
+            recorder: None,
+        }
+    }
+
+    /// Environment of the server, set when connecting to a known url.
+    ///
+    /// Signing with testnet credentials is refused by production environments.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Record the text frames received by [`Self::read`], see [`crate::recording`].
+    pub fn record(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    fn check_credentials(&self, credentials: &Credentials) -> Result<(), Error> {
+        match &self.environment {
+            Some(environment) if !environment.accepts(credentials) => {
+                Err(Error::TestnetCredentials)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn send(
+        &mut self,
+        method: ControlMethod,
+        params: Vec<serde_json::Value>,
+    ) -> Result<u64, Error> {
+        let id = self.id;
+        self.id += 1;
+
+        let message = Message::Text(control_message(method, params, id));
+        log::debug!("Sent {}", message);
+
+        self.socket.send(message)?;
+        self.pending.insert(id, method);
+
+        Ok(id)
+    }
+
+    fn send_api(&mut self, request: ApiRequest) -> Result<u64, Error> {
+        let id = self.id;
+        self.id += 1;
+
+        let message = Message::Text(request.to_message(id));
+        log::debug!("Sent {}", message);
+
+        self.socket.send(message)?;
+        self.pending.insert(id, ControlMethod::Api);
+
+        Ok(id)
+    }
+
+    /// Sends `SUBSCRIBE` message for the given `streams`.
+    ///
+    /// `streams` are not validated. Invalid streams will be
+    /// accepted by the server, but no data will be sent.
+    /// Requests to subscribe an existing stream will be ignored
+    /// by the server.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "method": "SUBSCRIBE", "params": [ <streams> ], "id": <id> }
+    /// ```
+    pub fn subscribe<'a>(
+        &mut self,
+        streams: impl IntoIterator<Item = &'a Stream>,
+    ) -> Result<u64, Error> {
+        self.send(
+            ControlMethod::Subscribe,
+            streams.into_iter().map(|s| s.as_str().into()).collect(),
+        )
+    }
+
+    /// Sends `SUBSCRIBE` message for the given `streams` slice.
+    ///
+    /// `streams` are not validated. Invalid streams will be
+    /// accepted by the server, but no data will be sent.
+    /// Requests to subscribe an existing stream will be ignored
+    /// by the server.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "method": "SUBSCRIBE", "params": [ <streams> ], "id": <id> }
+    /// ```
+    pub fn subscribe_from_slice(&mut self, streams: &[Stream]) -> Result<u64, Error> {
+        self.send(
+            ControlMethod::Subscribe,
+            streams.iter().map(|s| s.as_str().into()).collect(),
+        )
+    }
+
+    /// Sends `UNSUBSCRIBE` message for the given `streams`.
+    ///
+    /// `streams` are not validated. Non-existing streams will be
+    /// ignored by the server.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "method": "UNSUBSCRIBE", "params": [ <streams> ], "id": <id> }
+    /// ```
+    pub fn unsubscribe<'a>(
+        &mut self,
+        streams: impl IntoIterator<Item = &'a Stream>,
+    ) -> Result<u64, Error> {
+        self.send(
+            ControlMethod::Unsubscribe,
+            streams.into_iter().map(|s| s.as_str().into()).collect(),
+        )
+    }
+
+    /// Sends `LIST_SUBSCRIPTIONS` message.
+    ///
+    /// Returns the message `id`, or an error if the message could not
+    /// be sent. The reply of the server is matched with this `id` by
+    /// [`Self::handle_reply`].
+    ///
+    /// You should expect the server to respond with a similar
+    /// message.
+    /// ```json
+    /// { "result": [ <streams> ], "id": <id> }
+    /// ```
+    pub fn subscriptions(&mut self) -> Result<u64, Error> {
+        self.send(ControlMethod::ListSubscriptions, vec![])
+    }
+
+    /// Sends `SET_PROPERTY` message for the `combined` property.
+    ///
+    /// When `combined` is `true`, stream events are wrapped as
+    /// `{"stream":"<streamName>","data":<rawPayload>}`.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "method": "SET_PROPERTY", "params": [ "combined", <combined> ], "id": <id> }
+    /// ```
+    pub fn set_combined_property(&mut self, combined: bool) -> Result<u64, Error> {
+        self.send(
+            ControlMethod::SetProperty,
+            vec!["combined".into(), combined.into()],
+        )
+    }
+
+    /// Sends `GET_PROPERTY` message for the `combined` property.
+    ///
+    /// Returns the message `id`. The value is replied as a [`crate::websocket::ControlResponse::Property`].
+    /// ```json
+    /// { "method": "GET_PROPERTY", "params": [ "combined" ], "id": <id> }
+    /// ```
+    pub fn combined_property(&mut self) -> Result<u64, Error> {
+        self.send(ControlMethod::GetProperty, vec!["combined".into()])
+    }
+
+    /// Sends `session.logon` WebSocket API request.
+    ///
+    /// Authenticates the connection with `credentials`, so that following
+    /// requests such as [`Self::user_data_stream_subscribe`] don't need to
+    /// be signed. Only Ed25519 keys are accepted by the server.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "session.logon", "params": { "apiKey": <key>, "signature": <signature>, "timestamp": <timestamp> } }
+    /// ```
+    pub fn session_logon(&mut self, credentials: &Credentials) -> Result<u64, Error> {
+        self.check_credentials(credentials)?;
+        let request = ApiRequest::new("session.logon")
+            .sign(credentials)
+            .map_err(|_| Error::InvalidApiSecret)?;
+
+        self.send_api(request)
+    }
+
+    /// Sends `userDataStream.subscribe` WebSocket API request.
+    ///
+    /// Requires a session authenticated with [`Self::session_logon`].
+    /// User data events are then pushed on this connection.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe" }
+    /// ```
+    pub fn user_data_stream_subscribe(&mut self) -> Result<u64, Error> {
+        self.send_api(ApiRequest::new("userDataStream.subscribe"))
+    }
+
+    /// Sends `userDataStream.subscribe.signature` WebSocket API request.
+    ///
+    /// Subscribes to the user data of the `credentials` account without
+    /// authenticating the session. Several accounts can be subscribed on
+    /// the same connection.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe.signature", "params": { "apiKey": <key>, "timestamp": <timestamp>, "signature": <signature> } }
+    /// ```
+    pub fn user_data_stream_subscribe_signature(
+        &mut self,
+        credentials: &Credentials,
+    ) -> Result<u64, Error> {
+        self.check_credentials(credentials)?;
+        let request = ApiRequest::new("userDataStream.subscribe.signature")
+            .sign(credentials)
+            .map_err(|_| Error::InvalidApiSecret)?;
+
+        self.send_api(request)
+    }
+
+    /// Sends `userDataStream.subscribe.listenToken` WebSocket API request.
+    ///
+    /// Subscribes to the margin user data with a `listen_token` issued by
+    /// [`crate::margin_stream::new_listen_token`].
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.subscribe.listenToken", "params": { "listenToken": <listen_token> } }
+    /// ```
+    pub fn user_data_stream_subscribe_listen_token(
+        &mut self,
+        listen_token: &str,
+    ) -> Result<u64, Error> {
+        let request = ApiRequest::new("userDataStream.subscribe.listenToken")
+            .param("listenToken", listen_token);
+
+        self.send_api(request)
+    }
+
+    /// Sends `userDataStream.unsubscribe` WebSocket API request.
+    ///
+    /// Stops the subscription `subscription_id`, or all subscriptions of
+    /// the connection when `None`.
+    ///
+    /// Returns the message `id`.
+    /// ```json
+    /// { "id": <id>, "method": "userDataStream.unsubscribe", "params": { "subscriptionId": <subscription_id> } }
+    /// ```
+    pub fn user_data_stream_unsubscribe(
+        &mut self,
+        subscription_id: Option<u64>,
+    ) -> Result<u64, Error> {
+        let mut request = ApiRequest::new("userDataStream.unsubscribe");
+        if let Some(subscription_id) = subscription_id {
+            request = request.param("subscriptionId", subscription_id);
+        }
+
+        self.send_api(request)
+    }
+
+    /// Match a received `message` with the control message or WebSocket
+    /// API request it replies to.
+    ///
+    /// Returns `None` for stream events and for messages not replying to
+    /// a pending request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     market_stream::trade::TradeStream, tungstenite::BinanceWebSocketClient,
+    /// };
+    ///
+    /// let mut conn = BinanceWebSocketClient::connect().expect("Failed to connect");
+    /// conn.subscribe(vec![&TradeStream::new("BTCUSDT").into()])
+    ///     .expect("Failed to subscribe");
+    ///
+    /// while let Ok(message) = conn.read() {
+    ///     match conn.handle_reply(&message) {
+    ///         Some(reply) => println!("Reply to {}: {:?}", reply.id, reply.result),
+    ///         None => println!("{}", message),
+    ///     }
+    /// }
+    /// ```
+    pub fn handle_reply(&mut self, message: &Message) -> Option<Reply> {
+        match message {
+            Message::Text(text) => self.pending.handle(text),
+            _ => None,
+        }
+    }
+
+    /// Whether the message `id` was sent and not replied yet.
+    pub fn is_pending(&self, id: u64) -> bool {
+        self.pending.contains(id)
+    }
+
+    /// Read a message, recording text frames when a [`Recorder`] is set.
+    ///
+    /// Recording stops on the first write error.
+    pub fn read(&mut self) -> Result<Message, Error> {
+        let message = self.socket.read()?;
+        if let (Some(recorder), Message::Text(text)) = (self.recorder.as_mut(), &message) {
+            if let Err(err) = recorder.record(text) {
+                log::error!("Recording stopped: {}", err);
+                self.recorder = None;
+            }
+        }
+
+        Ok(message)
+    }
+
+    pub fn close(mut self) -> Result<(), Error> {
+        self.socket.close(None).map_err(Error::Socket)
+    }
+}
+
+impl<T> From<WebSocketState<T>> for WebSocket<T> {
+    fn from(conn: WebSocketState<T>) -> WebSocket<T> {
+        conn.socket
+    }
+}
+
+impl<T> AsMut<WebSocket<T>> for WebSocketState<T> {
+    fn as_mut(&mut self) -> &mut WebSocket<T> {
+        &mut self.socket
+    }
+}
@@ -0,0 +1,207 @@
+use crate::http::request::Request;
+use crate::trade::{
+    self,
+    batch::{order_count, OrderBatch, OrderRateLimiter, RateLimitUsage},
+};
+use crate::ureq::{BinanceHttpClient, Error, Response};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl BinanceHttpClient {
+    /// Send the requests of an [`OrderBatch`], up to `max_concurrency` at a
+    /// time on scoped threads, waiting whenever an order count limit is reached.
+    ///
+    /// Returns one result per request, in the order of the batch.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     http::Credentials,
+    ///     trade::{self, batch::{BulkCancel, OrderBatch}, order::Side},
+    ///     ureq::BinanceHttpClient,
+    /// };
+    /// use rust_decimal_macros::dec;
+    ///
+    /// let credentials = Credentials::from_hmac("api-key".to_owned(), "api-secret".to_owned());
+    /// let client = BinanceHttpClient::default().credentials(credentials);
+    ///
+    /// let orders = ["BNBUSDT", "ETHUSDT", "BTCUSDT"]
+    ///     .map(|symbol| trade::new_order(symbol, Side::Buy, "MARKET").quote_order_qty(dec!(20)));
+    /// for result in client.send_batch(OrderBatch::new(orders)) {
+    ///     println!("{:?}", result.and_then(|response| response.into_body_str()));
+    /// }
+    ///
+    /// client.send_batch(BulkCancel::new().symbol("BNBUSDT").order_id("ETHUSDT", 12));
+    /// ```
+    pub fn send_batch(&self, batch: impl Into<OrderBatch>) -> Vec<Result<Response, Box<Error>>> {
+        let (requests, max_concurrency, check_usage, mut limiter) = batch.into().into_parts();
+
+        if check_usage && !requests.is_empty() {
+            match self.order_rate_limiter() {
+                Ok(usage) => limiter = usage,
+                Err(err) => log::warn!("Failed to get the order count usage: {:?}", err),
+            }
+        }
+
+        let mut results = Vec::with_capacity(requests.len());
+        let mut requests = requests.into_iter().peekable();
+        while let Some(next) = requests.peek() {
+            let timestamp = now();
+            if let Some(delay) = limiter.delay(order_count(next), timestamp) {
+                log::debug!("Order count limit reached, waiting {:?}", delay);
+                std::thread::sleep(delay);
+                continue;
+            }
+
+            let available = limiter.available(timestamp);
+            let mut wave: Vec<Request> = vec![];
+            let mut count = 0;
+            while let Some(next) = requests.peek() {
+                if wave.len() == max_concurrency
+                    || !wave.is_empty() && count + order_count(next) > available
+                {
+                    break;
+                }
+                count += order_count(next);
+                wave.extend(requests.next());
+            }
+            limiter.record(count, timestamp);
+
+            let responses: Vec<Result<Response, Box<Error>>> = std::thread::scope(|scope| {
+                let handles: Vec<_> = wave
+                    .into_iter()
+                    .map(|request| scope.spawn(move || self.send(request)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("Batch request thread panicked"))
+                    .collect()
+            });
+
+            let timestamp = now();
+            for response in responses.iter().flatten() {
+                limiter.update(|name| response.header(name), timestamp);
+            }
+            results.extend(responses);
+        }
+
+        results
+    }
+
+    fn order_rate_limiter(&self) -> Result<OrderRateLimiter, Box<Error>> {
+        let data = self.send(trade::order_limit_usage())?.into_body_str()?;
+        let usage: Vec<RateLimitUsage> =
+            serde_json::from_str(&data).map_err(|err| Box::new(Error::Deserialize(err)))?;
+
+        Ok(OrderRateLimiter::from_usage(&usage, now()))
+    }
+}
+
+fn now() -> u64 {
+    // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::trade::{
+        self,
+        batch::{BulkCancel, OrderBatch},
+        order::Side,
+    };
+    use crate::ureq::BinanceHttpClient;
+    use rust_decimal_macros::dec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use ureq::{AgentBuilder, Error as UreqError, Middleware, MiddlewareNext, Request, Response};
+
+    struct BatchMiddleware {
+        orders: Arc<AtomicUsize>,
+    }
+
+    impl Middleware for BatchMiddleware {
+        fn handle(&self, req: Request, _: MiddlewareNext) -> Result<Response, UreqError> {
+            let url = req.request_url()?;
+            match (req.method(), url.path()) {
+                ("GET", "/api/v3/rateLimit/order") => Response::new(
+                    200,
+                    "OK",
+                    r#"[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,"limit":50,"count":0}]"#,
+                ),
+                ("POST", "/api/v3/order") => {
+                    let count = self.orders.fetch_add(1, Ordering::SeqCst) + 1;
+                    let symbol = url
+                        .query_pairs()
+                        .into_iter()
+                        .find(|(k, _)| *k == "symbol")
+                        .map(|(_, v)| v.to_owned())
+                        .unwrap();
+                    match symbol.as_str() {
+                        "XYZUSDT" => Response::new(
+                            400,
+                            "Bad Request",
+                            r#"{"code":-1121,"msg":"Invalid symbol."}"#,
+                        ),
+                        _ => Ok(format!(
+                            "HTTP/1.1 200 OK\r\nX-MBX-ORDER-COUNT-10S: {}\r\n\r\n{}",
+                            count, symbol
+                        )
+                        .parse()
+                        .unwrap()),
+                    }
+                }
+                (method, path) => Response::new(200, "OK", &format!("{} {}", method, path)),
+            }
+        }
+    }
+
+    #[test]
+    fn client_send_batch_test() {
+        let orders = Arc::new(AtomicUsize::new(0));
+        let agent = AgentBuilder::new()
+            .middleware(BatchMiddleware {
+                orders: orders.clone(),
+            })
+            .build();
+        let client = BinanceHttpClient::new(agent, "https://base-url.com");
+
+        let batch = OrderBatch::new(
+            ["BNBUSDT", "XYZUSDT", "ETHUSDT"]
+                .map(|symbol| trade::new_order(symbol, Side::Buy, "MARKET").quantity(dec!(1))),
+        )
+        .max_concurrency(2);
+        let results = client.send_batch(batch);
+
+        assert_eq!(orders.load(Ordering::SeqCst), 3);
+        let bodies: Vec<Option<String>> = results
+            .into_iter()
+            .map(|result| result.unwrap().into_body_str().ok())
+            .collect();
+        assert_eq!(
+            bodies,
+            vec![Some("BNBUSDT".to_owned()), None, Some("ETHUSDT".to_owned())]
+        );
+
+        let results = client.send_batch(
+            BulkCancel::new()
+                .symbol("BNBUSDT")
+                .order_id("ETHUSDT", 12)
+                .order_id("ETHUSDT", 13),
+        );
+        let bodies: Vec<String> = results
+            .into_iter()
+            .map(|result| result.unwrap().into_body_str().unwrap())
+            .collect();
+        assert_eq!(
+            bodies,
+            vec![
+                "DELETE /api/v3/openOrders",
+                "DELETE /api/v3/order",
+                "DELETE /api/v3/order"
+            ]
+        );
+    }
+}
@@ -1,7 +1,11 @@
+use crate::environment::Environment;
+use crate::http::cassette::{Cassette, CassetteError, Mode, RecordedResponse};
 use crate::http::{request::Request, Credentials};
+use crate::trade::client_order_id::{assign_client_order_ids, ClientOrderIdGenerator};
 use crate::ureq::{Error, Response};
 use crate::version::VERSION;
 use http::Uri;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use ureq::{Agent, AgentBuilder, Error as UreqError};
 
@@ -9,8 +13,11 @@ use ureq::{Agent, AgentBuilder, Error as UreqError};
 pub struct BinanceHttpClient {
     client: Agent,
     base_url: String,
+    environment: Option<Environment>,
     timestamp_delta: i64,
     credentials: Option<Credentials>,
+    cassette: Option<Cassette>,
+    client_order_ids: Option<Arc<dyn ClientOrderIdGenerator>>,
 }
 
 impl BinanceHttpClient {
@@ -18,8 +25,11 @@ impl BinanceHttpClient {
         Self {
             client,
             base_url: base_url.to_owned(),
+            environment: Environment::from_url(base_url),
             timestamp_delta: 0,
             credentials: None,
+            cassette: None,
+            client_order_ids: None,
         }
     }
 
@@ -27,11 +37,24 @@ impl BinanceHttpClient {
         Self {
             client: AgentBuilder::new().build(),
             base_url: base_url.to_owned(),
+            environment: Environment::from_url(base_url),
             timestamp_delta: 0,
             credentials: None,
+            cassette: None,
+            client_order_ids: None,
         }
     }
 
+    /// Send the requests to the REST API of `environment`.
+    ///
+    /// The environment is otherwise deduced from the base url. Requests
+    /// with testnet credentials are refused by production environments.
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.base_url = environment.rest_url().to_owned();
+        self.environment = Some(environment);
+        self
+    }
+
     pub fn credentials(mut self, credentials: Credentials) -> Self {
         self.credentials = Some(credentials);
         self
@@ -41,17 +64,37 @@ impl BinanceHttpClient {
         self.timestamp_delta = timestamp_delta;
         self
     }
+
+    /// Record the requests and their response to `cassette`, or answer
+    /// them from it without network access, see [`crate::http::cassette`].
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.cassette = Some(cassette);
+        self
+    }
+
+    /// Set the client order ids missing from the order creating requests
+    /// with `generator`, see [`crate::trade::client_order_id`].
+    pub fn client_order_id_generator(
+        mut self,
+        generator: impl ClientOrderIdGenerator + 'static,
+    ) -> Self {
+        self.client_order_ids = Some(Arc::new(generator));
+        self
+    }
 }
 
 impl BinanceHttpClient {
     pub fn send<R: Into<Request>>(&self, request: R) -> Result<Response, Box<Error>> {
+        let mut request = request.into();
+        assign_client_order_ids(&mut request, self.client_order_ids.as_deref())
+            .map_err(|err| Box::new(Error::InvalidClientOrderId(err)))?;
         let Request {
             method,
             path,
             params,
             credentials,
             sign,
-        } = request.into();
+        } = request;
 
         // Build URL
         let url: Uri = format!("{}{}", self.base_url, path).parse()?;
@@ -72,7 +115,26 @@ impl BinanceHttpClient {
 
         let client_credentials = self.credentials.as_ref();
         let request_credentials = credentials.as_ref();
-        if let Some(Credentials { api_key, signature }) = request_credentials.or(client_credentials)
+        if let (Some(environment), Some(credentials)) = (
+            &self.environment,
+            request_credentials.or(client_credentials),
+        ) {
+            if !environment.accepts(credentials) {
+                return Err(Box::new(Error::TestnetCredentials));
+            }
+        }
+        let cassette = self.cassette.as_ref();
+        if let Some(cassette) = cassette.filter(|cassette| cassette.mode() == Mode::Replay) {
+            let recorded = cassette
+                .replay_response(&method, &path, &params)
+                .map_err(Error::Cassette)?;
+            log::debug!("{} (replayed)", recorded.status);
+
+            return Ok(Response::from(into_ureq_response(&recorded)?));
+        }
+        if let Some(Credentials {
+            api_key, signature, ..
+        }) = request_credentials.or(client_credentials)
         {
             // Set API-Key in header
             ureq_request = ureq_request.set("X-MBX-APIKEY", api_key);
@@ -116,10 +178,46 @@ impl BinanceHttpClient {
 
         log::debug!("{}", response.status());
 
+        if let Some(cassette) = cassette {
+            let status = response.status();
+            let headers = response
+                .headers_names()
+                .iter()
+                .filter_map(|name| {
+                    response
+                        .header(name)
+                        .map(|value| (name.to_owned(), value.to_owned()))
+                })
+                .collect();
+            let body = response
+                .into_string()
+                .map_err(|err| Error::Cassette(CassetteError::Io(err)))?;
+            let recorded = RecordedResponse::new(status, headers, body);
+            let response = into_ureq_response(&recorded)?;
+            cassette.record_response(&method, &path, &params, recorded);
+
+            return Ok(Response::from(response));
+        }
+
         Ok(Response::from(response))
     }
 }
 
+/// Rebuild a `ureq` response, parsed from its HTTP representation.
+fn into_ureq_response(recorded: &RecordedResponse) -> Result<ureq::Response, Box<Error>> {
+    let mut raw = format!("HTTP/1.1 {} \r\n", recorded.status);
+    for (name, value) in recorded.headers.iter() {
+        raw.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    raw.push_str(&format!(
+        "Content-Length: {}\r\n\r\n{}",
+        recorded.body.len(),
+        recorded.body
+    ));
+
+    raw.parse().map_err(|err| Box::new(Error::Send(err)))
+}
+
 impl Default for BinanceHttpClient {
     fn default() -> Self {
         Self::new(AgentBuilder::new().build(), "https://api.binance.com")
@@ -130,9 +228,12 @@ impl Default for BinanceHttpClient {
 mod tests {
     use super::BinanceHttpClient;
     use crate::{
-        http::{error::ClientError, request::Request, Credentials, Method},
+        environment::Environment,
+        http::{cassette::Cassette, error::ClientError, request::Request, Credentials, Method},
+        trade::{self, client_order_id::StrategyIds, order::Side},
         ureq::Error,
     };
+    use rust_decimal_macros::dec;
     use std::collections::HashMap;
     use ureq::{
         AgentBuilder, Error as UreqError, Middleware, MiddlewareNext, Request as UreqRequest,
@@ -225,6 +326,135 @@ mod tests {
         assert_eq!(data, "Test Response".to_owned());
     }
 
+    #[test]
+    fn client_refuses_testnet_credentials_test() {
+        let agent = AgentBuilder::new()
+            .middleware(MockMiddleware::new().response(200, "Test Response"))
+            .build();
+
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .environment(Environment::Production)
+            .credentials(Credentials::from_hmac("api-key", "api-secret").testnet());
+
+        let request = || Request {
+            method: Method::Get,
+            path: "/path".to_owned(),
+            params: vec![],
+            credentials: None,
+            sign: true,
+        };
+
+        match client.send(request()) {
+            Err(err) => assert!(matches!(*err, Error::TestnetCredentials)),
+            Ok(_) => panic!("Request sent"),
+        }
+
+        let client = client.environment(Environment::Testnet);
+        assert!(client.send(request()).is_ok());
+    }
+
+    #[test]
+    fn client_assigns_client_order_ids_test() {
+        let agent = AgentBuilder::new()
+            .middleware(
+                MockMiddleware::new()
+                    .param("newClientOrderId", None)
+                    .response(200, "Test Response"),
+            )
+            .build();
+
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .client_order_id_generator(StrategyIds::new("grid").unwrap());
+
+        let request = |client_order_id: Option<&str>| Request {
+            method: Method::Post,
+            path: "/api/v3/order".to_owned(),
+            params: client_order_id
+                .map(|id| vec![("newClientOrderId".to_owned(), id.to_owned())])
+                .unwrap_or_default(),
+            credentials: None,
+            sign: false,
+        };
+
+        let data = client.send(request(None)).unwrap().into_body_str().unwrap();
+        assert_eq!(data, "Test Response".to_owned());
+
+        match client.send(request(Some("grid 1"))) {
+            Err(err) => assert!(matches!(*err, Error::InvalidClientOrderId(_))),
+            Ok(_) => panic!("Request sent"),
+        }
+    }
+
+    #[test]
+    fn client_records_and_replays_cassette_test() {
+        let path = std::env::temp_dir().join(format!("ureq_cassette_{}.json", std::process::id()));
+        let request = || Request {
+            method: Method::Get,
+            path: "/api/v3/account".to_owned(),
+            params: vec![("omitZeroBalances".to_owned(), "true".to_owned())],
+            credentials: None,
+            sign: true,
+        };
+
+        let agent = AgentBuilder::new()
+            .middleware(MockMiddleware::new().response(200, "{\"uid\":354937868}"))
+            .build();
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .cassette(Cassette::record(&path));
+        let data = client.send(request()).unwrap().into_body_str().unwrap();
+        assert_eq!(data, "{\"uid\":354937868}");
+
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("api-key") && !recorded.contains("354937868"));
+
+        let agent = AgentBuilder::new()
+            .middleware(MockMiddleware::new().response(500, "Error"))
+            .build();
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .cassette(Cassette::replay(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let data = client.send(request()).unwrap().into_body_str().unwrap();
+        assert_eq!(data, "{\"uid\":0}");
+
+        let mut request = request();
+        request.path = "/api/v3/openOrders".to_owned();
+        match client.send(request) {
+            Err(err) => assert!(matches!(*err, Error::Cassette(_))),
+            Ok(_) => panic!("Request sent"),
+        }
+    }
+
+    #[test]
+    fn client_replays_cassette_with_client_order_id_generator_test() {
+        let path = std::env::temp_dir().join(format!(
+            "ureq_cassette_generator_{}.json",
+            std::process::id()
+        ));
+        let request = || trade::new_order("BNBUSDT", Side::Buy, "MARKET").quantity(dec!(1));
+
+        let agent = AgentBuilder::new()
+            .middleware(MockMiddleware::new().response(200, "{\"orderId\":28}"))
+            .build();
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .client_order_id_generator(StrategyIds::new("record").unwrap())
+            .cassette(Cassette::record(&path));
+        client.send(request()).unwrap();
+
+        let agent = AgentBuilder::new()
+            .middleware(MockMiddleware::new().response(500, "Error"))
+            .build();
+        let client = BinanceHttpClient::new(agent, "https://base-url.com")
+            .credentials(Credentials::from_hmac("api-key", "api-secret"))
+            .client_order_id_generator(StrategyIds::new("replay").unwrap())
+            .cassette(Cassette::replay(&path).unwrap());
+        std::fs::remove_file(&path).unwrap();
+        let data = client.send(request()).unwrap().into_body_str().unwrap();
+        assert_eq!(data, "{\"orderId\":28}");
+    }
+
     #[test]
     fn client_handles_not_found_error_test() {
         let agent = AgentBuilder::new()
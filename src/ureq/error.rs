@@ -1,9 +1,14 @@
+use crate::http::cassette::CassetteError;
 use crate::http::error::{ClientError, HttpError as BinanceHttpError};
+use crate::trade::client_order_id::InvalidClientOrderId;
 use http::{uri::InvalidUri, Error as HttpError};
 use ureq::Error as UreqError;
 
 /// Communication error with the server.
+///
+/// New variants may be added in minor releases.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// 4XX error from the server.
     Client(ClientError),
@@ -11,6 +16,14 @@ pub enum Error {
     Server(BinanceHttpError<String>),
     /// The format of the API secret is invalid.
     InvalidApiSecret,
+    /// Testnet credentials used against a production environment.
+    TestnetCredentials,
+    /// A client order id of the request does not match the format accepted by the server.
+    InvalidClientOrderId(InvalidClientOrderId),
+    /// The response body could not be deserialized.
+    Deserialize(serde_json::Error),
+    /// The cassette could not record or replay the request.
+    Cassette(CassetteError),
     Parse(HttpError),
     Send(UreqError),
 }
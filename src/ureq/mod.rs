@@ -14,12 +14,12 @@
 //!
 //! # Testnet
 //!
-//! Can be configured to communicate with the testnet environment by specifying the base url on initialization.
+//! Can be configured to communicate with the testnet environment by specifying the environment on initialization.
 //!
 //! ```
-//! use binance_spot_connector_rust::ureq::BinanceHttpClient;
+//! use binance_spot_connector_rust::{environment::Environment, ureq::BinanceHttpClient};
 //!
-//! let testnet_client = BinanceHttpClient::with_url("https://testnet.binance.vision");
+//! let testnet_client = BinanceHttpClient::default().environment(Environment::Testnet);
 //! ```
 //!
 //! # Errors
@@ -40,10 +40,13 @@
 //! let client = BinanceHttpClient::new(agent, "https://api.binance.com");
 //! ```
 
+mod batch;
 mod client;
 mod error;
+mod pagination;
 mod response;
 
 pub use client::*;
 pub use error::*;
+pub use pagination::*;
 pub use response::*;
@@ -0,0 +1,160 @@
+use crate::http::pagination::Paginate;
+use crate::ureq::{BinanceHttpClient, Error};
+use std::collections::VecDeque;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+impl BinanceHttpClient {
+    /// Iterate over the items of a [`Paginate`] implementation, sending one request per page.
+    ///
+    /// After an error, calling `next` again retries the same page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use binance_spot_connector_rust::{
+    ///     market::{self, kline_history::KlineHistoryItem, klines::KlineInterval},
+    ///     ureq::BinanceHttpClient,
+    /// };
+    ///
+    /// let client = BinanceHttpClient::default();
+    /// let history = market::kline_history("BTCUSDT", KlineInterval::Minutes1, 1654079109000);
+    ///
+    /// for item in client.paginate(history) {
+    ///     match item.expect("Failed to download klines") {
+    ///         KlineHistoryItem::Kline(kline) => println!("{} {}", kline.open_time, kline.close),
+    ///         KlineHistoryItem::Gap(gap) => println!("Missing {}..{}", gap.start_time, gap.end_time),
+    ///     }
+    /// }
+    /// ```
+    pub fn paginate<P: Paginate>(&self, pager: P) -> Paginated<'_, P> {
+        Paginated {
+            client: self,
+            pager,
+            items: VecDeque::new(),
+        }
+    }
+}
+
+/// Blocking iterator over the items of a [`Paginate`] implementation.
+pub struct Paginated<'a, P: Paginate> {
+    client: &'a BinanceHttpClient,
+    pager: P,
+    items: VecDeque<P::Item>,
+}
+
+impl<'a, P: Paginate> Paginated<'a, P> {
+    /// Download state, e.g. to store the cursor of a history for a later resume.
+    pub fn pager(&self) -> &P {
+        &self.pager
+    }
+
+    fn fetch_page(&mut self) -> Result<(), Box<Error>> {
+        let request = match self.pager.next_request() {
+            Some(request) => request,
+            None => return Ok(()),
+        };
+
+        let response = self.client.send(request)?;
+        let used_weight = response
+            .header(self.pager.weight_header())
+            .and_then(|weight| weight.parse().ok());
+
+        let data = response.into_body_str()?;
+        let page: P::Page =
+            serde_json::from_str(&data).map_err(|err| Box::new(Error::Deserialize(err)))?;
+        self.items.extend(self.pager.handle_page(page));
+
+        // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis();
+
+        if let Some(delay) = self.pager.throttle(used_weight, timestamp as u64) {
+            log::debug!("Request weight limit reached, waiting {:?}", delay);
+            std::thread::sleep(delay);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, P: Paginate> Iterator for Paginated<'a, P> {
+    type Item = Result<P::Item, Box<Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                return Some(Ok(item));
+            }
+
+            self.pager.next_request()?;
+
+            if let Err(err) = self.fetch_page() {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::market::{
+        kline_history::{KlineGap, KlineHistoryItem},
+        klines::KlineInterval,
+    };
+    use crate::ureq::BinanceHttpClient;
+    use ureq::{AgentBuilder, Error as UreqError, Middleware, MiddlewareNext, Request, Response};
+
+    fn kline(open_time: u64) -> String {
+        format!(
+            r#"[{},"1","1","1","1","1",{},"1",1,"1","1","0"]"#,
+            open_time,
+            open_time + 59_999
+        )
+    }
+
+    struct PagesMiddleware;
+
+    impl Middleware for PagesMiddleware {
+        fn handle(&self, req: Request, _: MiddlewareNext) -> Result<Response, UreqError> {
+            let url = req.request_url()?;
+            let start_time = url
+                .query_pairs()
+                .into_iter()
+                .find(|(k, _)| *k == "startTime")
+                .map(|(_, v)| v)
+                .unwrap();
+
+            let body = match start_time {
+                "0" => format!("[{},{}]", kline(0), kline(60_000)),
+                "60001" => format!("[{}]", kline(180_000)),
+                _ => "[]".to_owned(),
+            };
+
+            Response::new(200, "OK", &body)
+        }
+    }
+
+    #[test]
+    fn client_paginate_test() {
+        let agent = AgentBuilder::new().middleware(PagesMiddleware).build();
+
+        let client = BinanceHttpClient::new(agent, "https://base-url.com");
+        let history = crate::market::kline_history("BTCUSDT", KlineInterval::Minutes1, 0)
+            .limit(2)
+            .weight_limit(None);
+
+        let items: Vec<KlineHistoryItem> =
+            client.paginate(history).map(|item| item.unwrap()).collect();
+
+        assert_eq!(items.len(), 4);
+        assert_eq!(
+            items[2],
+            KlineHistoryItem::Gap(KlineGap {
+                start_time: 120_000,
+                end_time: 180_000
+            })
+        );
+    }
+}
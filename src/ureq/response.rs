@@ -13,6 +13,10 @@ impl Response {
         Self { inner_response }
     }
 
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        self.inner_response.header(name)
+    }
+
     /// Fetch the data received from the API.
     pub fn into_body_str(self) -> Result<String, Box<Error>> {
         let status = self.inner_response.status();
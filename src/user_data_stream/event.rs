@@ -0,0 +1,293 @@
+use crate::trade::client_order_id::strategy_tag;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+/// User Data Stream event.
+///
+/// Events are identical whether they are received from a `listenKey`
+/// stream or from a WebSocket API user data subscription, in which case
+/// they are wrapped in a [`SubscriptionEvent`].
+///
+/// Event types not modelled by the library are deserialized as [`UserDataEvent::Unknown`].
+///
+/// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/user-data-stream)
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "e")]
+pub enum UserDataEvent {
+    #[serde(rename = "outboundAccountPosition")]
+    OutboundAccountPosition(OutboundAccountPosition),
+    #[serde(rename = "balanceUpdate")]
+    BalanceUpdate(BalanceUpdate),
+    #[serde(rename = "executionReport")]
+    ExecutionReport(Box<ExecutionReport>),
+    #[serde(rename = "listStatus")]
+    ListStatus(ListStatus),
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpired),
+    #[serde(rename = "externalLockUpdate")]
+    ExternalLockUpdate(ExternalLockUpdate),
+    #[serde(rename = "eventStreamTerminated")]
+    EventStreamTerminated(EventStreamTerminated),
+    #[serde(other)]
+    Unknown,
+}
+
+/// User data event pushed on a WebSocket API user data subscription.
+///
+/// ```json
+/// { "subscriptionId": 0, "event": { "e": "outboundAccountPosition", ... } }
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionEvent {
+    #[serde(default)]
+    pub subscription_id: Option<u64>,
+    pub event: UserDataEvent,
+}
+
+/// Sent any time an account balance has changed and contains the assets
+/// that were possibly changed by the event that generated the balance change.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct OutboundAccountPosition {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "u")]
+    pub last_update_time: u64,
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountBalance>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct AccountBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "f")]
+    pub free: Decimal,
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
+
+/// Sent on deposits, withdrawals and transfers of funds between accounts.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct BalanceUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "d")]
+    pub balance_delta: Decimal,
+    #[serde(rename = "T")]
+    pub clear_time: u64,
+}
+
+/// Sent when an order is updated.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExecutionReport {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "P")]
+    pub stop_price: Decimal,
+    #[serde(rename = "F")]
+    pub iceberg_quantity: Decimal,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    /// Client order id of the order being canceled, empty otherwise.
+    #[serde(rename = "C")]
+    pub original_client_order_id: String,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "r")]
+    pub reject_reason: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l")]
+    pub last_executed_quantity: Decimal,
+    #[serde(rename = "z")]
+    pub cumulative_filled_quantity: Decimal,
+    #[serde(rename = "L")]
+    pub last_executed_price: Decimal,
+    #[serde(rename = "n")]
+    pub commission_amount: Decimal,
+    #[serde(rename = "N")]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    /// `-1` unless the execution is a trade.
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+    #[serde(rename = "w")]
+    pub is_on_book: bool,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "O")]
+    pub order_creation_time: u64,
+    #[serde(rename = "Z")]
+    pub cumulative_quote_quantity: Decimal,
+    #[serde(rename = "Y")]
+    pub last_quote_quantity: Decimal,
+    #[serde(rename = "Q")]
+    pub quote_order_quantity: Decimal,
+    #[serde(rename = "W", default)]
+    pub working_time: Option<u64>,
+    #[serde(rename = "V", default)]
+    pub self_trade_prevention_mode: Option<String>,
+    #[serde(rename = "d", default)]
+    pub trailing_delta: Option<u64>,
+    #[serde(rename = "D", default)]
+    pub trailing_time: Option<i64>,
+    #[serde(rename = "j", default)]
+    pub strategy_id: Option<u64>,
+    #[serde(rename = "J", default)]
+    pub strategy_type: Option<u64>,
+    #[serde(rename = "v", default)]
+    pub prevented_match_id: Option<u64>,
+}
+
+impl ExecutionReport {
+    /// Strategy tag of the client order id of the order, see
+    /// [`crate::trade::client_order_id::StrategyIds`].
+    pub fn strategy_tag(&self) -> Option<&str> {
+        match self.original_client_order_id.is_empty() {
+            true => strategy_tag(&self.client_order_id),
+            false => strategy_tag(&self.original_client_order_id),
+        }
+    }
+}
+
+/// Sent in addition to `executionReport` when an order belongs to an order list.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListStatus {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "g")]
+    pub order_list_id: i64,
+    #[serde(rename = "c")]
+    pub contingency_type: String,
+    #[serde(rename = "l")]
+    pub list_status_type: String,
+    #[serde(rename = "L")]
+    pub list_order_status: String,
+    #[serde(rename = "r")]
+    pub list_reject_reason: String,
+    #[serde(rename = "C")]
+    pub list_client_order_id: String,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "O")]
+    pub orders: Vec<ListStatusOrder>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListStatusOrder {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+}
+
+/// Sent when the `listenKey` used by the stream has expired.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ListenKeyExpired {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "listenKey")]
+    pub listen_key: String,
+}
+
+/// Sent when part of the spot balance is locked or unlocked by an external system.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ExternalLockUpdate {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "d")]
+    pub delta: Decimal,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+}
+
+/// Sent on the WebSocket API when the user data subscription is stopped,
+/// e.g. after `session.logout` or `userDataStream.unsubscribe`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct EventStreamTerminated {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountBalance, OutboundAccountPosition, SubscriptionEvent, UserDataEvent};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn user_data_stream_parse_execution_report_test() {
+        let data = r#"{"e":"executionReport","E":1499405658658,"s":"ETHBTC","c":"mUvoqJxFIILMdfAW5iGSOW","S":"BUY","o":"LIMIT","f":"GTC","q":"1.00000000","p":"0.10264410","P":"0.00000000","F":"0.00000000","g":-1,"C":"","x":"NEW","X":"NEW","r":"NONE","i":4293153,"l":"0.00000000","z":"0.00000000","L":"0.00000000","n":"0","N":null,"T":1499405658657,"t":-1,"v":3,"I":8641984,"w":true,"m":false,"M":false,"O":1499405658657,"Z":"0.00000000","Y":"0.00000000","Q":"0.00000000","W":1499405658657,"V":"NONE"}"#;
+
+        let event: UserDataEvent = serde_json::from_str(data).unwrap();
+
+        match event {
+            UserDataEvent::ExecutionReport(report) => {
+                assert_eq!(report.symbol, "ETHBTC");
+                assert_eq!(report.price, dec!(0.10264410));
+                assert_eq!(report.order_status, "NEW");
+                assert_eq!(report.commission_asset, None);
+                assert_eq!(report.prevented_match_id, Some(3));
+                assert_eq!(report.trailing_delta, None);
+            }
+            _ => panic!("Invalid event"),
+        }
+    }
+
+    #[test]
+    fn user_data_stream_parse_subscription_event_test() {
+        let data = r#"{"subscriptionId":0,"event":{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"0.000000"}]}}"#;
+
+        let event: SubscriptionEvent = serde_json::from_str(data).unwrap();
+
+        assert_eq!(
+            event,
+            SubscriptionEvent {
+                subscription_id: Some(0),
+                event: UserDataEvent::OutboundAccountPosition(OutboundAccountPosition {
+                    event_time: 1564034571105,
+                    last_update_time: 1564034571073,
+                    balances: vec![AccountBalance {
+                        asset: "ETH".to_owned(),
+                        free: dec!(10000),
+                        locked: dec!(0),
+                    }],
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn user_data_stream_parse_unknown_event_test() {
+        let data = r#"{"e":"USER_LIABILITY_CHANGE","E":1701949843000}"#;
+
+        let event: UserDataEvent = serde_json::from_str(data).unwrap();
+
+        assert_eq!(event, UserDataEvent::Unknown);
+    }
+}
@@ -1,6 +1,7 @@
 //! Binance SPOT User Data Websocket  Streams
 //!
 //! A collection of SPOT User Data Websocket streams.
+pub mod event;
 mod user_data;
 
 pub use user_data::UserDataStream;
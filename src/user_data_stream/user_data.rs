@@ -7,16 +7,16 @@ use crate::websocket::Stream;
 /// Possible Updates:
 ///
 /// * `outboundAccountPosition` is sent any time an account balance has
-/// changed and contains the assets that were possibly changed by
-/// the event that generated the balance change.
+///   changed and contains the assets that were possibly changed by
+///   the event that generated the balance change.
 ///
 /// * `balanceUpdate` occurs during the following: Deposits or
-/// withdrawals from the account; Transfer of funds between
-/// accounts (e.g. Spot to Margin).
+///   withdrawals from the account; Transfer of funds between
+///   accounts (e.g. Spot to Margin).
 ///
 /// * `executionReport` occurs when an order is updated. If the order is
-/// an OCO, an event will be displayed named `ListStatus` in addition
-/// to the `executionReport` event.
+///   an OCO, an event will be displayed named `ListStatus` in addition
+///   to the `executionReport` event.
 ///
 /// [API Documentation](https://developers.binance.com/docs/binance-spot-api-docs/user-data-stream)
 pub struct UserDataStream {
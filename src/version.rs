@@ -1 +1 @@
-pub const VERSION: &str = "1.3.0";
+pub const VERSION: &str = "2.0.0";
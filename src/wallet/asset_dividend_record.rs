@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/asset/assetDividend`
 ///
@@ -13,6 +19,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = wallet::asset_dividend_record().asset("BNB").start_time(1640995200000).end_time(1640995200000).limit(123);
 /// ```
+#[derive(Clone)]
 pub struct AssetDividendRecord {
     asset: Option<String>,
     start_time: Option<u64>,
@@ -105,6 +112,47 @@ impl Default for AssetDividendRecord {
     }
 }
 
+impl TimeWindowed for AssetDividendRecord {
+    type Page = Rows<AssetDividend>;
+    type Row = AssetDividend;
+
+    fn max_window(&self) -> Option<u64> {
+        Some(180 * 86_400_000)
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Capped(500)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, _page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.limit = Some(500);
+        request.into()
+    }
+
+    fn rows(page: Rows<AssetDividend>) -> Vec<AssetDividend> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        10
+    }
+}
+
+/// Dividend returned by `GET /sapi/v1/asset/assetDividend`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividend {
+    pub id: u64,
+    pub amount: Decimal,
+    pub asset: String,
+    pub div_time: u64,
+    pub en_info: String,
+    pub tran_id: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::AssetDividendRecord;
@@ -1,4 +1,6 @@
 use crate::http::{request::Request, Credentials, Method};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/capital/config/getall`
 ///
@@ -61,6 +63,40 @@ impl Default for CoinInfo {
     }
 }
 
+/// Coin returned by `GET /sapi/v1/capital/config/getall`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Coin {
+    pub coin: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub withdraw_all_enable: bool,
+    pub network_list: Vec<Network>,
+}
+
+/// Network of a [`Coin`], with its withdrawal settings.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Network {
+    pub network: String,
+    pub coin: String,
+    #[serde(default)]
+    pub is_default: bool,
+    #[serde(default)]
+    pub deposit_enable: bool,
+    pub withdraw_enable: bool,
+    pub withdraw_fee: Decimal,
+    pub withdraw_min: Decimal,
+    pub withdraw_max: Decimal,
+    /// Step of the withdrawal amounts, zero if any amount is accepted.
+    #[serde(default)]
+    pub withdraw_integer_multiple: Decimal,
+    /// Whether the address is shared by many users, a tag then identifies the recipient.
+    #[serde(default)]
+    pub same_address: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::CoinInfo;
@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/capital/deposit/hisrec`
 ///
@@ -16,6 +22,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = wallet::deposit_history().coin("BNB").limit(500);
 /// ```
+#[derive(Clone)]
 pub struct DepositHistory {
     coin: Option<String>,
     status: Option<u32>,
@@ -130,6 +137,55 @@ impl Default for DepositHistory {
     }
 }
 
+impl TimeWindowed for DepositHistory {
+    type Page = Vec<Deposit>;
+    type Row = Deposit;
+
+    fn max_window(&self) -> Option<u64> {
+        Some(90 * 86_400_000)
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(1000)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.offset = Some(page * 1000);
+        request.limit = Some(1000);
+        request.into()
+    }
+
+    fn rows(page: Vec<Deposit>) -> Vec<Deposit> {
+        page
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Deposit returned by `GET /sapi/v1/capital/deposit/hisrec`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Deposit {
+    pub id: String,
+    pub amount: Decimal,
+    pub coin: String,
+    pub network: String,
+    pub status: u32,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    pub transfer_type: u32,
+    pub confirm_times: String,
+    pub unlock_confirm: u32,
+    pub wallet_type: u32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::DepositHistory;
@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/asset/dribblet`
 ///
@@ -11,6 +17,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = wallet::dust_log();
 /// ```
+#[derive(Clone)]
 pub struct DustLog {
     start_time: Option<u64>,
     end_time: Option<u64>,
@@ -81,6 +88,65 @@ impl From<DustLog> for Request {
     }
 }
 
+impl TimeWindowed for DustLog {
+    type Page = DustLogPage;
+    type Row = Dribblet;
+
+    fn max_window(&self) -> Option<u64> {
+        None
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Capped(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, _page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.into()
+    }
+
+    fn rows(page: DustLogPage) -> Vec<Dribblet> {
+        page.user_asset_dribblets
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Response of `GET /sapi/v1/asset/dribblet`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogPage {
+    pub total: u64,
+    #[serde(default)]
+    pub user_asset_dribblets: Vec<Dribblet>,
+}
+
+/// Conversion of small assets to BNB.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Dribblet {
+    pub operate_time: u64,
+    pub total_transfered_amount: Decimal,
+    pub total_service_charge_amount: Decimal,
+    pub trans_id: u64,
+    pub user_asset_dribblet_details: Vec<DribbletDetail>,
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DribbletDetail {
+    pub trans_id: u64,
+    pub service_charge_amount: Decimal,
+    pub amount: Decimal,
+    pub operate_time: u64,
+    pub transfered_amount: Decimal,
+    pub from_asset: String,
+}
+
 #[cfg(test)]
 mod tests {
     use super::DustLog;
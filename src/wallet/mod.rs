@@ -27,6 +27,7 @@ pub mod universal_transfer;
 pub mod universal_transfer_history;
 pub mod user_asset;
 pub mod withdraw;
+pub mod withdraw_guard;
 pub mod withdraw_history;
 
 use rust_decimal::Decimal;
@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{Rows, TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/asset/transfer`
 ///
@@ -16,6 +22,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = wallet::universal_transfer_history("MAIN_UMFUTURE").start_time(1640995200000).end_time(1640995200000).current(1).size(100).from_symbol("BNBUSDT").to_symbol("BNBUSDT");
 /// ```
+#[derive(Clone)]
 pub struct UniversalTransferHistory {
     r#type: String,
     start_time: Option<u64>,
@@ -126,6 +133,48 @@ impl From<UniversalTransferHistory> for Request {
     }
 }
 
+impl TimeWindowed for UniversalTransferHistory {
+    type Page = Rows<UniversalTransfer>;
+    type Row = UniversalTransfer;
+
+    fn max_window(&self) -> Option<u64> {
+        None
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(100)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.current = Some(page + 1);
+        request.size = Some(100);
+        request.into()
+    }
+
+    fn rows(page: Rows<UniversalTransfer>) -> Vec<UniversalTransfer> {
+        page.rows
+    }
+
+    fn page_weight(&self) -> u32 {
+        1
+    }
+}
+
+/// Transfer returned by `GET /sapi/v1/asset/transfer`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    pub asset: String,
+    pub amount: Decimal,
+    pub r#type: String,
+    pub status: String,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::UniversalTransferHistory;
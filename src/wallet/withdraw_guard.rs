@@ -0,0 +1,593 @@
+use crate::http::request::Request;
+use crate::wallet::coin_info::{Coin, Network};
+use crate::wallet::withdraw::Withdraw;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Time a dry run can be confirmed for, unless set with [`WithdrawGuard::confirmation_ttl`].
+pub const DEFAULT_CONFIRMATION_TTL: Duration = Duration::from_secs(60);
+
+const DAY: u64 = 86_400_000;
+
+/// Withdrawals checked against a whitelist and amount caps, an opt-in
+/// alternative to [`crate::wallet::withdraw()`].
+///
+/// A withdrawal is first dry run: it must go to a whitelisted destination,
+/// within the per withdrawal and daily caps of the coin, and be accepted by the
+/// network according to `GET /sapi/v1/capital/config/getall`. The dry run
+/// returns what would be sent along with a confirmation token, which
+/// [`WithdrawGuard::confirm`] exchanges once for the `withdraw` request.
+///
+/// Withdrawals are refused until the coin information is set with
+/// [`WithdrawGuard::coin_info`], and to any destination not whitelisted.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::wallet::{coin_info::Coin, withdraw_guard::WithdrawGuard};
+/// use rust_decimal_macros::dec;
+///
+/// let coins: Vec<Coin> = serde_json::from_str(r#"[{"coin":"BNB","networkList":[{"network":"BSC","coin":"BNB","withdrawEnable":true,"withdrawFee":"0.0005","withdrawMin":"0.01","withdrawMax":"10000","withdrawIntegerMultiple":"0.00000001"}]}]"#).unwrap();
+///
+/// let mut guard = WithdrawGuard::new()
+///     .whitelist("BNB", "BSC", "0x4f2a", None)
+///     .coin_limit("BNB", dec!(5))
+///     .daily_limit("BNB", dec!(20));
+/// guard.coin_info(&coins);
+///
+/// let dry_run = guard.dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(2), 1_700_000_000_000).expect("Withdrawal refused");
+/// assert_eq!(dry_run.received, dec!(1.9995));
+///
+/// let request = guard.confirm_at(&dry_run.confirmation, 1_700_000_010_000).expect("Withdrawal refused");
+/// ```
+pub struct WithdrawGuard {
+    whitelist: Vec<Destination>,
+    coin_limits: HashMap<String, Decimal>,
+    daily_limits: HashMap<String, Decimal>,
+    networks: HashMap<(String, String), Network>,
+    confirmation_ttl: Duration,
+    pending: HashMap<String, DryRun>,
+    /// Amount withdrawn per coin and UTC day, the last two days only.
+    withdrawn: HashMap<(String, u64), Decimal>,
+}
+
+impl WithdrawGuard {
+    pub fn new() -> Self {
+        Self {
+            whitelist: vec![],
+            coin_limits: HashMap::new(),
+            daily_limits: HashMap::new(),
+            networks: HashMap::new(),
+            confirmation_ttl: DEFAULT_CONFIRMATION_TTL,
+            pending: HashMap::new(),
+            withdrawn: HashMap::new(),
+        }
+    }
+
+    /// Allow withdrawals of `coin` on `network` to `address`, with `tag` as
+    /// the `addressTag` if any.
+    pub fn whitelist(
+        mut self,
+        coin: &str,
+        network: &str,
+        address: &str,
+        tag: Option<&str>,
+    ) -> Self {
+        self.whitelist.push(Destination {
+            coin: coin.to_owned(),
+            network: network.to_owned(),
+            address: address.to_owned(),
+            tag: tag.map(|tag| tag.to_owned()),
+        });
+        self
+    }
+
+    /// Largest amount of a single withdrawal of `coin`.
+    pub fn coin_limit(mut self, coin: &str, limit: Decimal) -> Self {
+        self.coin_limits.insert(coin.to_owned(), limit);
+        self
+    }
+
+    /// Largest amount of `coin` withdrawn per UTC day.
+    pub fn daily_limit(mut self, coin: &str, limit: Decimal) -> Self {
+        self.daily_limits.insert(coin.to_owned(), limit);
+        self
+    }
+
+    pub fn confirmation_ttl(mut self, confirmation_ttl: Duration) -> Self {
+        self.confirmation_ttl = confirmation_ttl;
+        self
+    }
+
+    /// Set the networks of the coins, as returned by `GET /sapi/v1/capital/config/getall`.
+    pub fn coin_info(&mut self, coins: &[Coin]) {
+        self.networks = coins
+            .iter()
+            .flat_map(|coin| coin.network_list.iter())
+            .map(|network| {
+                (
+                    (network.coin.clone(), network.network.clone()),
+                    network.clone(),
+                )
+            })
+            .collect();
+    }
+
+    /// Count `amount` of `coin` withdrawn at `timestamp` without the guard,
+    /// e.g. from `GET /sapi/v1/capital/withdraw/history`, towards the daily limit.
+    pub fn record(&mut self, coin: &str, amount: Decimal, timestamp: u64) {
+        *self
+            .withdrawn
+            .entry((coin.to_owned(), timestamp / DAY))
+            .or_insert(Decimal::ZERO) += amount;
+
+        // Records may come in any order, e.g. newest first from the history.
+        if let Some(latest) = self.withdrawn.keys().map(|(_, day)| *day).max() {
+            self.withdrawn.retain(|(_, day), _| day + 1 >= latest);
+        }
+    }
+
+    /// Amount of `coin` withdrawn on the UTC day of `timestamp`.
+    pub fn withdrawn(&self, coin: &str, timestamp: u64) -> Decimal {
+        self.withdrawn
+            .get(&(coin.to_owned(), timestamp / DAY))
+            .copied()
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// Check a withdrawal according to the system clock, see [`WithdrawGuard::dry_run_at`].
+    pub fn dry_run(
+        &mut self,
+        coin: &str,
+        network: &str,
+        address: &str,
+        tag: Option<&str>,
+        amount: Decimal,
+    ) -> Result<DryRun, WithdrawRefused> {
+        self.dry_run_at(coin, network, address, tag, amount, now())
+    }
+
+    /// Check a withdrawal at `timestamp`, returning what would be sent and the
+    /// token to confirm it with.
+    pub fn dry_run_at(
+        &mut self,
+        coin: &str,
+        network: &str,
+        address: &str,
+        tag: Option<&str>,
+        amount: Decimal,
+        timestamp: u64,
+    ) -> Result<DryRun, WithdrawRefused> {
+        let destination = Destination {
+            coin: coin.to_owned(),
+            network: network.to_owned(),
+            address: address.to_owned(),
+            tag: tag.map(|tag| tag.to_owned()),
+        };
+        let fee = self.check(&destination, amount, timestamp)?;
+
+        self.pending
+            .retain(|_, dry_run| timestamp < dry_run.expires_at);
+
+        let dry_run = DryRun {
+            destination,
+            amount,
+            fee,
+            received: amount - fee,
+            confirmation: confirmation_token(),
+            expires_at: timestamp + self.confirmation_ttl.as_millis() as u64,
+        };
+        self.pending
+            .insert(dry_run.confirmation.clone(), dry_run.clone());
+
+        Ok(dry_run)
+    }
+
+    /// Confirm a dry run according to the system clock, see [`WithdrawGuard::confirm_at`].
+    pub fn confirm(&mut self, confirmation: &str) -> Result<Request, WithdrawRefused> {
+        self.confirm_at(confirmation, now())
+    }
+
+    /// Build the `withdraw` request of a dry run, checked again at `timestamp`.
+    ///
+    /// A token confirms a single withdrawal, which counts towards the daily
+    /// limit from then on. The token is sent as the `withdrawOrderId`.
+    ///
+    /// The request is returned built, so that the checked destination can't
+    /// be changed; it is signed with the credentials of the client.
+    pub fn confirm_at(
+        &mut self,
+        confirmation: &str,
+        timestamp: u64,
+    ) -> Result<Request, WithdrawRefused> {
+        let dry_run = self
+            .pending
+            .remove(confirmation)
+            .ok_or(WithdrawRefused::UnknownConfirmation)?;
+        if timestamp >= dry_run.expires_at {
+            return Err(WithdrawRefused::ConfirmationExpired {
+                expires_at: dry_run.expires_at,
+                timestamp,
+            });
+        }
+        self.check(&dry_run.destination, dry_run.amount, timestamp)?;
+        self.record(&dry_run.destination.coin, dry_run.amount, timestamp);
+
+        let Destination {
+            coin,
+            network,
+            address,
+            tag,
+        } = &dry_run.destination;
+        let mut request = Withdraw::new(coin, address, dry_run.amount)
+            .network(network)
+            .withdraw_order_id(&dry_run.confirmation);
+        if let Some(tag) = tag {
+            request = request.address_tag(tag);
+        }
+
+        Ok(request.into())
+    }
+
+    /// Check a withdrawal, returning the network fee.
+    fn check(
+        &self,
+        destination: &Destination,
+        amount: Decimal,
+        timestamp: u64,
+    ) -> Result<Decimal, WithdrawRefused> {
+        if !self.whitelist.contains(destination) {
+            return Err(WithdrawRefused::NotWhitelisted(destination.clone()));
+        }
+
+        let coin = &destination.coin;
+        let network = self
+            .networks
+            .get(&(coin.clone(), destination.network.clone()))
+            .ok_or_else(|| WithdrawRefused::UnknownNetwork {
+                coin: coin.clone(),
+                network: destination.network.clone(),
+            })?;
+        if !network.withdraw_enable {
+            return Err(WithdrawRefused::WithdrawDisabled {
+                coin: coin.clone(),
+                network: network.network.clone(),
+            });
+        }
+        if network.same_address && destination.tag.is_none() {
+            return Err(WithdrawRefused::TagRequired {
+                coin: coin.clone(),
+                network: network.network.clone(),
+            });
+        }
+        if amount < network.withdraw_min {
+            return Err(WithdrawRefused::BelowMinimum {
+                amount,
+                minimum: network.withdraw_min,
+            });
+        }
+        if amount > network.withdraw_max {
+            return Err(WithdrawRefused::AboveMaximum {
+                amount,
+                maximum: network.withdraw_max,
+            });
+        }
+        if !network.withdraw_integer_multiple.is_zero()
+            && !(amount % network.withdraw_integer_multiple).is_zero()
+        {
+            return Err(WithdrawRefused::InvalidMultiple {
+                amount,
+                multiple: network.withdraw_integer_multiple,
+            });
+        }
+        if amount <= network.withdraw_fee {
+            return Err(WithdrawRefused::FeeExceedsAmount {
+                amount,
+                fee: network.withdraw_fee,
+            });
+        }
+
+        if let Some(limit) = self.coin_limits.get(coin) {
+            if amount > *limit {
+                return Err(WithdrawRefused::CoinLimitExceeded {
+                    amount,
+                    limit: *limit,
+                });
+            }
+        }
+        if let Some(limit) = self.daily_limits.get(coin) {
+            let withdrawn = self.withdrawn(coin, timestamp);
+            if withdrawn + amount > *limit {
+                return Err(WithdrawRefused::DailyLimitExceeded {
+                    amount,
+                    withdrawn,
+                    limit: *limit,
+                });
+            }
+        }
+
+        Ok(network.withdraw_fee)
+    }
+}
+
+impl Default for WithdrawGuard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Address a coin is withdrawn to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Destination {
+    pub coin: String,
+    pub network: String,
+    pub address: String,
+    pub tag: Option<String>,
+}
+
+/// Withdrawal checked by [`WithdrawGuard::dry_run_at`], not sent yet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DryRun {
+    pub destination: Destination,
+    /// Amount debited from the account.
+    pub amount: Decimal,
+    pub fee: Decimal,
+    /// Amount credited to the destination, `amount - fee`.
+    pub received: Decimal,
+    /// Token to pass to [`WithdrawGuard::confirm_at`] to get the request.
+    pub confirmation: String,
+    /// Time the confirmation is refused from, in milliseconds.
+    pub expires_at: u64,
+}
+
+/// Withdrawal refused by a [`WithdrawGuard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WithdrawRefused {
+    NotWhitelisted(Destination),
+    /// Network missing from the coin information.
+    UnknownNetwork {
+        coin: String,
+        network: String,
+    },
+    WithdrawDisabled {
+        coin: String,
+        network: String,
+    },
+    /// Shared address of the network, sent without a tag.
+    TagRequired {
+        coin: String,
+        network: String,
+    },
+    BelowMinimum {
+        amount: Decimal,
+        minimum: Decimal,
+    },
+    AboveMaximum {
+        amount: Decimal,
+        maximum: Decimal,
+    },
+    /// Amount not a multiple of the `withdrawIntegerMultiple` of the network.
+    InvalidMultiple {
+        amount: Decimal,
+        multiple: Decimal,
+    },
+    /// Nothing would be received after the network fee.
+    FeeExceedsAmount {
+        amount: Decimal,
+        fee: Decimal,
+    },
+    CoinLimitExceeded {
+        amount: Decimal,
+        limit: Decimal,
+    },
+    DailyLimitExceeded {
+        amount: Decimal,
+        withdrawn: Decimal,
+        limit: Decimal,
+    },
+    /// Token not returned by a dry run, or already confirmed.
+    UnknownConfirmation,
+    ConfirmationExpired {
+        expires_at: u64,
+        timestamp: u64,
+    },
+}
+
+impl fmt::Display for WithdrawRefused {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WithdrawRefused::NotWhitelisted(destination) => write!(
+                f,
+                "{} {} address {} (tag {:?}) is not whitelisted",
+                destination.coin, destination.network, destination.address, destination.tag
+            ),
+            WithdrawRefused::UnknownNetwork { coin, network } => {
+                write!(f, "no coin information for {} on {}", coin, network)
+            }
+            WithdrawRefused::WithdrawDisabled { coin, network } => {
+                write!(f, "withdrawals of {} on {} are disabled", coin, network)
+            }
+            WithdrawRefused::TagRequired { coin, network } => {
+                write!(f, "withdrawals of {} on {} require a tag", coin, network)
+            }
+            WithdrawRefused::BelowMinimum { amount, minimum } => {
+                write!(f, "amount {} is below the minimum {}", amount, minimum)
+            }
+            WithdrawRefused::AboveMaximum { amount, maximum } => {
+                write!(f, "amount {} is above the maximum {}", amount, maximum)
+            }
+            WithdrawRefused::InvalidMultiple { amount, multiple } => {
+                write!(f, "amount {} is not a multiple of {}", amount, multiple)
+            }
+            WithdrawRefused::FeeExceedsAmount { amount, fee } => {
+                write!(f, "amount {} does not cover the fee {}", amount, fee)
+            }
+            WithdrawRefused::CoinLimitExceeded { amount, limit } => {
+                write!(f, "amount {} is above the limit {}", amount, limit)
+            }
+            WithdrawRefused::DailyLimitExceeded {
+                amount,
+                withdrawn,
+                limit,
+            } => write!(
+                f,
+                "amount {} with {} withdrawn today is above the daily limit {}",
+                amount, withdrawn, limit
+            ),
+            WithdrawRefused::UnknownConfirmation => write!(f, "unknown confirmation token"),
+            WithdrawRefused::ConfirmationExpired {
+                expires_at,
+                timestamp,
+            } => write!(
+                f,
+                "confirmation expired at {}, {}ms before {}",
+                expires_at,
+                timestamp - expires_at,
+                timestamp
+            ),
+        }
+    }
+}
+
+impl Error for WithdrawRefused {}
+
+fn confirmation_token() -> String {
+    let bytes: [u8; 16] = rand::random();
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn now() -> u64 {
+    // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock may have gone backwards")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{WithdrawGuard, WithdrawRefused};
+    use crate::wallet::coin_info::Coin;
+    use rust_decimal_macros::dec;
+
+    const COINS: &str = r#"[
+        {"coin":"BNB","name":"BNB","withdrawAllEnable":true,"networkList":[
+            {"network":"BSC","coin":"BNB","isDefault":true,"withdrawEnable":true,"withdrawFee":"0.0005","withdrawMin":"0.01","withdrawMax":"10000","withdrawIntegerMultiple":"0.00000001","sameAddress":false},
+            {"network":"BNB","coin":"BNB","isDefault":false,"withdrawEnable":true,"withdrawFee":"0.002","withdrawMin":"0.01","withdrawMax":"10000","withdrawIntegerMultiple":"0.00000001","sameAddress":true}
+        ]},
+        {"coin":"ETH","name":"Ethereum","withdrawAllEnable":true,"networkList":[
+            {"network":"ETH","coin":"ETH","isDefault":true,"withdrawEnable":false,"withdrawFee":"0.0012","withdrawMin":"0.0024","withdrawMax":"10000","sameAddress":false}
+        ]}
+    ]"#;
+
+    #[test]
+    fn wallet_withdraw_guard_test() {
+        let coins: Vec<Coin> = serde_json::from_str(COINS).unwrap();
+        let mut guard = WithdrawGuard::new()
+            .whitelist("BNB", "BSC", "0x4f2a", None)
+            .whitelist("BNB", "BNB", "bnb1hot", None)
+            .whitelist("ETH", "ETH", "0x4f2a", None)
+            .coin_limit("BNB", dec!(5))
+            .daily_limit("BNB", dec!(8));
+
+        let day = 1_700_006_400_000;
+        assert!(matches!(
+            guard.dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(1), day),
+            Err(WithdrawRefused::UnknownNetwork { .. })
+        ));
+        guard.coin_info(&coins);
+
+        let refused = |guard: &mut WithdrawGuard, network, address, amount| {
+            guard
+                .dry_run_at("BNB", network, address, None, amount, day)
+                .unwrap_err()
+        };
+        assert!(matches!(
+            refused(&mut guard, "BSC", "0x5e7c", dec!(1)),
+            WithdrawRefused::NotWhitelisted(_)
+        ));
+        assert!(matches!(
+            refused(&mut guard, "BNB", "bnb1hot", dec!(1)),
+            WithdrawRefused::TagRequired { .. }
+        ));
+        assert!(matches!(
+            refused(&mut guard, "BSC", "0x4f2a", dec!(0.001)),
+            WithdrawRefused::BelowMinimum { .. }
+        ));
+        assert!(matches!(
+            refused(&mut guard, "BSC", "0x4f2a", dec!(1.000000001)),
+            WithdrawRefused::InvalidMultiple { .. }
+        ));
+        assert!(matches!(
+            refused(&mut guard, "BSC", "0x4f2a", dec!(6)),
+            WithdrawRefused::CoinLimitExceeded { .. }
+        ));
+        assert!(matches!(
+            guard.dry_run_at("ETH", "ETH", "0x4f2a", None, dec!(1), day),
+            Err(WithdrawRefused::WithdrawDisabled { .. })
+        ));
+
+        let dry_run = guard
+            .dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(5), day)
+            .unwrap();
+        assert_eq!(dry_run.fee, dec!(0.0005));
+        assert_eq!(dry_run.received, dec!(4.9995));
+
+        let request = guard
+            .confirm_at(&dry_run.confirmation, day + 1_000)
+            .unwrap();
+        assert_eq!(
+            request.params(),
+            &[
+                ("coin".to_owned(), "BNB".to_owned()),
+                ("address".to_owned(), "0x4f2a".to_owned()),
+                ("amount".to_owned(), "5".to_owned()),
+                ("withdrawOrderId".to_owned(), dry_run.confirmation.clone()),
+                ("network".to_owned(), "BSC".to_owned()),
+            ]
+        );
+        assert_eq!(
+            guard.confirm_at(&dry_run.confirmation, day + 2_000).err(),
+            Some(WithdrawRefused::UnknownConfirmation)
+        );
+        assert_eq!(guard.withdrawn("BNB", day), dec!(5));
+
+        // The daily limit is checked again on confirmation.
+        let first = guard
+            .dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(2), day)
+            .unwrap();
+        let second = guard
+            .dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(2), day)
+            .unwrap();
+        guard.confirm_at(&first.confirmation, day).unwrap();
+        assert_eq!(
+            guard.confirm_at(&second.confirmation, day).err(),
+            Some(WithdrawRefused::DailyLimitExceeded {
+                amount: dec!(2),
+                withdrawn: dec!(7),
+                limit: dec!(8)
+            })
+        );
+
+        // Yesterday recorded after today, as listed by the withdrawal history.
+        guard.record("BNB", dec!(1), day - 86_400_000);
+        assert_eq!(guard.withdrawn("BNB", day), dec!(7));
+        assert!(matches!(
+            guard.dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(2), day),
+            Err(WithdrawRefused::DailyLimitExceeded { .. })
+        ));
+
+        // A new UTC day starts.
+        let next_day = day + 86_400_000;
+        let dry_run = guard
+            .dry_run_at("BNB", "BSC", "0x4f2a", None, dec!(5), next_day)
+            .unwrap();
+        assert!(matches!(
+            guard.confirm_at(&dry_run.confirmation, next_day + 60_000),
+            Err(WithdrawRefused::ConfirmationExpired { .. })
+        ));
+    }
+}
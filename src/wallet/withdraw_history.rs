@@ -1,4 +1,10 @@
-use crate::http::{request::Request, Credentials, Method};
+use crate::http::{
+    pagination::{TimeWindowed, WindowPaging},
+    request::Request,
+    Credentials, Method,
+};
+use rust_decimal::Decimal;
+use serde::Deserialize;
 
 /// `GET /sapi/v1/capital/withdraw/history`
 ///
@@ -19,6 +25,7 @@ use crate::http::{request::Request, Credentials, Method};
 ///
 /// let request = wallet::withdraw_history().coin("BNB").limit(500);
 /// ```
+#[derive(Clone)]
 pub struct WithdrawHistory {
     coin: Option<String>,
     withdraw_order_id: Option<String>,
@@ -144,6 +151,78 @@ impl Default for WithdrawHistory {
     }
 }
 
+impl TimeWindowed for WithdrawHistory {
+    type Page = Vec<Withdrawal>;
+    type Row = Withdrawal;
+
+    fn max_window(&self) -> Option<u64> {
+        Some(90 * 86_400_000)
+    }
+
+    fn paging(&self) -> WindowPaging {
+        WindowPaging::Pages(1000)
+    }
+
+    fn window_request(&self, start_time: u64, end_time: u64, page: u32) -> Request {
+        let mut request = self.clone();
+        request.start_time = Some(start_time);
+        request.end_time = Some(end_time);
+        request.offset = Some(page * 1000);
+        request.limit = Some(1000);
+        request.into()
+    }
+
+    fn rows(page: Vec<Withdrawal>) -> Vec<Withdrawal> {
+        page
+    }
+
+    fn page_weight(&self) -> u32 {
+        18000
+    }
+
+    fn default_weight_limit(&self) -> u32 {
+        180000
+    }
+
+    fn weight_header(&self) -> &'static str {
+        "x-sapi-used-ip-weight-1s"
+    }
+
+    fn weight_interval(&self) -> u64 {
+        1000
+    }
+}
+
+/// Withdrawal returned by `GET /sapi/v1/capital/withdraw/history`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Withdrawal {
+    pub id: String,
+    pub amount: Decimal,
+    pub transaction_fee: Decimal,
+    pub coin: String,
+    pub status: u32,
+    pub address: String,
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    /// UTC time, formatted as `2019-10-12 11:12:02`.
+    pub apply_time: String,
+    #[serde(default)]
+    pub network: Option<String>,
+    pub transfer_type: u32,
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+    #[serde(default)]
+    pub info: Option<String>,
+    #[serde(default)]
+    pub confirm_no: Option<u32>,
+    pub wallet_type: u32,
+    #[serde(default)]
+    pub tx_key: Option<String>,
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::WithdrawHistory;
@@ -1,5 +1,9 @@
+//! Websocket streams and connection urls shared by the websocket clients.
 use std::fmt;
 
+/// Maximum number of streams on a single connection.
+pub const MAX_STREAMS_PER_CONNECTION: usize = 1024;
+
 /// Websocket stream.
 ///
 /// The `Stream` trait is a simplified interface for Binance approved
@@ -25,3 +29,636 @@ impl fmt::Display for Stream {
         write!(f, "{}", self.stream_name)
     }
 }
+
+/// Url of a connection to the market streams.
+///
+/// Streams set in the url are pushed as soon as the connection is open,
+/// without `SUBSCRIBE` messages counting against the limit of 5 incoming
+/// messages per second.
+///
+/// * Combined (default): `/stream?streams=<stream1>/<stream2>`, each frame is
+///   wrapped as `{"stream":"<streamName>","data":<rawPayload>}`.
+/// * Raw: `/ws/<stream>`, frames are the raw payload. A raw url holds at most 1 stream.
+///
+/// # Example
+///
+/// ```
+/// use binance_spot_connector_rust::{
+///     market::klines::KlineInterval,
+///     market_stream::{agg_trade::AggTradeStream, kline::KlineStream},
+///     websocket::StreamUrl,
+/// };
+///
+/// let url = StreamUrl::new(&[
+///     KlineStream::new("BTCUSDT", KlineInterval::Minutes1).into(),
+///     AggTradeStream::new("BTCUSDT").into(),
+/// ])
+/// .url();
+///
+/// assert_eq!(
+///     url.unwrap(),
+///     "wss://stream.binance.com:9443/stream?streams=btcusdt@kline_1m/btcusdt@aggTrade"
+/// );
+/// ```
+pub struct StreamUrl {
+    base_url: String,
+    streams: Vec<String>,
+    combined: bool,
+}
+
+impl StreamUrl {
+    pub fn new<'a>(streams: impl IntoIterator<Item = &'a Stream>) -> Self {
+        Self {
+            base_url: "wss://stream.binance.com:9443".to_owned(),
+            streams: streams
+                .into_iter()
+                .map(|stream| stream.as_str().to_owned())
+                .collect(),
+            combined: true,
+        }
+    }
+
+    /// Server url without path, e.g. `wss://stream.testnet.binance.vision`.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Wrap frames in the combined stream envelope, `true` by default.
+    pub fn combined(mut self, combined: bool) -> Self {
+        self.combined = combined;
+        self
+    }
+
+    pub fn is_combined(&self) -> bool {
+        self.combined
+    }
+
+    /// Connection url, failing if the streams don't fit in a single connection.
+    pub fn url(&self) -> Result<String, StreamUrlError> {
+        if self.streams.len() > MAX_STREAMS_PER_CONNECTION {
+            return Err(StreamUrlError::TooManyStreams(self.streams.len()));
+        }
+
+        // `+` of time zone offsets would be decoded as a space in a query string.
+        let streams = self.streams.join("/").replace('+', "%2B");
+
+        match (self.combined, self.streams.len()) {
+            (true, 0) => Ok(format!("{}/stream", self.base_url)),
+            (true, _) => Ok(format!("{}/stream?streams={}", self.base_url, streams)),
+            (false, 0) => Ok(format!("{}/ws", self.base_url)),
+            (false, 1) => Ok(format!("{}/ws/{}", self.base_url, self.streams[0])),
+            (false, count) => Err(StreamUrlError::RawStreams(count)),
+        }
+    }
+}
+
+/// Streams of a [`StreamUrl`] not fitting in a single connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamUrlError {
+    /// More than [`MAX_STREAMS_PER_CONNECTION`] streams.
+    TooManyStreams(usize),
+    /// More than one stream on a raw connection.
+    RawStreams(usize),
+}
+
+impl fmt::Display for StreamUrlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamUrlError::TooManyStreams(count) => write!(
+                f,
+                "{} streams exceed the limit of {} streams per connection",
+                count, MAX_STREAMS_PER_CONNECTION
+            ),
+            StreamUrlError::RawStreams(count) => write!(
+                f,
+                "{} streams on a raw connection, use a combined connection",
+                count
+            ),
+        }
+    }
+}
+
+impl std::error::Error for StreamUrlError {}
+
+/// Control message or WebSocket API request awaiting a reply.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlMethod {
+    Subscribe,
+    Unsubscribe,
+    ListSubscriptions,
+    SetProperty,
+    GetProperty,
+    /// WebSocket API request, e.g. `session.logon`.
+    Api,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl ControlMethod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ControlMethod::Subscribe => "SUBSCRIBE",
+            ControlMethod::Unsubscribe => "UNSUBSCRIBE",
+            ControlMethod::ListSubscriptions => "LIST_SUBSCRIPTIONS",
+            ControlMethod::SetProperty => "SET_PROPERTY",
+            ControlMethod::GetProperty => "GET_PROPERTY",
+            ControlMethod::Api => "API",
+        }
+    }
+}
+
+/// Successful reply to a control message.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ControlResponse {
+    /// `SUBSCRIBE`, `UNSUBSCRIBE` or `SET_PROPERTY` was applied.
+    Done,
+    /// Streams listed by `LIST_SUBSCRIPTIONS`.
+    Subscriptions(Vec<String>),
+    /// Value returned by `GET_PROPERTY`.
+    Property(serde_json::Value),
+    /// `result` of a WebSocket API request.
+    Api(serde_json::Value),
+}
+
+/// Error reply to a control message.
+///
+/// ```json
+/// { "code": 2, "msg": "Invalid request: property name must be a string", "id": 7 }
+/// ```
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ControlError {
+    pub code: i64,
+    pub message: String,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl fmt::Display for ControlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (code {})", self.message, self.code)
+    }
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl std::error::Error for ControlError {}
+
+/// Reply of the server matched with the message `id` it answers.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub id: u64,
+    pub method: ControlMethod,
+    pub result: Result<ControlResponse, ControlError>,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(serde::Deserialize)]
+struct RawReply {
+    id: Option<u64>,
+    #[serde(default)]
+    result: serde_json::Value,
+    code: Option<i64>,
+    msg: Option<String>,
+    error: Option<RawError>,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(serde::Deserialize)]
+struct RawError {
+    code: i64,
+    msg: String,
+}
+
+/// Message received on a connection, decoded.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// Market stream event, with the stream name when received on a
+    /// combined stream connection.
+    Market {
+        stream: Option<String>,
+        event: crate::market_stream::event::MarketEvent,
+    },
+    /// User data event, with the subscription id when received from a
+    /// WebSocket API user data subscription.
+    UserData {
+        subscription_id: Option<u64>,
+        event: crate::user_data_stream::event::UserDataEvent,
+    },
+    /// Reply to a control message or WebSocket API request.
+    Reply(Reply),
+    /// Payload not modelled by the library.
+    Unknown {
+        stream: Option<String>,
+        data: serde_json::Value,
+    },
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl Event {
+    /// Decode a stream payload, unwrapping the combined stream envelope
+    /// `{"stream":"<streamName>","data":<rawPayload>}`.
+    pub fn from_text(text: &str) -> Result<Self, serde_json::Error> {
+        use crate::market_stream::event::MarketEvent;
+        use crate::user_data_stream::event::{SubscriptionEvent, UserDataEvent};
+        use serde::Deserialize;
+        use serde_json::Value;
+
+        let (stream, data) = match serde_json::from_str(text)? {
+            Value::Object(mut map) if map.contains_key("stream") && map.contains_key("data") => {
+                let stream = map.remove("stream").and_then(|stream| match stream {
+                    Value::String(stream) => Some(stream),
+                    _ => None,
+                });
+                (stream, map.remove("data").unwrap_or_default())
+            }
+            data => (None, data),
+        };
+
+        if let Some(event) = MarketEvent::from_value(&data)? {
+            return Ok(Event::Market { stream, event });
+        }
+
+        if data.get("subscriptionId").is_some() && data.get("event").is_some() {
+            let event = SubscriptionEvent::deserialize(&data)?;
+            if event.event != UserDataEvent::Unknown {
+                return Ok(Event::UserData {
+                    subscription_id: event.subscription_id,
+                    event: event.event,
+                });
+            }
+        } else if data.get("e").is_some() {
+            let event = UserDataEvent::deserialize(&data)?;
+            if event != UserDataEvent::Unknown {
+                return Ok(Event::UserData {
+                    subscription_id: None,
+                    event,
+                });
+            }
+        }
+
+        Ok(Event::Unknown { stream, data })
+    }
+}
+
+/// Messages sent on a connection and not replied yet, by `id`.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+#[derive(Default)]
+pub(crate) struct PendingRequests {
+    requests: std::collections::HashMap<u64, ControlMethod>,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl PendingRequests {
+    pub(crate) fn insert(&mut self, id: u64, method: ControlMethod) {
+        self.requests.insert(id, method);
+    }
+
+    pub(crate) fn contains(&self, id: u64) -> bool {
+        self.requests.contains_key(&id)
+    }
+
+    /// Match a text frame with a pending message, `None` for stream events.
+    pub(crate) fn handle(&mut self, text: &str) -> Option<Reply> {
+        if self.requests.is_empty() {
+            return None;
+        }
+
+        let raw: RawReply = serde_json::from_str(text).ok()?;
+        let id = raw.id?;
+        let method = self.requests.remove(&id)?;
+
+        let result = match (raw.error, raw.code, raw.msg) {
+            (Some(error), _, _) => Err(ControlError {
+                code: error.code,
+                message: error.msg,
+            }),
+            (None, Some(code), Some(message)) => Err(ControlError { code, message }),
+            _ => match method {
+                ControlMethod::ListSubscriptions => serde_json::from_value(raw.result)
+                    .map(ControlResponse::Subscriptions)
+                    .map_err(|err| ControlError {
+                        code: -1,
+                        message: err.to_string(),
+                    }),
+                ControlMethod::GetProperty => Ok(ControlResponse::Property(raw.result)),
+                ControlMethod::Api => Ok(ControlResponse::Api(raw.result)),
+                _ => Ok(ControlResponse::Done),
+            },
+        };
+
+        Some(Reply { id, method, result })
+    }
+}
+
+/// Control message of the market streams.
+///
+/// ```json
+/// { "method": "SUBSCRIBE", "params": [ "btcusdt@aggTrade" ], "id": 1 }
+/// ```
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+pub(crate) fn control_message(
+    method: ControlMethod,
+    params: Vec<serde_json::Value>,
+    id: u64,
+) -> String {
+    let mut message = serde_json::Map::new();
+    message.insert("id".to_owned(), id.into());
+    message.insert("method".to_owned(), method.as_str().into());
+    if !params.is_empty() {
+        message.insert("params".to_owned(), params.into());
+    }
+
+    serde_json::Value::Object(message).to_string()
+}
+
+/// WebSocket API request.
+///
+/// Unlike stream control messages, WebSocket API requests carry their
+/// parameters as a JSON object.
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+pub(crate) struct ApiRequest {
+    method: String,
+    params: Vec<(String, serde_json::Value)>,
+}
+
+#[cfg(any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite"))]
+impl ApiRequest {
+    pub(crate) fn new(method: &str) -> Self {
+        Self {
+            method: method.to_owned(),
+            params: vec![],
+        }
+    }
+
+    pub(crate) fn param(mut self, key: &str, value: impl Into<serde_json::Value>) -> Self {
+        self.params.push((key.to_owned(), value.into()));
+        self
+    }
+
+    /// Append `apiKey`, `timestamp` and `signature` to the parameters.
+    pub(crate) fn sign(
+        self,
+        credentials: &crate::http::Credentials,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        // Use system clock, panic if system clock is behind `std::time::UNIX_EPOCH`
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("Clock may have gone backwards")
+            .as_millis();
+
+        self.sign_at(credentials, timestamp)
+    }
+
+    fn sign_at(
+        mut self,
+        credentials: &crate::http::Credentials,
+        timestamp: u128,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        self.params
+            .push(("apiKey".to_owned(), credentials.api_key.as_str().into()));
+        self.params
+            .push(("timestamp".to_owned(), (timestamp as u64).into()));
+
+        // The payload is made of all parameters sorted by name.
+        let mut params: Vec<(&str, String)> = self
+            .params
+            .iter()
+            .map(|(k, v)| match v {
+                serde_json::Value::String(v) => (k.as_str(), v.to_owned()),
+                v => (k.as_str(), v.to_string()),
+            })
+            .collect();
+        params.sort();
+        let payload = params
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<String>>()
+            .join("&");
+
+        let signature = crate::utils::sign(&payload, &credentials.signature)?;
+        self.params.push(("signature".to_owned(), signature.into()));
+
+        Ok(self)
+    }
+
+    pub(crate) fn to_message(&self, id: u64) -> String {
+        let mut message = serde_json::Map::new();
+        message.insert("id".to_owned(), id.into());
+        message.insert("method".to_owned(), self.method.as_str().into());
+        if !self.params.is_empty() {
+            let params: serde_json::Map<String, serde_json::Value> = self
+                .params
+                .iter()
+                .map(|(k, v)| (k.to_owned(), v.to_owned()))
+                .collect();
+            message.insert("params".to_owned(), params.into());
+        }
+
+        serde_json::Value::Object(message).to_string()
+    }
+}
+
+#[cfg(all(
+    test,
+    any(feature = "enable-tungstenite", feature = "enable-tokio-tungstenite")
+))]
+mod tests {
+    use super::{
+        control_message, ApiRequest, ControlError, ControlMethod, ControlResponse, Event,
+        PendingRequests, Stream, StreamUrl, StreamUrlError,
+    };
+    use crate::http::Credentials;
+    use crate::market_stream::event::MarketEvent;
+    use crate::user_data_stream::event::UserDataEvent;
+
+    #[test]
+    fn stream_url_test() {
+        let streams = vec![
+            Stream::new("btcusdt@kline_1h@+08:00"),
+            Stream::new("btcusdt@trade"),
+        ];
+
+        assert_eq!(
+            StreamUrl::new(&streams)
+                .base_url("wss://stream.testnet.binance.vision/")
+                .url(),
+            Ok("wss://stream.testnet.binance.vision/stream?streams=btcusdt@kline_1h@%2B08:00/btcusdt@trade".to_owned())
+        );
+        assert_eq!(
+            StreamUrl::new(&streams[..1]).combined(false).url(),
+            Ok("wss://stream.binance.com:9443/ws/btcusdt@kline_1h@+08:00".to_owned())
+        );
+        assert_eq!(
+            StreamUrl::new(&streams).combined(false).url(),
+            Err(StreamUrlError::RawStreams(2))
+        );
+
+        let streams: Vec<Stream> = (0..1025)
+            .map(|i| Stream::new(&format!("s{}@trade", i)))
+            .collect();
+        assert_eq!(
+            StreamUrl::new(&streams).url(),
+            Err(StreamUrlError::TooManyStreams(1025))
+        );
+    }
+
+    #[test]
+    fn pending_requests_handle_test() {
+        let mut pending = PendingRequests::default();
+        assert_eq!(pending.handle(r#"{"result":null,"id":0}"#), None);
+
+        pending.insert(0, ControlMethod::Subscribe);
+        pending.insert(1, ControlMethod::ListSubscriptions);
+        pending.insert(2, ControlMethod::SetProperty);
+        pending.insert(3, ControlMethod::GetProperty);
+        pending.insert(4, ControlMethod::Api);
+
+        assert_eq!(
+            pending.handle(r#"{"e":"trade","E":1672515782136,"s":"BNBBTC"}"#),
+            None
+        );
+
+        let reply = pending.handle(r#"{"result":null,"id":0}"#).unwrap();
+        assert_eq!(reply.method, ControlMethod::Subscribe);
+        assert_eq!(reply.result, Ok(ControlResponse::Done));
+        assert!(!pending.contains(0));
+        assert_eq!(pending.handle(r#"{"result":null,"id":0}"#), None);
+
+        let reply = pending
+            .handle(r#"{"result":["btcusdt@aggTrade","btcusdt@depth"],"id":1}"#)
+            .unwrap();
+        assert_eq!(
+            reply.result,
+            Ok(ControlResponse::Subscriptions(vec![
+                "btcusdt@aggTrade".to_owned(),
+                "btcusdt@depth".to_owned()
+            ]))
+        );
+
+        let reply = pending
+            .handle(r#"{"code":2,"msg":"Invalid request: unknown property","id":2}"#)
+            .unwrap();
+        assert_eq!(
+            reply.result,
+            Err(ControlError {
+                code: 2,
+                message: "Invalid request: unknown property".to_owned()
+            })
+        );
+
+        let reply = pending.handle(r#"{"result":true,"id":3}"#).unwrap();
+        assert_eq!(
+            reply.result,
+            Ok(ControlResponse::Property(serde_json::Value::Bool(true)))
+        );
+
+        let reply = pending
+            .handle(r#"{"id":4,"status":400,"error":{"code":-1022,"msg":"Signature for this request is not valid."}}"#)
+            .unwrap();
+        assert_eq!(reply.result.unwrap_err().code, -1022);
+    }
+
+    #[test]
+    fn event_from_text_test() {
+        let event = Event::from_text(r#"{"stream":"btcusdt@aggTrade","data":{"e":"aggTrade","E":1672515782136,"s":"BTCUSDT","a":12345,"p":"0.001","q":"100","f":100,"l":105,"T":1672515782136,"m":true,"M":true}}"#).unwrap();
+        match event {
+            Event::Market {
+                stream: Some(stream),
+                event: MarketEvent::AggTrade(trade),
+            } => {
+                assert_eq!(stream, "btcusdt@aggTrade");
+                assert_eq!(trade.agg_trade_id, 12345);
+            }
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        let event = Event::from_text(r#"{"subscriptionId":1,"event":{"e":"listenKeyExpired","E":1699596037418,"listenKey":"key"}}"#).unwrap();
+        match event {
+            Event::UserData {
+                subscription_id: Some(1),
+                event: UserDataEvent::ListenKeyExpired(_),
+            } => {}
+            event => panic!("Invalid event {:?}", event),
+        }
+
+        assert_eq!(
+            Event::from_text(r#"{"e":"USER_LIABILITY_CHANGE","E":1701949843000}"#).unwrap(),
+            Event::Unknown {
+                stream: None,
+                data: serde_json::json!({"e":"USER_LIABILITY_CHANGE","E":1701949843000u64}),
+            }
+        );
+        assert!(Event::from_text("not json").is_err());
+    }
+
+    #[test]
+    fn control_message_test() {
+        assert_eq!(
+            control_message(
+                ControlMethod::SetProperty,
+                vec!["combined".into(), true.into()],
+                5
+            ),
+            r#"{"id":5,"method":"SET_PROPERTY","params":["combined",true]}"#
+        );
+        assert_eq!(
+            control_message(
+                ControlMethod::Subscribe,
+                vec![r#"btcusdt@trade","ethusdt@trade"#.into()],
+                4
+            ),
+            r#"{"id":4,"method":"SUBSCRIBE","params":["btcusdt@trade\",\"ethusdt@trade"]}"#
+        );
+        assert_eq!(
+            control_message(ControlMethod::ListSubscriptions, vec![], 3),
+            r#"{"id":3,"method":"LIST_SUBSCRIPTIONS"}"#
+        );
+    }
+
+    #[test]
+    fn api_request_to_message_test() {
+        let message = ApiRequest::new("userDataStream.subscribe.listenToken")
+            .param("listenToken", "token")
+            .to_message(1);
+
+        assert_eq!(
+            message,
+            r#"{"id":1,"method":"userDataStream.subscribe.listenToken","params":{"listenToken":"token"}}"#
+        );
+    }
+
+    #[test]
+    fn api_request_sign_test() {
+        let credentials = Credentials::from_hmac(
+            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        );
+
+        let message = ApiRequest::new("userDataStream.subscribe.signature")
+            .sign_at(&credentials, 1747385641636)
+            .unwrap()
+            .to_message(0);
+
+        let expected = serde_json::json!({
+            "id": 0,
+            "method": "userDataStream.subscribe.signature",
+            "params": {
+                "apiKey": "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+                "timestamp": 1747385641636u64,
+                "signature": crate::utils::sign(
+                    "apiKey=vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A&timestamp=1747385641636",
+                    &credentials.signature,
+                )
+                .unwrap(),
+            }
+        });
+
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&message).unwrap(),
+            expected
+        );
+    }
+}